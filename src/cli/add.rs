@@ -0,0 +1,1219 @@
+use clap::Args;
+
+use crate::cli::add_summary::AddSummary;
+use crate::error::{CsmError, Result};
+use crate::github::GitHubClient;
+use crate::models::{Skill, SkillScope, SkillSource};
+use crate::services::SkillService;
+use crate::services::naming::{is_valid_name, next_available_name, slugify};
+use crate::utils::archive::extract_skills;
+use crate::utils::cancellation::CancellationToken;
+use crate::utils::editor::launch_editor;
+
+const ARCHIVE_SUFFIXES: &[&str] = &[".zip", ".tar.gz", ".tgz"];
+
+/// `--list-versions` prints at most this many refs (the first ones
+/// `list_refs` returns), so a repo with hundreds of tags doesn't flood the
+/// terminal. The refs are not sorted by recency first.
+const MAX_LISTED_VERSIONS: usize = 20;
+
+#[derive(Debug, Args)]
+pub struct AddArgs {
+    /// Local file path, inline content source, or a `.zip`/`.tar.gz` archive of skills.
+    pub source: String,
+
+    #[arg(long)]
+    pub name: Option<String>,
+
+    #[arg(long, default_value = "project")]
+    pub scope: String,
+
+    /// Open the stored skill in $EDITOR immediately after adding it.
+    #[arg(long)]
+    pub edit: bool,
+
+    /// On a name collision, append `-2`, `-3`, ... instead of failing.
+    #[arg(long)]
+    pub auto_suffix: bool,
+
+    /// Print the repo's tags and branches instead of adding, so a specific
+    /// release can be picked for a `github:owner/repo/path@ref` source.
+    #[arg(long)]
+    pub list_versions: bool,
+
+    /// Accept the suggested slug automatically when `--name` has invalid characters.
+    #[arg(long)]
+    pub yes: bool,
+
+    /// Add one skill per `.md` file found beneath a `github:` source's path,
+    /// naming each after its containing directory.
+    #[arg(long)]
+    pub recursive: bool,
+
+    /// Add the skill disabled, overriding `[general] enable_on_add`, so it's
+    /// excluded from the merge until explicitly reviewed and enabled.
+    #[arg(long)]
+    pub disabled: bool,
+
+    /// On a name collision, overwrite the existing skill's content in place
+    /// instead of failing, keeping its id. Mutually exclusive with
+    /// `--auto-suffix`, which handles collisions by renaming instead.
+    #[arg(long, conflicts_with = "auto_suffix")]
+    pub force: bool,
+
+    /// Reject (instead of warn about) fetched content that looks like an
+    /// HTML error/login page rather than a skill body.
+    #[arg(long)]
+    pub strict: bool,
+}
+
+pub async fn run(
+    args: &AddArgs,
+    service: &dyn SkillService,
+    github: &dyn GitHubClient,
+    cancel: &CancellationToken,
+) -> Result<AddSummary> {
+    let scope: SkillScope = args
+        .scope
+        .parse()
+        .map_err(|e: crate::models::ParseSkillScopeError| CsmError::Validation(e.to_string()))?;
+
+    if is_archive_source(&args.source) {
+        return Ok(add_from_archive(&args.source, scope, service, cancel).await?);
+    }
+
+    if std::path::Path::new(&args.source).is_dir() {
+        return add_local_directory(std::path::Path::new(&args.source), scope, service, cancel).await;
+    }
+
+    let mut source = crate::models::parse_source(&args.source);
+
+    if is_ambiguous_bare_source(&args.source) && crate::utils::prompt::is_interactive() {
+        source = clarify_ambiguous_source(&args.source, source, &mut std::io::stdin().lock());
+    }
+
+    if args.list_versions {
+        list_versions(&source, github).await?;
+        return Ok(AddSummary::default());
+    }
+
+    if args.recursive {
+        return add_directory(&source, scope, service, github, cancel).await;
+    }
+
+    let mut name = args
+        .name
+        .clone()
+        .unwrap_or_else(|| args.source.clone());
+    if !is_valid_name(&name) {
+        let suggestion = slugify(&name);
+        if args.yes {
+            name = suggestion;
+        } else {
+            return Err(CsmError::InvalidName(format!(
+                "invalid name '{name}'; try '{suggestion}' (pass --yes to accept it)"
+            )));
+        }
+    }
+    if args.auto_suffix {
+        let existing: Vec<String> = service.list().await?.into_iter().map(|s| s.name).collect();
+        name = next_available_name(&existing, &name);
+    }
+
+    let mut skill = if args.force {
+        service.add_or_overwrite(&name, source, scope).await?
+    } else {
+        service.add(&name, source, scope).await?
+    };
+
+    if crate::utils::content_sanity::looks_like_html_error_page(&skill.content) {
+        if args.strict {
+            service.purge(&skill.name, scope).await?;
+            return Err(CsmError::InvalidContent(format!(
+                "'{}' looks like an HTML error/login page, not a skill body",
+                skill.name
+            )));
+        }
+        eprintln!(
+            "warning: '{}' looks like an HTML error/login page, not a skill body",
+            skill.name
+        );
+    }
+
+    if args.edit {
+        let mut scratch = tempfile::Builder::new()
+            .suffix(".md")
+            .tempfile()?;
+        std::io::Write::write_all(&mut scratch, skill.content.as_bytes())?;
+        launch_editor(scratch.path())?;
+        let edited = std::fs::read_to_string(scratch.path())?;
+        skill = service.update_content(&skill.name, scope, edited).await?;
+    }
+
+    if args.disabled && skill.enabled {
+        skill = service.set_enabled(&skill.name, scope, false).await?;
+    }
+
+    Ok(AddSummary {
+        added: vec![skill],
+        skipped_existing: Vec::new(),
+        failed: Vec::new(),
+        interrupted: false,
+    })
+}
+
+/// Fetches and prints the tags/branches for a `--list-versions` GitHub
+/// source, in `list_refs` order, truncated to [`MAX_LISTED_VERSIONS`].
+async fn list_versions(source: &SkillSource, github: &dyn GitHubClient) -> Result<()> {
+    let SkillSource::GitHub { owner, repo, .. } = source else {
+        return Err(CsmError::Validation(
+            "--list-versions is only supported for github: sources".to_string(),
+        ));
+    };
+
+    let refs = github.list_refs(owner, repo).await?;
+    for name in refs.into_iter().take(MAX_LISTED_VERSIONS) {
+        println!("{name}");
+    }
+    Ok(())
+}
+
+fn is_archive_source(source: &str) -> bool {
+    ARCHIVE_SUFFIXES.iter().any(|suffix| source.ends_with(suffix))
+}
+
+/// True for a bare word like `myskill` that `parse_source` resolved to
+/// `Local` by default but that has no `/` in it and no file to back it up
+/// — the shape of a typo'd `github:owner/repo` as much as a deliberate
+/// local path, worth confirming rather than assuming.
+fn is_ambiguous_bare_source(raw: &str) -> bool {
+    !raw.contains('/') && !std::path::Path::new(raw).exists()
+}
+
+/// Prompts (via `reader`) to clarify an ambiguous bare `add` source rather
+/// than silently treating it as a local path. Reads at most a couple of
+/// lines; an empty or unrecognized answer keeps `fallback`, preserving
+/// today's error behavior once `add` tries to read a nonexistent file.
+fn clarify_ambiguous_source(
+    raw: &str,
+    fallback: SkillSource,
+    reader: &mut impl std::io::BufRead,
+) -> SkillSource {
+    use std::io::Write;
+
+    print!(
+        "'{raw}' isn't an existing local file and doesn't look like a github owner/repo or a URL.\n\
+         Is this a (l)ocal file, a (g)ithub owner/repo, or a (u)rl? [l]: "
+    );
+    let _ = std::io::stdout().flush();
+
+    let mut answer = String::new();
+    if reader.read_line(&mut answer).is_err() {
+        return fallback;
+    }
+
+    match answer.trim() {
+        "g" | "github" => {
+            print!("github owner/repo: ");
+            let _ = std::io::stdout().flush();
+            let mut owner_repo = String::new();
+            if reader.read_line(&mut owner_repo).is_ok() && !owner_repo.trim().is_empty() {
+                return crate::models::parse_source(&format!("github:{}", owner_repo.trim()));
+            }
+            fallback
+        }
+        "u" | "url" => {
+            print!("url: ");
+            let _ = std::io::stdout().flush();
+            let mut url = String::new();
+            if reader.read_line(&mut url).is_ok() && !url.trim().is_empty() {
+                return crate::models::parse_source(url.trim());
+            }
+            fallback
+        }
+        _ => fallback,
+    }
+}
+
+/// Adds one skill per `.md` file beneath a `github:` directory source,
+/// deriving each name from its containing directory. Skills that already
+/// exist are skipped with a warning rather than aborting the whole batch,
+/// mirroring `add_from_archive`.
+async fn add_directory(
+    source: &SkillSource,
+    scope: SkillScope,
+    service: &dyn SkillService,
+    github: &dyn GitHubClient,
+    cancel: &CancellationToken,
+) -> Result<AddSummary> {
+    let SkillSource::GitHub { owner, repo, path, ref_spec } = source else {
+        return Err(CsmError::Validation(
+            "--recursive is only supported for github: sources".to_string(),
+        ));
+    };
+
+    let files = github.fetch_directory(owner, repo, path, ref_spec).await?;
+
+    let mut summary = AddSummary::default();
+    for (file_path, content) in files {
+        if cancel.is_cancelled() {
+            summary.interrupted = true;
+            break;
+        }
+        let name = name_from_directory(&file_path);
+        let file_source = SkillSource::GitHub {
+            owner: owner.clone(),
+            repo: repo.clone(),
+            path: file_path,
+            ref_spec: ref_spec.clone(),
+        };
+        match service.add_with_content(&name, file_source, scope, content).await {
+            Ok(skill) => summary.added.push(skill),
+            Err(CsmError::AlreadyExists(_)) => {
+                eprintln!("warning: skill '{name}' already exists, skipping");
+                summary.skipped_existing.push(name);
+            }
+            Err(e) => summary.failed.push((name, e.to_string())),
+        }
+    }
+    Ok(summary)
+}
+
+/// Names a fetched file after its containing directory, e.g.
+/// `skills/typescript/CLAUDE.md` -> `typescript`. Falls back to the file
+/// stem when the file has no containing directory.
+fn name_from_directory(file_path: &str) -> String {
+    std::path::Path::new(file_path)
+        .parent()
+        .and_then(|parent| parent.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| {
+            std::path::Path::new(file_path)
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_else(|| file_path.to_string())
+        })
+}
+
+/// Adds every `.md` file directly inside a local directory as its own
+/// skill, named after its file stem. Duplicate names and unreadable files
+/// (empty or non-UTF8) are recorded in the summary rather than aborting
+/// the batch, mirroring `add_from_archive`.
+async fn add_local_directory(
+    dir: &std::path::Path,
+    scope: SkillScope,
+    service: &dyn SkillService,
+    cancel: &CancellationToken,
+) -> Result<AddSummary> {
+    let mut paths: Vec<_> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("md"))
+        .collect();
+    paths.sort();
+
+    let mut summary = AddSummary::default();
+    for path in paths {
+        if cancel.is_cancelled() {
+            summary.interrupted = true;
+            break;
+        }
+
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.to_string_lossy().into_owned());
+
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) if !content.trim().is_empty() => content,
+            Ok(_) => {
+                summary.failed.push((name, "empty content".to_string()));
+                continue;
+            }
+            Err(e) => {
+                summary.failed.push((name, format!("not readable as a skill file: {e}")));
+                continue;
+            }
+        };
+
+        match service
+            .add_with_content(&name, SkillSource::Local(path.to_string_lossy().into_owned()), scope, content)
+            .await
+        {
+            Ok(skill) => summary.added.push(skill),
+            Err(CsmError::AlreadyExists(_)) => summary.skipped_existing.push(name),
+            Err(e) => summary.failed.push((name, e.to_string())),
+        }
+    }
+    Ok(summary)
+}
+
+/// Adds every markdown file found in a local zip/tarball as its own skill,
+/// recording each outcome instead of aborting on the first failure.
+async fn add_from_archive(
+    path: &str,
+    scope: SkillScope,
+    service: &dyn SkillService,
+    cancel: &CancellationToken,
+) -> Result<AddSummary> {
+    let bytes = std::fs::read(path)?;
+    let entries = extract_skills(&bytes, path)?;
+
+    let mut summary = AddSummary::default();
+    for entry in entries {
+        if cancel.is_cancelled() {
+            summary.interrupted = true;
+            break;
+        }
+        match service
+            .add_with_content(&entry.name, SkillSource::Local(path.to_string()), scope, entry.content)
+            .await
+        {
+            Ok(skill) => summary.added.push(skill),
+            Err(CsmError::AlreadyExists(_)) => summary.skipped_existing.push(entry.name),
+            Err(e) => summary.failed.push((entry.name, e.to_string())),
+        }
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct FakeService {
+        skill: Mutex<Skill>,
+    }
+
+    #[async_trait]
+    impl SkillService for FakeService {
+        async fn add(&self, name: &str, source: SkillSource, scope: SkillScope) -> Result<Skill> {
+            let now = chrono::Utc::now();
+            let skill = Skill {
+                id: 1,
+                name: name.to_string(),
+                source,
+                scope,
+                content: "original content".to_string(),
+                content_hash: "orig-hash".to_string(),
+                previous_content: None,
+                enabled: true,
+                priority: 0,
+                update_mode: crate::models::UpdateMode::Auto,
+                update_trigger: crate::models::UpdateTrigger::OnCommit,
+                failure_count: 0,
+                last_failure_at: None,
+                archived: false,
+                archived_at: None,
+                last_known_ref: None,
+                notes: None,
+                tags: Vec::new(),
+                created_at: now,
+                updated_at: now,
+            };
+            *self.skill.lock().unwrap() = skill.clone();
+            Ok(skill)
+        }
+
+        async fn add_or_overwrite(&self, name: &str, source: SkillSource, scope: SkillScope) -> Result<Skill> {
+            let mut skill = self.skill.lock().unwrap();
+            skill.name = name.to_string();
+            skill.source = source;
+            skill.scope = scope;
+            skill.content = "original content".to_string();
+            skill.content_hash = "orig-hash".to_string();
+            Ok(skill.clone())
+        }
+
+        async fn add_with_content(
+            &self,
+            _name: &str,
+            _source: SkillSource,
+            _scope: SkillScope,
+            _content: String,
+        ) -> Result<Skill> {
+            unimplemented!()
+        }
+
+        async fn list(&self) -> Result<Vec<Skill>> {
+            Ok(vec![self.skill.lock().unwrap().clone()])
+        }
+
+        async fn update_content(
+            &self,
+            _name: &str,
+            _scope: SkillScope,
+            content: String,
+        ) -> Result<Skill> {
+            let mut skill = self.skill.lock().unwrap();
+            skill.content = content;
+            Ok(skill.clone())
+        }
+
+        async fn merge_preview(
+            &self,
+            _scope: Option<SkillScope>,
+        ) -> Result<crate::models::MergePreviewStats> {
+            unimplemented!()
+        }
+
+        async fn effective_list(&self) -> Result<Vec<crate::models::Skill>> {
+            unimplemented!()
+        }
+
+        async fn set_note(
+            &self,
+            _n: &str,
+            _sc: SkillScope,
+            _note: Option<String>,
+        ) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn archive(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn restore(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn purge(&self, _n: &str, _sc: SkillScope) -> Result<()> {
+            unimplemented!()
+        }
+        async fn rename(&self, _n: &str, _sc: SkillScope, _new: &str) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_tags(&self, _n: &str, _sc: SkillScope, _tags: Vec<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_priority(&self, _n: &str, _sc: SkillScope, _p: i32) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_enabled(&self, _n: &str, _sc: SkillScope, _e: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn record_update_result(&self, _n: &str, _sc: SkillScope, _failed: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn rollback_content(&self, _n: &str, _sc: SkillScope) -> Result<bool> {
+            unimplemented!()
+        }
+
+    }
+
+    #[tokio::test]
+    async fn add_with_edit_persists_editor_changes() {
+        // A fake $EDITOR that appends a marker line to whatever file it's given.
+        let fake_editor = std::env::temp_dir().join("csm_test_fake_editor.sh");
+        std::fs::write(
+            &fake_editor,
+            "#!/bin/sh\necho 'edited in place' >> \"$1\"\n",
+        )
+        .unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&fake_editor, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        std::env::set_var("EDITOR", &fake_editor);
+
+        let now = chrono::Utc::now();
+        let service = FakeService {
+            skill: Mutex::new(Skill {
+                id: 0,
+                name: String::new(),
+                source: SkillSource::Inline,
+                scope: SkillScope::Project,
+                content: String::new(),
+                content_hash: String::new(),
+                previous_content: None,
+                enabled: true,
+                priority: 0,
+                update_mode: crate::models::UpdateMode::Auto,
+                update_trigger: crate::models::UpdateTrigger::OnCommit,
+                failure_count: 0,
+                last_failure_at: None,
+                archived: false,
+                archived_at: None,
+                last_known_ref: None,
+                notes: None,
+                tags: Vec::new(),
+                created_at: now,
+                updated_at: now,
+            }),
+        };
+        let args = AddArgs {
+            source: "draft.md".to_string(),
+            name: Some("draft".to_string()),
+            scope: "project".to_string(),
+            edit: true,
+            auto_suffix: false,
+            list_versions: false,
+            yes: false,
+            recursive: false,
+            disabled: false,
+            force: false,
+            strict: false,
+        };
+        let github = FakeGitHub::default();
+
+        let summary = run(&args, &service, &github, &CancellationToken::default()).await.unwrap();
+
+        assert!(summary.added[0].content.contains("edited in place"));
+    }
+
+    #[tokio::test]
+    async fn force_overwrites_an_existing_skill_in_place_keeping_its_id() {
+        let now = chrono::Utc::now();
+        let service = FakeService {
+            skill: Mutex::new(Skill {
+                id: 7,
+                name: "draft".to_string(),
+                source: SkillSource::Inline,
+                scope: SkillScope::Project,
+                content: "stale content".to_string(),
+                content_hash: "stale-hash".to_string(),
+                previous_content: None,
+                enabled: true,
+                priority: 0,
+                update_mode: crate::models::UpdateMode::Auto,
+                update_trigger: crate::models::UpdateTrigger::OnCommit,
+                failure_count: 0,
+                last_failure_at: None,
+                archived: false,
+                archived_at: None,
+                last_known_ref: None,
+                notes: None,
+                tags: Vec::new(),
+                created_at: now,
+                updated_at: now,
+            }),
+        };
+        let args = AddArgs {
+            source: "draft.md".to_string(),
+            name: Some("draft".to_string()),
+            scope: "project".to_string(),
+            edit: false,
+            auto_suffix: false,
+            list_versions: false,
+            yes: false,
+            recursive: false,
+            disabled: false,
+            force: true,
+            strict: false,
+        };
+        let github = FakeGitHub::default();
+
+        let summary = run(&args, &service, &github, &CancellationToken::default()).await.unwrap();
+
+        assert_eq!(summary.added[0].id, 7);
+        assert_ne!(summary.added[0].content, "stale content");
+    }
+
+    #[derive(Default)]
+    struct FakeGitHub {
+        refs: Vec<String>,
+        directory: Vec<(String, String)>,
+    }
+
+    #[async_trait]
+    impl crate::github::GitHubClient for FakeGitHub {
+        async fn fetch_file(&self, _owner: &str, _repo: &str, _path: &str, _ref: &str) -> Result<String> {
+            unimplemented!()
+        }
+        async fn latest_commit_sha(&self, _owner: &str, _repo: &str, _ref: &str) -> Result<String> {
+            unimplemented!()
+        }
+        async fn latest_tag(&self, _owner: &str, _repo: &str) -> Result<Option<String>> {
+            unimplemented!()
+        }
+        async fn commits_between(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _from: &str,
+            _to: &str,
+        ) -> Result<Vec<String>> {
+            unimplemented!()
+        }
+        async fn list_refs(&self, _owner: &str, _repo: &str) -> Result<Vec<String>> {
+            Ok(self.refs.clone())
+        }
+        async fn fetch_directory(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _path: &str,
+            _ref_spec: &str,
+        ) -> Result<Vec<(String, String)>> {
+            Ok(self.directory.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn list_versions_skips_the_add() {
+        let refs: Vec<String> = (0..30).map(|i| format!("v0.{i}.0")).collect();
+        let github = FakeGitHub {
+            refs: refs.clone(),
+            ..Default::default()
+        };
+        let service = FakeMultiService { existing: Vec::new() };
+        let args = AddArgs {
+            source: "github:acme/skills/tools/deploy.md".to_string(),
+            name: None,
+            scope: "project".to_string(),
+            edit: false,
+            auto_suffix: false,
+            list_versions: true,
+            yes: false,
+            recursive: false,
+            disabled: false,
+            force: false,
+            strict: false,
+        };
+
+        let summary = run(&args, &service, &github, &CancellationToken::default()).await.unwrap();
+
+        assert!(summary.added.is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_versions_rejects_non_github_sources() {
+        let github = FakeGitHub::default();
+        let service = FakeMultiService { existing: Vec::new() };
+        let args = AddArgs {
+            source: "./notes.md".to_string(),
+            name: None,
+            scope: "project".to_string(),
+            edit: false,
+            auto_suffix: false,
+            list_versions: true,
+            yes: false,
+            recursive: false,
+            disabled: false,
+            force: false,
+            strict: false,
+        };
+
+        let err = run(&args, &service, &github, &CancellationToken::default()).await.unwrap_err();
+
+        assert!(matches!(err, CsmError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn invalid_name_is_rejected_with_a_slugified_suggestion() {
+        let now = chrono::Utc::now();
+        let service = FakeService {
+            skill: Mutex::new(Skill {
+                id: 0,
+                name: String::new(),
+                source: SkillSource::Inline,
+                scope: SkillScope::Project,
+                content: String::new(),
+                content_hash: String::new(),
+                previous_content: None,
+                enabled: true,
+                priority: 0,
+                update_mode: crate::models::UpdateMode::Auto,
+                update_trigger: crate::models::UpdateTrigger::OnCommit,
+                failure_count: 0,
+                last_failure_at: None,
+                archived: false,
+                archived_at: None,
+                last_known_ref: None,
+                notes: None,
+                tags: Vec::new(),
+                created_at: now,
+                updated_at: now,
+            }),
+        };
+        let github = FakeGitHub::default();
+        let args = AddArgs {
+            source: "draft.md".to_string(),
+            name: Some("My Skill!".to_string()),
+            scope: "project".to_string(),
+            edit: false,
+            auto_suffix: false,
+            list_versions: false,
+            yes: false,
+            recursive: false,
+            disabled: false,
+            force: false,
+            strict: false,
+        };
+
+        let err = run(&args, &service, &github, &CancellationToken::default()).await.unwrap_err();
+
+        match err {
+            CsmError::InvalidName(message) => assert!(message.contains("my-skill")),
+            other => panic!("expected InvalidName, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn yes_accepts_the_slugified_name_instead_of_erroring() {
+        let now = chrono::Utc::now();
+        let service = FakeService {
+            skill: Mutex::new(Skill {
+                id: 0,
+                name: String::new(),
+                source: SkillSource::Inline,
+                scope: SkillScope::Project,
+                content: String::new(),
+                content_hash: String::new(),
+                previous_content: None,
+                enabled: true,
+                priority: 0,
+                update_mode: crate::models::UpdateMode::Auto,
+                update_trigger: crate::models::UpdateTrigger::OnCommit,
+                failure_count: 0,
+                last_failure_at: None,
+                archived: false,
+                archived_at: None,
+                last_known_ref: None,
+                notes: None,
+                tags: Vec::new(),
+                created_at: now,
+                updated_at: now,
+            }),
+        };
+        let github = FakeGitHub::default();
+        let args = AddArgs {
+            source: "draft.md".to_string(),
+            name: Some("My Skill!".to_string()),
+            scope: "project".to_string(),
+            edit: false,
+            auto_suffix: false,
+            list_versions: false,
+            yes: true,
+            recursive: false,
+            disabled: false,
+            force: false,
+            strict: false,
+        };
+
+        let summary = run(&args, &service, &github, &CancellationToken::default()).await.unwrap();
+
+        assert_eq!(summary.added[0].name, "my-skill");
+    }
+
+    struct FakeMultiService {
+        existing: Vec<String>,
+    }
+
+    #[async_trait]
+    impl SkillService for FakeMultiService {
+        async fn add(&self, _name: &str, _source: SkillSource, _scope: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_or_overwrite(&self, _name: &str, _source: SkillSource, _scope: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+
+        async fn add_with_content(
+            &self,
+            name: &str,
+            source: SkillSource,
+            scope: SkillScope,
+            content: String,
+        ) -> Result<Skill> {
+            if self.existing.contains(&name.to_string()) {
+                return Err(CsmError::AlreadyExists(name.to_string()));
+            }
+            if content.trim().is_empty() {
+                return Err(CsmError::Validation(format!("skill '{name}' has empty content")));
+            }
+            let now = chrono::Utc::now();
+            Ok(Skill {
+                id: 1,
+                name: name.to_string(),
+                source,
+                scope,
+                content,
+                content_hash: "hash".to_string(),
+                previous_content: None,
+                enabled: true,
+                priority: 0,
+                update_mode: crate::models::UpdateMode::Auto,
+                update_trigger: crate::models::UpdateTrigger::OnCommit,
+                failure_count: 0,
+                last_failure_at: None,
+                archived: false,
+                archived_at: None,
+                last_known_ref: None,
+                notes: None,
+                tags: Vec::new(),
+                created_at: now,
+                updated_at: now,
+            })
+        }
+
+        async fn list(&self) -> Result<Vec<Skill>> {
+            Ok(Vec::new())
+        }
+
+        async fn update_content(&self, _name: &str, _scope: SkillScope, _content: String) -> Result<Skill> {
+            unimplemented!()
+        }
+
+        async fn merge_preview(&self, _scope: Option<SkillScope>) -> Result<crate::models::MergePreviewStats> {
+            unimplemented!()
+        }
+
+        async fn effective_list(&self) -> Result<Vec<Skill>> {
+            unimplemented!()
+        }
+
+        async fn set_note(&self, _n: &str, _sc: SkillScope, _note: Option<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn archive(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn restore(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn purge(&self, _n: &str, _sc: SkillScope) -> Result<()> {
+            unimplemented!()
+        }
+        async fn rename(&self, _n: &str, _sc: SkillScope, _new: &str) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_tags(&self, _n: &str, _sc: SkillScope, _tags: Vec<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_priority(&self, _n: &str, _sc: SkillScope, _p: i32) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_enabled(&self, _n: &str, _sc: SkillScope, _e: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn record_update_result(&self, _n: &str, _sc: SkillScope, _failed: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn rollback_content(&self, _n: &str, _sc: SkillScope) -> Result<bool> {
+            unimplemented!()
+        }
+
+    }
+
+    fn zip_with_entries(entries: &[(&str, &str)]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        {
+            let mut writer = zip::ZipWriter::new(std::io::Cursor::new(&mut buffer));
+            let options: zip::write::FileOptions<()> = zip::write::FileOptions::default();
+            for (name, content) in entries {
+                writer.start_file(*name, options).unwrap();
+                std::io::Write::write_all(&mut writer, content.as_bytes()).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        buffer
+    }
+
+    #[tokio::test]
+    async fn adding_a_local_directory_imports_each_md_file_and_reports_the_rest() {
+        let dir = std::env::temp_dir().join("csm_test_add_local_directory");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("new.md"), "# new").unwrap();
+        std::fs::write(dir.join("dup.md"), "# dup").unwrap();
+        std::fs::write(dir.join("empty.md"), "").unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a skill").unwrap();
+
+        let service = FakeMultiService {
+            existing: vec!["dup".to_string()],
+        };
+        let args = AddArgs {
+            source: dir.to_str().unwrap().to_string(),
+            name: None,
+            scope: "project".to_string(),
+            edit: false,
+            auto_suffix: false,
+            list_versions: false,
+            yes: false,
+            recursive: false,
+            disabled: false,
+            force: false,
+            strict: false,
+        };
+        let github = FakeGitHub::default();
+
+        let summary = run(&args, &service, &github, &CancellationToken::default()).await.unwrap();
+
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(summary.added.len(), 1);
+        assert_eq!(summary.added[0].name, "new");
+        assert_eq!(summary.skipped_existing, vec!["dup".to_string()]);
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].0, "empty");
+    }
+
+    #[tokio::test]
+    async fn archive_add_summarizes_new_existing_and_invalid_entries() {
+        let bytes = zip_with_entries(&[
+            ("new.md", "# new"),
+            ("dup.md", "# dup"),
+            ("empty.md", ""),
+        ]);
+        let path = std::env::temp_dir().join("csm_test_add_summary_bundle.zip");
+        std::fs::write(&path, &bytes).unwrap();
+
+        let service = FakeMultiService {
+            existing: vec!["dup".to_string()],
+        };
+        let summary = add_from_archive(
+            path.to_str().unwrap(),
+            SkillScope::Project,
+            &service,
+            &CancellationToken::default(),
+        )
+            .await
+            .unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(summary.added.len(), 1);
+        assert_eq!(summary.skipped_existing, vec!["dup".to_string()]);
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].0, "empty");
+        assert!(!summary.all_failed());
+    }
+
+    #[tokio::test]
+    async fn recursive_add_names_each_skill_after_its_containing_directory_and_skips_existing() {
+        let service = FakeMultiService {
+            existing: vec!["python".to_string()],
+        };
+        let github = FakeGitHub {
+            directory: vec![
+                ("skills/typescript/CLAUDE.md".to_string(), "# ts".to_string()),
+                ("skills/python/CLAUDE.md".to_string(), "# py".to_string()),
+            ],
+            ..Default::default()
+        };
+        let args = AddArgs {
+            source: "github:acme/skills/skills".to_string(),
+            name: None,
+            scope: "project".to_string(),
+            edit: false,
+            auto_suffix: false,
+            list_versions: false,
+            yes: false,
+            recursive: true,
+            disabled: false,
+            force: false,
+            strict: false,
+        };
+
+        let summary = run(&args, &service, &github, &CancellationToken::default()).await.unwrap();
+
+        assert_eq!(summary.added.len(), 1);
+        assert_eq!(summary.added[0].name, "typescript");
+        assert_eq!(summary.skipped_existing, vec!["python".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn cancelling_before_the_batch_starts_stops_the_archive_import_immediately() {
+        let bytes = zip_with_entries(&[("first.md", "# first"), ("second.md", "# second")]);
+        let path = std::env::temp_dir().join("csm_test_add_cancel_bundle.zip");
+        std::fs::write(&path, &bytes).unwrap();
+
+        let service = FakeMultiService { existing: Vec::new() };
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        let summary = add_from_archive(path.to_str().unwrap(), SkillScope::Project, &service, &cancel)
+            .await
+            .unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(summary.interrupted);
+        assert!(summary.added.is_empty());
+    }
+
+    struct FakeHtmlService {
+        purged: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl SkillService for FakeHtmlService {
+        async fn add(&self, name: &str, source: SkillSource, scope: SkillScope) -> Result<Skill> {
+            let now = chrono::Utc::now();
+            Ok(Skill {
+                id: 1,
+                name: name.to_string(),
+                source,
+                scope,
+                content: "<!DOCTYPE html><html><body>Please sign in</body></html>".to_string(),
+                content_hash: "hash".to_string(),
+                previous_content: None,
+                enabled: true,
+                priority: 0,
+                update_mode: crate::models::UpdateMode::Auto,
+                update_trigger: crate::models::UpdateTrigger::OnCommit,
+                failure_count: 0,
+                last_failure_at: None,
+                archived: false,
+                archived_at: None,
+                last_known_ref: None,
+                notes: None,
+                tags: Vec::new(),
+                created_at: now,
+                updated_at: now,
+            })
+        }
+        async fn add_or_overwrite(&self, _n: &str, _s: SkillSource, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_with_content(
+            &self,
+            _n: &str,
+            _s: SkillSource,
+            _sc: SkillScope,
+            _c: String,
+        ) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn list(&self) -> Result<Vec<Skill>> {
+            unimplemented!()
+        }
+        async fn update_content(&self, _n: &str, _sc: SkillScope, _c: String) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn merge_preview(&self, _scope: Option<SkillScope>) -> Result<crate::models::MergePreviewStats> {
+            unimplemented!()
+        }
+        async fn effective_list(&self) -> Result<Vec<Skill>> {
+            unimplemented!()
+        }
+        async fn set_note(&self, _n: &str, _sc: SkillScope, _note: Option<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn archive(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn restore(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn purge(&self, n: &str, _sc: SkillScope) -> Result<()> {
+            self.purged.lock().unwrap().push(n.to_string());
+            Ok(())
+        }
+        async fn rename(&self, _n: &str, _sc: SkillScope, _new: &str) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_tags(&self, _n: &str, _sc: SkillScope, _tags: Vec<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_priority(&self, _n: &str, _sc: SkillScope, _p: i32) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_enabled(&self, _n: &str, _sc: SkillScope, _e: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn record_update_result(&self, _n: &str, _sc: SkillScope, _failed: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn rollback_content(&self, _n: &str, _sc: SkillScope) -> Result<bool> {
+            unimplemented!()
+        }
+    }
+
+    fn html_add_args(strict: bool) -> AddArgs {
+        AddArgs {
+            source: "https://example.com/skill.md".to_string(),
+            name: Some("wall".to_string()),
+            scope: "project".to_string(),
+            edit: false,
+            auto_suffix: false,
+            list_versions: false,
+            yes: false,
+            recursive: false,
+            disabled: false,
+            force: false,
+            strict,
+        }
+    }
+
+    #[tokio::test]
+    async fn an_html_error_page_is_a_soft_warning_by_default() {
+        let service = FakeHtmlService { purged: Mutex::new(Vec::new()) };
+        let github = FakeGitHub::default();
+
+        let summary = run(&html_add_args(false), &service, &github, &CancellationToken::default())
+            .await
+            .unwrap();
+
+        assert_eq!(summary.added.len(), 1);
+        assert!(service.purged.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn strict_mode_rejects_an_html_error_page_and_purges_it() {
+        let service = FakeHtmlService { purged: Mutex::new(Vec::new()) };
+        let github = FakeGitHub::default();
+
+        let err = run(&html_add_args(true), &service, &github, &CancellationToken::default())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, CsmError::InvalidContent(_)));
+        assert_eq!(*service.purged.lock().unwrap(), vec!["wall".to_string()]);
+    }
+
+    #[test]
+    fn a_bare_word_with_no_existing_file_is_ambiguous() {
+        assert!(is_ambiguous_bare_source("myskill"));
+    }
+
+    #[test]
+    fn a_slash_containing_source_is_never_ambiguous() {
+        assert!(!is_ambiguous_bare_source("owner/repo"));
+    }
+
+    #[test]
+    fn an_existing_local_file_is_not_ambiguous() {
+        let path = std::env::temp_dir().join("csm_test_add_ambiguity_existing.md");
+        std::fs::write(&path, "content").unwrap();
+
+        assert!(!is_ambiguous_bare_source(&path.to_string_lossy()));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn answering_local_at_the_prompt_keeps_the_original_local_source() {
+        let mut input = std::io::Cursor::new(b"l\n".to_vec());
+        let fallback = SkillSource::Local("myskill".to_string());
+
+        let resolved = clarify_ambiguous_source("myskill", fallback.clone(), &mut input);
+
+        assert_eq!(resolved, fallback);
+    }
+
+    #[test]
+    fn answering_github_at_the_prompt_resolves_to_a_github_source() {
+        let mut input = std::io::Cursor::new(b"g\nacme/skills\n".to_vec());
+        let fallback = SkillSource::Local("myskill".to_string());
+
+        let resolved = clarify_ambiguous_source("myskill", fallback, &mut input);
+
+        assert!(matches!(
+            resolved,
+            SkillSource::GitHub { ref owner, ref repo, .. } if owner == "acme" && repo == "skills"
+        ));
+    }
+
+    #[test]
+    fn an_unrecognized_answer_keeps_the_fallback_local_source() {
+        let mut input = std::io::Cursor::new(b"???\n".to_vec());
+        let fallback = SkillSource::Local("myskill".to_string());
+
+        let resolved = clarify_ambiguous_source("myskill", fallback.clone(), &mut input);
+
+        assert_eq!(resolved, fallback);
+    }
+}
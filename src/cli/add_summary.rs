@@ -0,0 +1,74 @@
+use std::fmt;
+
+use crate::models::Skill;
+
+/// Outcome of an `add` that may touch several skills at once (a directory,
+/// archive, or GitHub-dir source), so a partial failure doesn't hide the
+/// skills that did make it in.
+#[derive(Debug, Default)]
+pub struct AddSummary {
+    pub added: Vec<Skill>,
+    pub skipped_existing: Vec<String>,
+    pub failed: Vec<(String, String)>,
+    /// Set when a `CancellationToken` stopped the batch early (Ctrl-C),
+    /// after finishing whichever skill was already in progress.
+    pub interrupted: bool,
+}
+
+impl AddSummary {
+    /// True only when every candidate failed and nothing was added or
+    /// skipped, the sole case that should exit the process nonzero.
+    pub fn all_failed(&self) -> bool {
+        !self.failed.is_empty() && self.added.is_empty() && self.skipped_existing.is_empty()
+    }
+}
+
+impl fmt::Display for AddSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Added: {}, Skipped (existing): {}, Failed: {}",
+            self.added.len(),
+            self.skipped_existing.len(),
+            self.failed.len()
+        )?;
+        for (name, reason) in &self.failed {
+            write!(f, "\n  {name}: {reason}")?;
+        }
+        if self.interrupted {
+            write!(
+                f,
+                "\ninterrupted after {} item(s)",
+                self.added.len() + self.skipped_existing.len() + self.failed.len()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_failed_is_false_when_anything_succeeded_or_was_skipped() {
+        let mostly_ok = AddSummary {
+            added: vec![],
+            skipped_existing: vec!["dup".to_string()],
+            failed: vec![("bad".to_string(), "boom".to_string())],
+            interrupted: false,
+        };
+        assert!(!mostly_ok.all_failed());
+    }
+
+    #[test]
+    fn all_failed_is_true_when_only_failures_occurred() {
+        let all_bad = AddSummary {
+            added: vec![],
+            skipped_existing: vec![],
+            failed: vec![("bad".to_string(), "boom".to_string())],
+            interrupted: false,
+        };
+        assert!(all_bad.all_failed());
+    }
+}
@@ -0,0 +1,252 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Subcommand};
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::error::{CsmError, Result};
+
+#[derive(Debug, Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Sets a config key, e.g. `github.token`.
+    Set {
+        key: String,
+        value: String,
+
+        /// Show the old -> new value without writing anything.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Reads back a config key previously set with `config set`.
+    Get { key: String },
+    /// Prints the resolved config, database, and cache paths.
+    Path {
+        /// Emit the paths as JSON instead of human-readable lines.
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+pub fn run(args: &ConfigArgs, config_path: &Path) -> Result<String> {
+    match &args.command {
+        ConfigCommand::Set { key, value, dry_run } => match key.as_str() {
+            "github.token" if *dry_run => {
+                let old = read_github_token(config_path)?.unwrap_or_else(|| "<unset>".to_string());
+                Ok(format!(
+                    "github.token: {old} -> {value} (dry run, nothing written)"
+                ))
+            }
+            "github.token" => set_github_token(value, config_path),
+            other => Err(CsmError::Validation(format!("unknown config key '{other}'"))),
+        },
+        ConfigCommand::Get { key } => match key.as_str() {
+            "github.token" => Ok(read_github_token(config_path)?.unwrap_or_else(|| "<unset>".to_string())),
+            other => Err(CsmError::Validation(format!("unknown config key '{other}'"))),
+        },
+        ConfigCommand::Path { .. } => Err(CsmError::Other(
+            "config path must be handled via run_path, not run".to_string(),
+        )),
+    }
+}
+
+/// Paths `csm` resolved for this invocation, for `csm config path`.
+#[derive(Debug, Serialize)]
+struct ResolvedPaths {
+    config: PathBuf,
+    database: PathBuf,
+    cache: PathBuf,
+    claude_md: PathBuf,
+}
+
+/// Handles `ConfigCommand::Path` directly against a resolved `Config`,
+/// separately from `run` (which only needs a bare `config.toml` path for
+/// `set`/`get`). Mirrors `export::run`/`export::run_full`'s split for
+/// commands that need different amounts of context.
+pub fn run_path(config: &Config, json: bool) -> String {
+    let paths = ResolvedPaths {
+        config: config.csm_home.join("config.toml"),
+        database: config.csm_home.join("csm.db"),
+        cache: config.csm_home.join("cache"),
+        claude_md: config.csm_home.join("CLAUDE.md"),
+    };
+
+    if json {
+        return serde_json::to_string_pretty(&paths).unwrap_or_default();
+    }
+
+    format!(
+        "config:    {}\ndatabase:  {}\ncache:     {}\nCLAUDE.md: {}",
+        paths.config.display(),
+        paths.database.display(),
+        paths.cache.display(),
+        paths.claude_md.display(),
+    )
+}
+
+/// Reads `github.token` back from wherever `set_github_token` may have put
+/// it: the system keyring first, then `config.toml`. `None` means unset in
+/// both places, not an error.
+fn read_github_token(config_path: &Path) -> Result<Option<String>> {
+    if let Ok(entry) = keyring::Entry::new("csm", "github.token") {
+        if let Ok(password) = entry.get_password() {
+            return Ok(Some(password));
+        }
+    }
+
+    let existing = std::fs::read_to_string(config_path).unwrap_or_default();
+    let doc: toml::Value = toml::from_str(&existing).unwrap_or(toml::Value::Table(Default::default()));
+    Ok(doc
+        .get("github")
+        .and_then(|g| g.get("token"))
+        .and_then(|t| t.as_str())
+        .map(str::to_string))
+}
+
+/// Stores into the system keyring when one is reachable, since a token
+/// written to `config.toml` sits in plaintext on disk. Falls back to
+/// plaintext only when no keyring backend is available (e.g. headless CI).
+fn set_github_token(value: &str, config_path: &Path) -> Result<String> {
+    let stored_in_keyring = keyring::Entry::new("csm", "github.token")
+        .and_then(|entry| entry.set_password(value))
+        .is_ok();
+
+    if stored_in_keyring {
+        return Ok("github.token stored in the system keyring".to_string());
+    }
+
+    write_plaintext_token(value, config_path)?;
+    Ok("github.token stored in config.toml (no keyring backend available)".to_string())
+}
+
+/// Merges `github.token = value` into `config.toml`, preserving whatever
+/// else is already there rather than overwriting the whole file.
+fn write_plaintext_token(value: &str, config_path: &Path) -> Result<()> {
+    let existing = std::fs::read_to_string(config_path).unwrap_or_default();
+    let mut doc: toml::Value =
+        toml::from_str(&existing).unwrap_or_else(|_| toml::Value::Table(Default::default()));
+
+    let table = doc
+        .as_table_mut()
+        .ok_or_else(|| CsmError::Other("config.toml is not a table".to_string()))?;
+    let github = table
+        .entry("github")
+        .or_insert_with(|| toml::Value::Table(Default::default()));
+    let github_table = github
+        .as_table_mut()
+        .ok_or_else(|| CsmError::Other("github section of config.toml is not a table".to_string()))?;
+    github_table.insert("token".to_string(), toml::Value::String(value.to_string()));
+
+    let serialized = toml::to_string(&doc)
+        .map_err(|e| CsmError::Other(format!("failed to serialize config: {e}")))?;
+    std::fs::write(config_path, serialized)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_an_unknown_config_key() {
+        let path = std::env::temp_dir().join("csm_test_config_unknown_key.toml");
+        let args = ConfigArgs {
+            command: ConfigCommand::Set {
+                key: "database.pragmas".to_string(),
+                value: "x".to_string(),
+                dry_run: false,
+            },
+        };
+
+        let err = run(&args, &path).unwrap_err();
+
+        assert!(matches!(err, CsmError::Validation(_)));
+    }
+
+    #[test]
+    fn dry_run_reports_the_change_without_writing_it() {
+        let path = std::env::temp_dir().join("csm_test_config_dry_run.toml");
+        let _ = std::fs::remove_file(&path);
+
+        let output = run(
+            &ConfigArgs {
+                command: ConfigCommand::Set {
+                    key: "github.token".to_string(),
+                    value: "new-secret".to_string(),
+                    dry_run: true,
+                },
+            },
+            &path,
+        )
+        .unwrap();
+
+        assert!(output.contains("<unset>"));
+        assert!(output.contains("new-secret"));
+
+        let after = run(
+            &ConfigArgs {
+                command: ConfigCommand::Get {
+                    key: "github.token".to_string(),
+                },
+            },
+            &path,
+        )
+        .unwrap();
+        assert_eq!(after, "<unset>");
+    }
+
+    #[test]
+    fn printed_database_path_matches_the_resolved_csm_home() {
+        let config = Config {
+            csm_home: std::path::PathBuf::from("/home/user/.csm"),
+            database: Default::default(),
+            github: Default::default(),
+            merge: Default::default(),
+            general: Default::default(),
+            conflicts: Default::default(),
+        };
+
+        let output = run_path(&config, false);
+
+        assert!(output.contains("database:  /home/user/.csm/csm.db"));
+    }
+
+    #[test]
+    fn json_path_output_includes_the_database_path() {
+        let config = Config {
+            csm_home: std::path::PathBuf::from("/home/user/.csm"),
+            database: Default::default(),
+            github: Default::default(),
+            merge: Default::default(),
+            general: Default::default(),
+            conflicts: Default::default(),
+        };
+
+        let output = run_path(&config, true);
+
+        assert!(output.contains("\"database\": \"/home/user/.csm/csm.db\""));
+    }
+
+    #[test]
+    fn write_plaintext_token_preserves_existing_sections() {
+        let path = std::env::temp_dir().join("csm_test_config_write_token.toml");
+        std::fs::write(&path, "[database]\npragmas = [\"foreign_keys = ON\"]\n").unwrap();
+
+        write_plaintext_token("gh-secret", &path).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let doc: toml::Value = toml::from_str(&contents).unwrap();
+        assert_eq!(doc["github"]["token"].as_str(), Some("gh-secret"));
+        assert_eq!(
+            doc["database"]["pragmas"][0].as_str(),
+            Some("foreign_keys = ON")
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
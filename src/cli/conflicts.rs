@@ -0,0 +1,295 @@
+use clap::Args;
+use serde::Serialize;
+
+use crate::error::{CsmError, Result};
+use crate::models::{Conflict, Skill};
+use crate::services::{ConflictService, SkillService};
+
+#[derive(Debug, Args)]
+pub struct ConflictsArgs {
+    /// Writes the report to this path instead of printing a summary.
+    #[arg(long)]
+    pub export: Option<std::path::PathBuf>,
+
+    /// `json` (the default) or `markdown`.
+    #[arg(long, default_value = "json")]
+    pub format: String,
+
+    /// Forgets every pair whitelisted via a previous `Ignore` resolution, so
+    /// `detect` surfaces them again if they still contradict. Skips
+    /// detection entirely when passed.
+    #[arg(long)]
+    pub clear_whitelist: bool,
+}
+
+/// A conflict with its skill ids resolved to names, for export.
+#[derive(Debug, Serialize)]
+struct ConflictEntry {
+    skill_a: String,
+    skill_b: String,
+    status: String,
+    description: String,
+    severity: u8,
+    /// A next step spelled out as a runnable `csm` command against these
+    /// two skills, resolved at display time (see `suggestion_for`).
+    suggestion: String,
+}
+
+pub async fn run(
+    args: &ConflictsArgs,
+    conflicts: &dyn ConflictService,
+    skills: &dyn SkillService,
+) -> Result<String> {
+    if args.clear_whitelist {
+        conflicts.clear_whitelist().await?;
+        return Ok("cleared the conflict whitelist".to_string());
+    }
+
+    let mut detected = conflicts.detect().await?;
+    detected.sort_by(|a, b| b.severity.cmp(&a.severity));
+    let all_skills = skills.list().await?;
+    let skill_for = |id: i64| -> Option<&Skill> { all_skills.iter().find(|s| s.id == id) };
+    let name_for = |id: i64| -> String {
+        skill_for(id).map(|s| s.name.clone()).unwrap_or_else(|| format!("#{id}"))
+    };
+
+    let entries: Vec<ConflictEntry> = detected
+        .iter()
+        .map(|c| ConflictEntry {
+            skill_a: name_for(c.skill_a_id),
+            skill_b: name_for(c.skill_b_id),
+            status: format!("{:?}", c.status),
+            description: c.description.clone(),
+            severity: c.severity,
+            suggestion: suggestion_for(skill_for(c.skill_a_id), skill_for(c.skill_b_id)),
+        })
+        .collect();
+
+    let rendered = match args.format.as_str() {
+        "json" => serde_json::to_string_pretty(&entries)?,
+        "markdown" => render_markdown(&entries),
+        other => {
+            return Err(CsmError::Validation(format!(
+                "unknown conflicts format '{other}'; expected 'json' or 'markdown'"
+            )))
+        }
+    };
+
+    match &args.export {
+        Some(path) => {
+            std::fs::write(path, &rendered)?;
+            Ok(format!(
+                "wrote {} conflict(s) to {}",
+                entries.len(),
+                path.display()
+            ))
+        }
+        None => Ok(rendered),
+    }
+}
+
+/// Builds the "what to do next" line for a conflict as runnable `csm`
+/// commands against the two actual skills, so it can be copy-pasted instead
+/// of paraphrased. Falls back to a generic pointer at `csm conflicts
+/// --clear-whitelist` for a side whose skill has since been deleted (`id`
+/// no longer resolves), since there's no name left to build a command with.
+fn suggestion_for(skill_a: Option<&Skill>, skill_b: Option<&Skill>) -> String {
+    let (Some(a), Some(b)) = (skill_a, skill_b) else {
+        return "one side of this conflict no longer exists; `csm conflicts --clear-whitelist` to reconsider a prior ignore".to_string();
+    };
+    let bump = a.priority.max(b.priority) + 10;
+    format!(
+        "disable one of them (`csm remove {a_name}` or `csm remove {b_name}`), rank one above the other (`csm priority set {a_name} {bump}`), or `csm conflicts --clear-whitelist` to reconsider a prior ignore",
+        a_name = a.name,
+        b_name = b.name,
+    )
+}
+
+fn render_markdown(entries: &[ConflictEntry]) -> String {
+    if entries.is_empty() {
+        return "No conflicts detected.".to_string();
+    }
+    entries
+        .iter()
+        .map(|e| {
+            format!(
+                "## {} vs {} (severity {})\n- Type: Contradiction\n- Status: {}\n\n```\n{}\n```\n\n- Suggestion: {}\n",
+                e.skill_a, e.skill_b, e.severity, e.status, e.description, e.suggestion
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ConflictStatus, Skill, SkillScope, SkillSource, UpdateMode, UpdateTrigger};
+    use async_trait::async_trait;
+
+    struct FakeConflicts(Vec<Conflict>);
+
+    #[async_trait]
+    impl ConflictService for FakeConflicts {
+        async fn detect(&self) -> Result<Vec<Conflict>> {
+            Ok(self.0.clone())
+        }
+        async fn conflicts_for_skill(&self, _skill_id: i64) -> Result<Vec<Conflict>> {
+            unimplemented!()
+        }
+        async fn restore(&self, _conflict: Conflict) -> Result<Conflict> {
+            unimplemented!()
+        }
+        async fn ignore(&self, _conflict_id: i64) -> Result<crate::models::Conflict> {
+            unimplemented!()
+        }
+        async fn clear_whitelist(&self) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    use crate::test_support::StubSkillService;
+
+    fn skill(id: i64, name: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn exported_json_report_names_both_sides_of_each_conflict() {
+        let conflict = Conflict {
+            id: 1,
+            skill_a_id: 1,
+            skill_b_id: 2,
+            description: "'always' in a vs 'never' in b".to_string(),
+            status: ConflictStatus::Unresolved,
+            detected_at: chrono::Utc::now(),
+            severity: 200,
+        };
+        let conflicts = FakeConflicts(vec![conflict]);
+        let skills = StubSkillService::new(vec![skill(1, "a"), skill(2, "b")]);
+        let path = std::env::temp_dir().join("csm_test_conflicts_export.json");
+
+        let message = run(
+            &ConflictsArgs {
+                export: Some(path.clone()),
+                format: "json".to_string(),
+                clear_whitelist: false,
+            },
+            &conflicts,
+            &skills,
+        )
+        .await
+        .unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(message.contains("1 conflict(s)"));
+        assert!(contents.contains("\"skill_a\": \"a\""));
+        assert!(contents.contains("\"skill_b\": \"b\""));
+    }
+
+    #[tokio::test]
+    async fn markdown_format_summarizes_each_conflict() {
+        let conflict = Conflict {
+            id: 1,
+            skill_a_id: 1,
+            skill_b_id: 2,
+            description: "'always' in a vs 'never' in b".to_string(),
+            status: ConflictStatus::Unresolved,
+            detected_at: chrono::Utc::now(),
+            severity: 200,
+        };
+        let conflicts = FakeConflicts(vec![conflict]);
+        let skills = StubSkillService::new(vec![skill(1, "a"), skill(2, "b")]);
+
+        let output = run(
+            &ConflictsArgs {
+                export: None,
+                format: "markdown".to_string(),
+                clear_whitelist: false,
+            },
+            &conflicts,
+            &skills,
+        )
+        .await
+        .unwrap();
+
+        assert!(output.contains("## a vs b"));
+        assert!(output.contains("'always' in a vs 'never' in b"));
+        assert!(output.contains("- Type: Contradiction"));
+        assert!(output.contains("- Suggestion:"));
+    }
+
+    #[tokio::test]
+    async fn suggestion_contains_a_runnable_command_with_the_real_skill_name() {
+        let conflict = Conflict {
+            id: 1,
+            skill_a_id: 1,
+            skill_b_id: 2,
+            description: "'always' in a vs 'never' in b".to_string(),
+            status: ConflictStatus::Unresolved,
+            detected_at: chrono::Utc::now(),
+            severity: 200,
+        };
+        let conflicts = FakeConflicts(vec![conflict]);
+        let skills = StubSkillService::new(vec![skill(1, "eager-safety"), skill(2, "cautious-safety")]);
+
+        let output = run(
+            &ConflictsArgs {
+                export: None,
+                format: "json".to_string(),
+                clear_whitelist: false,
+            },
+            &conflicts,
+            &skills,
+        )
+        .await
+        .unwrap();
+
+        assert!(output.contains("csm remove eager-safety"));
+        assert!(output.contains("csm priority set eager-safety"));
+    }
+
+    #[tokio::test]
+    async fn markdown_report_for_an_empty_conflict_set_says_so_rather_than_being_blank() {
+        let conflicts = FakeConflicts(Vec::new());
+        let skills = StubSkillService::new(Vec::new());
+
+        let output = run(
+            &ConflictsArgs {
+                export: None,
+                format: "markdown".to_string(),
+                clear_whitelist: false,
+            },
+            &conflicts,
+            &skills,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output, "No conflicts detected.");
+    }
+}
@@ -0,0 +1,153 @@
+use clap::Args;
+
+use crate::cli::resolve::resolve_skill_name;
+use crate::error::Result;
+use crate::services::SkillService;
+use crate::utils::diff::{diff_lines, trim_context, DiffLine, DiffLineKind};
+
+#[derive(Debug, Args)]
+pub struct DiffArgs {
+    pub a: String,
+    pub b: String,
+
+    /// Emit the diff as structured hunks instead of unified-diff text.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Lines of unchanged context kept around each hunk. `0` shows only
+    /// changed lines.
+    #[arg(long, default_value_t = 3)]
+    pub context: usize,
+}
+
+pub async fn run(args: &DiffArgs, service: &dyn SkillService) -> Result<String> {
+    let all = service.list().await?;
+    let a = resolve_skill_name(&all, &args.a)?;
+    let b = resolve_skill_name(&all, &args.b)?;
+
+    let lines = trim_context(diff_lines(&a.content, &b.content), args.context);
+
+    if args.json {
+        return Ok(serde_json::to_string_pretty(&lines)?);
+    }
+
+    let mut out = format!("--- {}\n+++ {}\n", a.name, b.name);
+    for line in &lines {
+        let prefix = match line.kind {
+            DiffLineKind::Context => ' ',
+            DiffLineKind::Removed => '-',
+            DiffLineKind::Added => '+',
+        };
+        out.push(prefix);
+        out.push_str(&line.text);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::CsmError;
+    use crate::models::{Skill, SkillScope, SkillSource, UpdateMode, UpdateTrigger};
+
+    use crate::test_support::StubSkillService;
+
+    fn skill(name: &str, content: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: content.to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::Manual,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn plain_diff_reports_added_and_removed_lines() {
+        let service = StubSkillService::new(vec![
+            skill("a", "shared\nold line"),
+            skill("b", "shared\nnew line"),
+        ]);
+
+        let output = run(
+            &DiffArgs { a: "a".to_string(), b: "b".to_string(), json: false, context: 3 },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert!(output.contains("-old line"));
+        assert!(output.contains("+new line"));
+        assert!(output.contains(" shared"));
+    }
+
+    #[tokio::test]
+    async fn json_diff_emits_structured_hunks() {
+        let service = StubSkillService::new(vec![
+            skill("a", "shared\nold line"),
+            skill("b", "shared\nnew line"),
+        ]);
+
+        let output = run(
+            &DiffArgs { a: "a".to_string(), b: "b".to_string(), json: true, context: 3 },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        let parsed: Vec<DiffLine> = serde_json::from_str(&output).unwrap();
+        assert!(parsed.iter().any(|l| l.kind == DiffLineKind::Removed && l.text == "old line"));
+        assert!(parsed.iter().any(|l| l.kind == DiffLineKind::Added && l.text == "new line"));
+    }
+
+    #[tokio::test]
+    async fn context_flag_limits_how_many_unchanged_lines_surround_a_hunk() {
+        let service = StubSkillService::new(vec![
+            skill("a", "l1\nl2\nl3\nchanged\nl5\nl6\nl7"),
+            skill("b", "l1\nl2\nl3\nCHANGED\nl5\nl6\nl7"),
+        ]);
+
+        let output = run(
+            &DiffArgs { a: "a".to_string(), b: "b".to_string(), json: false, context: 1 },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert!(output.contains(" l3"));
+        assert!(output.contains(" l5"));
+        assert!(!output.contains("l2"), "context beyond the requested window is trimmed");
+        assert!(!output.contains("l6"), "context beyond the requested window is trimmed");
+    }
+
+    #[tokio::test]
+    async fn diffing_a_missing_skill_errors_clearly() {
+        let service = StubSkillService::new(vec![skill("a", "content")]);
+
+        let err = run(
+            &DiffArgs { a: "a".to_string(), b: "missing".to_string(), json: false, context: 3 },
+            &service,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, CsmError::NotFound(_)));
+    }
+}
@@ -0,0 +1,579 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::Path;
+
+use clap::Args;
+
+use crate::error::Result;
+use crate::models::{Skill, SkillSource};
+use crate::services::{MergeService, RebuildSummary, SkillService};
+use crate::utils::hash::hash_file_streaming;
+use crate::utils::headings::extract_headings;
+
+#[derive(Debug, Args)]
+pub struct DoctorArgs {
+    /// Rebuild `CLAUDE.md` from the enabled skill set instead of only reporting drift.
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Report how many lines CLAUDE.md would change on --fix, without
+    /// writing anything. Useful as a CI gate ("run csm doctor --fix").
+    #[arg(long, conflicts_with = "fix")]
+    pub dry_run: bool,
+
+    /// Restore CLAUDE.md from the backup taken by the most recent --fix,
+    /// undoing it. Fails if no backup exists yet.
+    #[arg(long, conflicts_with_all = ["fix", "dry_run"])]
+    pub restore_backup: bool,
+
+    /// Print a unified diff of what --fix would write against the current
+    /// CLAUDE.md, without writing anything. The changed-line count is
+    /// reported only when --dry-run is passed as well.
+    #[arg(long, conflicts_with = "fix")]
+    pub diff: bool,
+}
+
+/// Drift between the enabled, effective skill set and what's actually
+/// merged into `CLAUDE.md`, as tracked by matching `##` heading titles.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DoctorReport {
+    pub missing: Vec<String>,
+    pub extra: Vec<String>,
+    pub fixed: bool,
+    /// Set only when `--dry-run` was passed: the number of lines that
+    /// differ between the on-disk `CLAUDE.md` and what `--fix` would write.
+    pub lines_changed: Option<usize>,
+    /// The schema version this build of `csm` expects the skills database
+    /// to be migrated to (see `repository::migrations`).
+    pub schema_version: Option<i64>,
+    /// Names of `SkillSource::Local` skills whose backing file no longer
+    /// hashes to the stored `content_hash`, i.e. the DB row is stale
+    /// relative to what's actually on disk. `--fix` does not touch these;
+    /// re-run `csm add` to pick up the new content (`list --changed` lists them).
+    pub drifted: Vec<String>,
+    /// Raw persistence-layer corruption from `SkillService::integrity_issues`
+    /// (e.g. a `scope` column that no longer parses), one message per bad
+    /// row. `--fix` cannot safely re-derive these, so it only flags them;
+    /// resolving one means fixing the row by hand and re-running `doctor`.
+    pub corrupt_rows: Vec<String>,
+    /// Set when `--restore-backup` was passed and it succeeded.
+    pub restored: bool,
+    /// Set when `--fix` actually rebuilt `CLAUDE.md`, giving visibility into
+    /// what the rebuild folded in.
+    pub rebuild_summary: Option<RebuildSummary>,
+    /// Set when `--diff` was passed: a unified diff of the on-disk
+    /// CLAUDE.md against what `--fix` would write.
+    pub diff: Option<String>,
+}
+
+impl DoctorReport {
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty()
+    }
+
+    /// Whether `CLAUDE.md` matches the enabled skill set exactly, including
+    /// content that `--dry-run` catches but heading-only `is_clean` doesn't.
+    pub fn is_up_to_date(&self) -> bool {
+        self.is_clean() && self.lines_changed.unwrap_or(0) == 0
+    }
+}
+
+impl fmt::Display for DoctorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.restored {
+            return write!(f, "restored CLAUDE.md from backup");
+        }
+        if self.is_up_to_date() && self.drifted.is_empty() && self.corrupt_rows.is_empty() {
+            return write!(f, "CLAUDE.md is in sync with the enabled skill set");
+        }
+        if !self.missing.is_empty() {
+            writeln!(f, "missing from CLAUDE.md: {}", self.missing.join(", "))?;
+        }
+        if !self.extra.is_empty() {
+            writeln!(f, "should not be in CLAUDE.md: {}", self.extra.join(", "))?;
+        }
+        if let Some(lines_changed) = self.lines_changed {
+            if lines_changed > 0 {
+                writeln!(f, "{lines_changed} line(s) of CLAUDE.md would change (dry run, nothing written)")?;
+            }
+        }
+        if let Some(diff) = &self.diff {
+            write!(f, "{diff}")?;
+        }
+        if !self.drifted.is_empty() {
+            writeln!(
+                f,
+                "stale content_hash for locally-edited skill(s): {}",
+                self.drifted.join(", ")
+            )?;
+        }
+        if !self.corrupt_rows.is_empty() {
+            writeln!(f, "corrupt row(s), fix by hand and re-run doctor:")?;
+            for issue in &self.corrupt_rows {
+                writeln!(f, "  {issue}")?;
+            }
+        }
+        if self.fixed {
+            write!(f, "rebuilt CLAUDE.md to match")?;
+            if let Some(summary) = &self.rebuild_summary {
+                write!(f, " ({} skill(s), {} byte(s))", summary.skill_count, summary.bytes)?;
+            }
+        } else if !self.is_clean() {
+            write!(f, "run with --fix to rebuild CLAUDE.md")?;
+        } else {
+            write!(f, "run without --dry-run to rebuild CLAUDE.md")?;
+        }
+        if let Some(schema_version) = self.schema_version {
+            write!(f, " (schema version {schema_version})")?;
+        }
+        Ok(())
+    }
+}
+
+pub async fn run(
+    args: &DoctorArgs,
+    service: &dyn SkillService,
+    merger: &dyn MergeService,
+    claude_md_path: &Path,
+    schema_version: Option<i64>,
+) -> Result<DoctorReport> {
+    if args.restore_backup {
+        merger.restore_backup().await?;
+        return Ok(DoctorReport {
+            restored: true,
+            schema_version,
+            ..Default::default()
+        });
+    }
+
+    let want_diff = args.dry_run || args.diff;
+
+    let enabled: Vec<_> = service
+        .effective_list()
+        .await?
+        .into_iter()
+        .filter(|s| s.enabled)
+        .collect();
+    let expected: BTreeSet<String> = enabled.iter().map(|s| s.name.clone()).collect();
+
+    let existing = std::fs::read_to_string(claude_md_path).unwrap_or_default();
+    let present: BTreeSet<String> = extract_headings(&existing)
+        .into_iter()
+        .filter(|h| h.level == 2)
+        .map(|h| h.title)
+        .collect();
+
+    let mut report = DoctorReport {
+        missing: expected.difference(&present).cloned().collect(),
+        extra: present.difference(&expected).cloned().collect(),
+        fixed: false,
+        lines_changed: None,
+        schema_version,
+        drifted: enabled.iter().filter_map(|s| drifted_name(s)).collect(),
+        corrupt_rows: service.integrity_issues().await?,
+        restored: false,
+        rebuild_summary: None,
+        diff: None,
+    };
+
+    if want_diff {
+        let rendered = crate::services::merge::render_merged(&enabled);
+        if args.dry_run {
+            report.lines_changed = Some(line_diff_count(&existing, &rendered));
+        }
+        if args.diff {
+            report.diff = Some(crate::utils::diff::unified_diff(&existing, &rendered));
+        }
+    }
+
+    if args.fix && !report.is_clean() {
+        report.rebuild_summary = Some(merger.rebuild(&enabled).await?);
+        report.fixed = true;
+    }
+
+    Ok(report)
+}
+
+/// Re-hashes `skill`'s backing file and returns its name if that no longer
+/// matches the stored `content_hash` (only meaningful for
+/// `SkillSource::Local`; other sources have no local file to drift from).
+fn drifted_name(skill: &Skill) -> Option<String> {
+    let SkillSource::Local(path) = &skill.source else {
+        return None;
+    };
+    let current_hash = hash_file_streaming(std::path::Path::new(path)).ok()?;
+    (current_hash != skill.content_hash).then(|| skill.name.clone())
+}
+
+/// Count of lines present in exactly one of `before`/`after`, as a rough
+/// "how much would change" signal without pulling in a full diff library.
+fn line_diff_count(before: &str, after: &str) -> usize {
+    let before_lines: std::collections::HashSet<&str> = before.lines().collect();
+    let after_lines: std::collections::HashSet<&str> = after.lines().collect();
+    before_lines.symmetric_difference(&after_lines).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    use crate::models::{MergePreviewStats, Skill, SkillScope, SkillSource};
+
+    struct FakeSkillService {
+        skills: Vec<Skill>,
+        corrupt: Vec<String>,
+    }
+
+    #[async_trait]
+    impl SkillService for FakeSkillService {
+        async fn add(&self, _n: &str, _s: SkillSource, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_or_overwrite(&self, _n: &str, _s: SkillSource, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_with_content(
+            &self,
+            _n: &str,
+            _s: SkillSource,
+            _sc: SkillScope,
+            _c: String,
+        ) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn list(&self) -> Result<Vec<Skill>> {
+            Ok(self.skills.clone())
+        }
+        async fn update_content(&self, _n: &str, _sc: SkillScope, _c: String) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn merge_preview(&self, _scope: Option<SkillScope>) -> Result<MergePreviewStats> {
+            unimplemented!()
+        }
+        async fn effective_list(&self) -> Result<Vec<Skill>> {
+            Ok(self.skills.clone())
+        }
+        async fn set_note(&self, _n: &str, _sc: SkillScope, _note: Option<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn archive(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn restore(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn purge(&self, _n: &str, _sc: SkillScope) -> Result<()> {
+            unimplemented!()
+        }
+        async fn rename(&self, _n: &str, _sc: SkillScope, _new: &str) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_tags(&self, _n: &str, _sc: SkillScope, _tags: Vec<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_priority(&self, _n: &str, _sc: SkillScope, _p: i32) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_enabled(&self, _n: &str, _sc: SkillScope, _e: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn record_update_result(&self, _n: &str, _sc: SkillScope, _failed: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn rollback_content(&self, _n: &str, _sc: SkillScope) -> Result<bool> {
+            unimplemented!()
+        }
+        async fn integrity_issues(&self) -> Result<Vec<String>> {
+            Ok(self.corrupt.clone())
+        }
+
+    }
+
+    struct FakeMerger {
+        rebuilt_with: Mutex<Option<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl MergeService for FakeMerger {
+        async fn merge(&self, _skill: &Skill) -> Result<()> {
+            unimplemented!()
+        }
+        async fn rebuild(&self, skills: &[Skill]) -> Result<RebuildSummary> {
+            *self.rebuilt_with.lock().unwrap() =
+                Some(skills.iter().map(|s| s.name.clone()).collect());
+            Ok(RebuildSummary {
+                skill_count: skills.len(),
+                bytes: 0,
+            })
+        }
+        async fn restore_backup(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn skill(name: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: format!("content for {name}"),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: crate::models::UpdateMode::Auto,
+            update_trigger: crate::models::UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn detects_a_manually_desynced_claude_md_and_repairs_it_with_fix() {
+        let path = std::env::temp_dir().join("csm_test_doctor_claude_md.md");
+        std::fs::write(&path, "\n## stale-skill\n\nold content\n").unwrap();
+
+        let service = FakeSkillService {
+            skills: vec![skill("fresh-skill")],
+            corrupt: Vec::new(),
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(&DoctorArgs { fix: false, dry_run: false, restore_backup: false, diff: false }, &service, &merger, &path, None)
+            .await
+            .unwrap();
+        assert_eq!(report.missing, vec!["fresh-skill".to_string()]);
+        assert_eq!(report.extra, vec!["stale-skill".to_string()]);
+        assert!(!report.fixed);
+        assert!(merger.rebuilt_with.lock().unwrap().is_none());
+
+        let report = run(&DoctorArgs { fix: true, dry_run: false, restore_backup: false, diff: false }, &service, &merger, &path, None)
+            .await
+            .unwrap();
+        assert!(report.fixed);
+        assert_eq!(
+            merger.rebuilt_with.lock().unwrap().as_ref().unwrap(),
+            &vec!["fresh-skill".to_string()]
+        );
+        assert_eq!(report.rebuild_summary.unwrap().skill_count, 1);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn fix_reports_a_summary_of_the_skills_folded_into_the_rebuild() {
+        let path = std::env::temp_dir().join("csm_test_doctor_rebuild_summary.md");
+        std::fs::write(&path, "\n## stale-skill\n\nold content\n").unwrap();
+
+        let service = FakeSkillService {
+            skills: vec![skill("alpha"), skill("beta")],
+            corrupt: Vec::new(),
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(&DoctorArgs { fix: true, dry_run: false, restore_backup: false, diff: false }, &service, &merger, &path, None)
+            .await
+            .unwrap();
+
+        let summary = report.rebuild_summary.expect("--fix must report a rebuild summary");
+        assert_eq!(summary.skill_count, 2);
+        assert!(report.to_string().contains("2 skill(s)"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn reports_clean_when_claude_md_already_matches() {
+        let path = std::env::temp_dir().join("csm_test_doctor_clean.md");
+        std::fs::write(&path, "\n## in-sync\n\ncontent\n").unwrap();
+
+        let service = FakeSkillService {
+            skills: vec![skill("in-sync")],
+            corrupt: Vec::new(),
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(&DoctorArgs { fix: true, dry_run: false, restore_backup: false, diff: false }, &service, &merger, &path, None)
+            .await
+            .unwrap();
+
+        assert!(report.is_clean());
+        assert!(!report.fixed);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn reports_the_schema_version_when_one_is_passed_in() {
+        let path = std::env::temp_dir().join("csm_test_doctor_schema_version.md");
+        std::fs::write(&path, "\n## in-sync\n\ncontent\n").unwrap();
+
+        let service = FakeSkillService {
+            skills: vec![skill("in-sync")],
+            corrupt: Vec::new(),
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(&DoctorArgs { fix: false, dry_run: false, restore_backup: false, diff: false }, &service, &merger, &path, Some(1))
+            .await
+            .unwrap();
+
+        assert_eq!(report.schema_version, Some(1));
+        assert!(report.to_string().contains("schema version 1"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn dry_run_reports_a_line_count_and_flags_out_of_date_content_headings_still_match() {
+        let path = std::env::temp_dir().join("csm_test_doctor_dry_run.md");
+        std::fs::write(&path, "\n## in-sync\n\nstale content\n").unwrap();
+
+        let service = FakeSkillService {
+            skills: vec![skill("in-sync")],
+            corrupt: Vec::new(),
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(&DoctorArgs { fix: false, dry_run: true, restore_backup: false, diff: false }, &service, &merger, &path, None)
+            .await
+            .unwrap();
+
+        assert!(report.is_clean(), "heading set matches, only the body changed");
+        assert!(!report.is_up_to_date());
+        assert!(report.lines_changed.unwrap() > 0);
+        assert!(!report.fixed);
+        assert!(merger.rebuilt_with.lock().unwrap().is_none());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn diff_prints_a_unified_diff_and_writes_nothing() {
+        let path = std::env::temp_dir().join("csm_test_doctor_diff.md");
+        std::fs::write(&path, "\n## in-sync\n\nstale content\n").unwrap();
+
+        let service = FakeSkillService {
+            skills: vec![skill("in-sync")],
+            corrupt: Vec::new(),
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(&DoctorArgs { fix: false, dry_run: false, restore_backup: false, diff: true }, &service, &merger, &path, None)
+            .await
+            .unwrap();
+
+        let diff = report.diff.as_deref().expect("--diff must produce a unified diff");
+        assert!(diff.contains("-stale content"));
+        assert!(diff.contains(&format!("+content for in-sync")));
+        assert!(merger.rebuilt_with.lock().unwrap().is_none());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "\n## in-sync\n\nstale content\n");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn flags_a_local_skill_whose_file_was_hand_edited_after_add() {
+        let claude_md_path = std::env::temp_dir().join("csm_test_doctor_drift_claude_md.md");
+        std::fs::write(&claude_md_path, "\n## local-skill\n\ncontent\n").unwrap();
+        let source_path = std::env::temp_dir().join("csm_test_doctor_drift_source.md");
+        std::fs::write(&source_path, "hand-edited content").unwrap();
+
+        let mut local = skill("local-skill");
+        local.source = SkillSource::Local(source_path.to_string_lossy().to_string());
+        local.content_hash = "stale-hash".to_string();
+        let service = FakeSkillService {
+            skills: vec![local],
+            corrupt: Vec::new(),
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(&DoctorArgs { fix: false, dry_run: false, restore_backup: false, diff: false }, &service, &merger, &claude_md_path, None)
+            .await
+            .unwrap();
+
+        assert_eq!(report.drifted, vec!["local-skill".to_string()]);
+        assert!(report.to_string().contains("local-skill"));
+
+        std::fs::remove_file(&claude_md_path).unwrap();
+        std::fs::remove_file(&source_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn corrupt_rows_flagged_by_the_skill_service_are_surfaced_in_the_report() {
+        let path = std::env::temp_dir().join("csm_test_doctor_corrupt_rows.md");
+        std::fs::write(&path, "\n## in-sync\n\ncontent\n").unwrap();
+
+        let service = FakeSkillService {
+            skills: vec![skill("in-sync")],
+            corrupt: vec!["skill id 5: invalid scope 'sideways'".to_string()],
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(&DoctorArgs { fix: false, dry_run: false, restore_backup: false, diff: false }, &service, &merger, &path, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report.corrupt_rows,
+            vec!["skill id 5: invalid scope 'sideways'".to_string()]
+        );
+        assert!(report.to_string().contains("invalid scope 'sideways'"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn restore_backup_delegates_to_the_merger_without_recomputing_drift() {
+        let path = std::env::temp_dir().join("csm_test_doctor_restore_backup.md");
+        std::fs::write(&path, "\n## in-sync\n\ncontent\n").unwrap();
+
+        let service = FakeSkillService {
+            skills: vec![skill("in-sync")],
+            corrupt: Vec::new(),
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(
+            &DoctorArgs { fix: false, dry_run: false, restore_backup: true, diff: false },
+            &service,
+            &merger,
+            &path,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert!(report.restored);
+        assert_eq!(report.to_string(), "restored CLAUDE.md from backup");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,96 @@
+use clap::Args;
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::github::resolve_token;
+
+#[derive(Debug, Args)]
+pub struct EnvArgs {
+    /// Emit the environment inputs as JSON instead of human-readable lines.
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Effective environment inputs `csm` observed for this invocation. Token
+/// fields are booleans so `csm env` never prints a secret.
+#[derive(Debug, Serialize)]
+struct EffectiveEnv {
+    csm_home: String,
+    csm_home_from_env: bool,
+    github_token_present: bool,
+    github_token_source: Option<&'static str>,
+}
+
+pub fn run(args: &EnvArgs, config: &Config) -> String {
+    let csm_home_env = std::env::var("CSM_HOME").ok();
+    let github_token_env = std::env::var("GITHUB_TOKEN").ok();
+    let resolved_token = resolve_token(config.github.token.as_deref());
+
+    let env = EffectiveEnv {
+        csm_home: config.csm_home.display().to_string(),
+        csm_home_from_env: csm_home_env.is_some(),
+        github_token_present: resolved_token.is_some(),
+        github_token_source: if github_token_env.is_some() {
+            Some("GITHUB_TOKEN")
+        } else if config.github.token.is_some() {
+            Some("config.toml")
+        } else {
+            None
+        },
+    };
+
+    if args.json {
+        return serde_json::to_string_pretty(&env).unwrap_or_default();
+    }
+
+    format!(
+        "CSM_HOME:       {} ({})\nGITHUB_TOKEN:   {}",
+        env.csm_home,
+        if env.csm_home_from_env { "from env" } else { "default" },
+        match (env.github_token_present, env.github_token_source) {
+            (true, Some(source)) => format!("present ({source})"),
+            (true, None) => "present".to_string(),
+            (false, _) => "absent".to_string(),
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_config() -> Config {
+        Config {
+            csm_home: std::path::PathBuf::from("/home/user/.csm"),
+            database: Default::default(),
+            github: Default::default(),
+            merge: Default::default(),
+            general: Default::default(),
+            conflicts: Default::default(),
+        }
+    }
+
+    #[test]
+    fn reports_github_token_presence_without_leaking_its_value() {
+        std::env::set_var("GITHUB_TOKEN", "super-secret");
+        std::env::remove_var("CSM_HOME");
+
+        let output = run(&EnvArgs { json: false }, &base_config());
+
+        assert!(output.contains("present (GITHUB_TOKEN)"));
+        assert!(!output.contains("super-secret"));
+
+        std::env::remove_var("GITHUB_TOKEN");
+    }
+
+    #[test]
+    fn json_output_reports_absence_when_no_token_is_configured() {
+        std::env::remove_var("GITHUB_TOKEN");
+        std::env::remove_var("CSM_HOME");
+
+        let output = run(&EnvArgs { json: true }, &base_config());
+
+        assert!(output.contains("\"github_token_present\": false"));
+        assert!(output.contains("\"github_token_source\": null"));
+    }
+}
@@ -0,0 +1,313 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::error::{CsmError, Result};
+use crate::models::Skill;
+use crate::services::{ConflictService, SkillService};
+
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    /// Export only this skill. Repeatable to export a handful by name.
+    #[arg(long = "skill")]
+    pub skill: Vec<String>,
+
+    /// Export every skill. Errors if combined with `--skill`.
+    #[arg(long)]
+    pub all: bool,
+
+    /// Write a full backup (skills, unresolved conflicts, and `[merge]`
+    /// config) to this path instead of printing skills. Errors if combined
+    /// with `--skill`/`--all`.
+    #[arg(long, conflicts_with_all = ["skill", "all"])]
+    pub full: Option<std::path::PathBuf>,
+}
+
+/// Current on-disk shape of a `FullBackup`. Bump when the shape changes so
+/// `csm import --full` can reject a backup it doesn't know how to restore.
+pub const FULL_BACKUP_VERSION: u32 = 1;
+
+/// A skill as captured in a `FullBackup`, with enough to recreate it via
+/// `SkillService::add_with_content`. `source` and `scope` are stored in
+/// their CLI string forms (`SkillSource::display_string`/`SkillScope`'s
+/// `Display`) and round-tripped back through `parse_source`/`FromStr` on
+/// import, the same as a `--source`/`--scope` argument would be.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillBackup {
+    pub name: String,
+    pub source: String,
+    pub scope: String,
+    pub content: String,
+    pub notes: Option<String>,
+    pub archived: bool,
+}
+
+impl From<&Skill> for SkillBackup {
+    fn from(skill: &Skill) -> Self {
+        Self {
+            name: skill.name.clone(),
+            source: skill.source.display_string(),
+            scope: skill.scope.to_string(),
+            content: skill.content.clone(),
+            notes: skill.notes.clone(),
+            archived: skill.archived,
+        }
+    }
+}
+
+/// A conflict as captured in a `FullBackup`. Skills are identified by name
+/// rather than id, since `import --full` recreates skills with fresh ids.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictBackup {
+    pub skill_a: String,
+    pub skill_b: String,
+    pub status: String,
+    pub description: String,
+    pub severity: u8,
+}
+
+/// The subset of `Config` that's meaningful to restore. Deliberately
+/// excludes `github.token` and database pragmas: neither belongs in a
+/// backup file, and `import` shouldn't be trusted to overwrite either.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConfigBackup {
+    pub dedupe_sections: bool,
+    pub header_text: Option<String>,
+    pub same_name_strategy: String,
+}
+
+impl From<&Config> for ConfigBackup {
+    fn from(config: &Config) -> Self {
+        Self {
+            dedupe_sections: config.merge.dedupe_sections,
+            header_text: config.merge.header_text.clone(),
+            same_name_strategy: config.merge.same_name_strategy.to_string(),
+        }
+    }
+}
+
+/// Full point-in-time snapshot produced by `csm export --full` and restored
+/// by `csm import --full`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FullBackup {
+    pub version: u32,
+    pub skills: Vec<SkillBackup>,
+    pub conflicts: Vec<ConflictBackup>,
+    pub config: ConfigBackup,
+}
+
+pub async fn run(args: &ExportArgs, service: &dyn SkillService) -> Result<Vec<Skill>> {
+    if args.all && !args.skill.is_empty() {
+        return Err(CsmError::Validation(
+            "--all cannot be combined with --skill".to_string(),
+        ));
+    }
+
+    let skills = service.list().await?;
+
+    if args.all || args.skill.is_empty() {
+        return Ok(skills);
+    }
+
+    let mut selected = Vec::with_capacity(args.skill.len());
+    let mut missing = Vec::new();
+    for name in &args.skill {
+        match skills.iter().find(|s| &s.name == name) {
+            Some(skill) => selected.push(skill.clone()),
+            None => missing.push(name.clone()),
+        }
+    }
+
+    if !missing.is_empty() {
+        return Err(CsmError::NotFound(missing.join(", ")));
+    }
+
+    Ok(selected)
+}
+
+/// Builds a `FullBackup` of every skill, currently-detected conflict, and
+/// the restorable slice of `config`.
+pub async fn run_full(
+    service: &dyn SkillService,
+    conflicts: &dyn ConflictService,
+    config: &Config,
+) -> Result<FullBackup> {
+    let skills = service.list().await?;
+    let detected = conflicts.detect().await?;
+
+    let name_for = |id: i64| -> String {
+        skills
+            .iter()
+            .find(|s| s.id == id)
+            .map(|s| s.name.clone())
+            .unwrap_or_else(|| format!("#{id}"))
+    };
+
+    Ok(FullBackup {
+        version: FULL_BACKUP_VERSION,
+        skills: skills.iter().map(SkillBackup::from).collect(),
+        conflicts: detected
+            .iter()
+            .map(|c| ConflictBackup {
+                skill_a: name_for(c.skill_a_id),
+                skill_b: name_for(c.skill_b_id),
+                status: c.status.to_string(),
+                description: c.description.clone(),
+                severity: c.severity,
+            })
+            .collect(),
+        config: ConfigBackup::from(config),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SkillScope, SkillSource, UpdateMode, UpdateTrigger};
+    use async_trait::async_trait;
+
+    use crate::test_support::StubSkillService;
+
+    fn skill(name: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn exports_exactly_the_named_skills() {
+        let service = StubSkillService::new(vec![skill("a"), skill("b"), skill("c")]);
+        let result = run(
+            &ExportArgs {
+                skill: vec!["a".to_string(), "c".to_string()],
+                all: false,
+                full: None,
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        let names: Vec<&str> = result.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "c"]);
+    }
+
+    #[tokio::test]
+    async fn errors_listing_names_not_found() {
+        let service = StubSkillService::new(vec![skill("a")]);
+        let err = run(
+            &ExportArgs {
+                skill: vec!["a".to_string(), "missing".to_string()],
+                all: false,
+                full: None,
+            },
+            &service,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, CsmError::NotFound(msg) if msg == "missing"));
+    }
+
+    #[tokio::test]
+    async fn rejects_all_combined_with_skill() {
+        let service = StubSkillService::new(vec![skill("a")]);
+        let err = run(
+            &ExportArgs {
+                skill: vec!["a".to_string()],
+                all: true,
+                full: None,
+            },
+            &service,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, CsmError::Validation(_)));
+    }
+
+    struct FakeConflicts(Vec<crate::models::Conflict>);
+
+    #[async_trait]
+    impl ConflictService for FakeConflicts {
+        async fn detect(&self) -> Result<Vec<crate::models::Conflict>> {
+            Ok(self.0.clone())
+        }
+        async fn conflicts_for_skill(&self, _skill_id: i64) -> Result<Vec<crate::models::Conflict>> {
+            unimplemented!()
+        }
+        async fn restore(&self, conflict: crate::models::Conflict) -> Result<crate::models::Conflict> {
+            Ok(conflict)
+        }
+        async fn ignore(&self, _conflict_id: i64) -> Result<crate::models::Conflict> {
+            unimplemented!()
+        }
+        async fn clear_whitelist(&self) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn run_full_captures_skills_conflicts_and_config() {
+        let mut a = skill("a");
+        a.id = 1;
+        let mut b = skill("b");
+        b.id = 2;
+        let service = StubSkillService::new(vec![a, b]);
+        let conflicts = FakeConflicts(vec![crate::models::Conflict {
+            id: 1,
+            skill_a_id: 1,
+            skill_b_id: 2,
+            description: "'always' in a vs 'never' in b".to_string(),
+            status: crate::models::ConflictStatus::Unresolved,
+            detected_at: chrono::Utc::now(),
+            severity: 200,
+        }]);
+        let config = Config {
+            csm_home: std::path::PathBuf::new(),
+            database: Default::default(),
+            github: Default::default(),
+            merge: crate::config::MergeConfig {
+                dedupe_sections: true,
+                header_text: Some("H".to_string()),
+                same_name_strategy: crate::models::SameNameStrategy::Append,
+                ..Default::default()
+            },
+            general: Default::default(),
+            conflicts: Default::default(),
+        };
+
+        let backup = run_full(&service, &conflicts, &config).await.unwrap();
+
+        assert_eq!(backup.version, FULL_BACKUP_VERSION);
+        let names: Vec<&str> = backup.skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+        assert_eq!(backup.conflicts.len(), 1);
+        assert_eq!(backup.conflicts[0].skill_a, "a");
+        assert_eq!(backup.conflicts[0].skill_b, "b");
+        assert_eq!(backup.conflicts[0].status, "unresolved");
+        assert!(backup.config.dedupe_sections);
+        assert_eq!(backup.config.header_text.as_deref(), Some("H"));
+        assert_eq!(backup.config.same_name_strategy, "append");
+    }
+}
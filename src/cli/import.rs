@@ -0,0 +1,400 @@
+use std::collections::HashMap;
+
+use clap::Args;
+
+use crate::cli::export::{FullBackup, FULL_BACKUP_VERSION};
+use crate::error::{CsmError, Result};
+use crate::models::{parse_source, Conflict, ConflictStatus, SkillScope};
+use crate::services::{ConflictService, SkillService};
+
+#[derive(Debug, Args)]
+pub struct ImportArgs {
+    /// Restores skills and conflicts from a `csm export --full` backup. The
+    /// backup's `[merge]` config is not applied.
+    #[arg(long)]
+    pub full: std::path::PathBuf,
+
+    /// Forces every imported skill to this scope ("global" or "project"),
+    /// regardless of the scope it was exported with. Takes precedence over
+    /// `--map-scope`.
+    #[arg(long)]
+    pub scope: Option<String>,
+
+    /// Rewrites one exported scope to another on import, e.g.
+    /// `--map-scope project=global` when the export's project paths won't
+    /// exist on this machine. Repeatable.
+    #[arg(long = "map-scope", value_name = "FROM=TO")]
+    pub map_scope: Vec<String>,
+}
+
+/// Parses `--map-scope FROM=TO` flags into a lookup table from exported
+/// scope to the scope it should be rehomed to.
+fn parse_scope_map(pairs: &[String]) -> Result<HashMap<SkillScope, SkillScope>> {
+    let mut map = HashMap::with_capacity(pairs.len());
+    for pair in pairs {
+        let (from, to) = pair.split_once('=').ok_or_else(|| {
+            CsmError::Validation(format!("invalid --map-scope '{pair}', expected FROM=TO"))
+        })?;
+        let from: SkillScope = from
+            .parse()
+            .map_err(|e: crate::models::ParseSkillScopeError| CsmError::Validation(e.to_string()))?;
+        let to: SkillScope = to
+            .parse()
+            .map_err(|e: crate::models::ParseSkillScopeError| CsmError::Validation(e.to_string()))?;
+        map.insert(from, to);
+    }
+    Ok(map)
+}
+
+/// How many rows `run_full` recreated, for a one-line confirmation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub skills: usize,
+    pub conflicts: usize,
+}
+
+impl std::fmt::Display for ImportSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "restored {} skill(s) and {} conflict(s)",
+            self.skills, self.conflicts
+        )
+    }
+}
+
+/// Restores every skill and conflict in `backup`. The backup's
+/// `ConfigBackup` is neither applied to `config.toml` nor returned.
+///
+/// Skills are recreated with `SkillService::add_with_content`, so this
+/// fails the same way `csm add` would if a name/scope pair already exists.
+/// Conflicts are matched back to their skills by name, since `import`
+/// recreates skills with fresh ids.
+///
+/// `args.scope` forces every skill to one scope; otherwise `args.map_scope`
+/// rehomes specific exported scopes (typically `project` -> `global`, since
+/// an export's project paths won't exist on the machine it's imported into).
+/// A skill left at `project` scope by neither is imported as-is, with a
+/// warning that its project path may not exist here.
+pub async fn run_full(
+    backup: &FullBackup,
+    args: &ImportArgs,
+    service: &dyn SkillService,
+    conflicts: &dyn ConflictService,
+) -> Result<ImportSummary> {
+    if backup.version != FULL_BACKUP_VERSION {
+        return Err(CsmError::Validation(format!(
+            "unsupported backup version {} (expected {FULL_BACKUP_VERSION})",
+            backup.version
+        )));
+    }
+
+    let scope_map = parse_scope_map(&args.map_scope)?;
+    let forced_scope = args
+        .scope
+        .as_deref()
+        .map(|s| {
+            s.parse::<SkillScope>()
+                .map_err(|e: crate::models::ParseSkillScopeError| CsmError::Validation(e.to_string()))
+        })
+        .transpose()?;
+
+    let mut restored = Vec::with_capacity(backup.skills.len());
+    for entry in &backup.skills {
+        let exported_scope: SkillScope = entry
+            .scope
+            .parse()
+            .map_err(|e: crate::models::ParseSkillScopeError| CsmError::Validation(e.to_string()))?;
+        let scope = forced_scope
+            .unwrap_or_else(|| scope_map.get(&exported_scope).copied().unwrap_or(exported_scope));
+        if scope == SkillScope::Project {
+            eprintln!(
+                "warning: skill '{}' imported at project scope; its project path may not exist here \
+                 (use --scope global or --map-scope project=global to rehome it)",
+                entry.name
+            );
+        }
+        let source = parse_source(&entry.source);
+
+        let skill = service
+            .add_with_content(&entry.name, source, scope, entry.content.clone())
+            .await?;
+
+        if entry.notes.is_some() {
+            service.set_note(&skill.name, scope, entry.notes.clone()).await?;
+        }
+        if entry.archived {
+            service.archive(&skill.name, scope).await?;
+        }
+
+        restored.push(skill);
+    }
+
+    for entry in &backup.conflicts {
+        let status: ConflictStatus = entry
+            .status
+            .parse()
+            .map_err(|e: crate::models::ParseConflictStatusError| CsmError::Validation(e.to_string()))?;
+        let skill_a_id = restored
+            .iter()
+            .find(|s| s.name == entry.skill_a)
+            .map(|s| s.id)
+            .ok_or_else(|| CsmError::NotFound(entry.skill_a.clone()))?;
+        let skill_b_id = restored
+            .iter()
+            .find(|s| s.name == entry.skill_b)
+            .map(|s| s.id)
+            .ok_or_else(|| CsmError::NotFound(entry.skill_b.clone()))?;
+
+        conflicts
+            .restore(Conflict {
+                id: 0,
+                skill_a_id,
+                skill_b_id,
+                description: entry.description.clone(),
+                status,
+                detected_at: chrono::Utc::now(),
+                severity: entry.severity,
+            })
+            .await?;
+    }
+
+    Ok(ImportSummary {
+        skills: restored.len(),
+        conflicts: backup.conflicts.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::export::{ConfigBackup, ConflictBackup, SkillBackup};
+    use crate::models::{Skill, SkillSource, UpdateMode, UpdateTrigger};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct FakeService(Mutex<Vec<Skill>>);
+
+    fn skill(id: i64, name: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[async_trait]
+    impl SkillService for FakeService {
+        async fn add(&self, _n: &str, _s: SkillSource, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_or_overwrite(&self, _n: &str, _s: SkillSource, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_with_content(
+            &self,
+            name: &str,
+            source: SkillSource,
+            scope: SkillScope,
+            content: String,
+        ) -> Result<Skill> {
+            let mut skills = self.0.lock().unwrap();
+            let mut created = skill((skills.len() + 1) as i64, name);
+            created.source = source;
+            created.scope = scope;
+            created.content = content;
+            skills.push(created.clone());
+            Ok(created)
+        }
+        async fn list(&self) -> Result<Vec<Skill>> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+        async fn update_content(&self, _n: &str, _sc: SkillScope, _c: String) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn merge_preview(
+            &self,
+            _scope: Option<SkillScope>,
+        ) -> Result<crate::models::MergePreviewStats> {
+            unimplemented!()
+        }
+        async fn effective_list(&self) -> Result<Vec<Skill>> {
+            unimplemented!()
+        }
+        async fn set_note(&self, name: &str, _sc: SkillScope, note: Option<String>) -> Result<Skill> {
+            let mut skills = self.0.lock().unwrap();
+            let s = skills.iter_mut().find(|s| s.name == name).unwrap();
+            s.notes = note;
+            Ok(s.clone())
+        }
+        async fn archive(&self, name: &str, _sc: SkillScope) -> Result<Skill> {
+            let mut skills = self.0.lock().unwrap();
+            let s = skills.iter_mut().find(|s| s.name == name).unwrap();
+            s.archived = true;
+            Ok(s.clone())
+        }
+        async fn restore(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn purge(&self, _n: &str, _sc: SkillScope) -> Result<()> {
+            unimplemented!()
+        }
+        async fn rename(&self, _n: &str, _sc: SkillScope, _new: &str) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_tags(&self, _n: &str, _sc: SkillScope, _tags: Vec<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_priority(&self, _n: &str, _sc: SkillScope, _p: i32) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_enabled(&self, _n: &str, _sc: SkillScope, _e: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn record_update_result(&self, _n: &str, _sc: SkillScope, _failed: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn rollback_content(&self, _n: &str, _sc: SkillScope) -> Result<bool> {
+            unimplemented!()
+        }
+    }
+
+    struct FakeConflicts(Mutex<Vec<Conflict>>);
+
+    #[async_trait]
+    impl ConflictService for FakeConflicts {
+        async fn detect(&self) -> Result<Vec<Conflict>> {
+            unimplemented!()
+        }
+        async fn conflicts_for_skill(&self, _skill_id: i64) -> Result<Vec<Conflict>> {
+            unimplemented!()
+        }
+        async fn restore(&self, conflict: Conflict) -> Result<Conflict> {
+            let mut conflicts = self.0.lock().unwrap();
+            let mut created = conflict;
+            created.id = (conflicts.len() + 1) as i64;
+            conflicts.push(created.clone());
+            Ok(created)
+        }
+        async fn ignore(&self, _conflict_id: i64) -> Result<crate::models::Conflict> {
+            unimplemented!()
+        }
+        async fn clear_whitelist(&self) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    fn backup() -> FullBackup {
+        FullBackup {
+            version: FULL_BACKUP_VERSION,
+            skills: vec![
+                SkillBackup {
+                    name: "a".to_string(),
+                    source: "inline".to_string(),
+                    scope: "global".to_string(),
+                    content: "always be kind".to_string(),
+                    notes: Some("pinned on purpose".to_string()),
+                    archived: false,
+                },
+                SkillBackup {
+                    name: "b".to_string(),
+                    source: "inline".to_string(),
+                    scope: "global".to_string(),
+                    content: "never be unkind".to_string(),
+                    notes: None,
+                    archived: true,
+                },
+            ],
+            conflicts: vec![ConflictBackup {
+                skill_a: "a".to_string(),
+                skill_b: "b".to_string(),
+                status: "unresolved".to_string(),
+                description: "'always' in a vs 'never' in b".to_string(),
+                severity: 200,
+            }],
+            config: ConfigBackup {
+                dedupe_sections: true,
+                header_text: Some("H".to_string()),
+                same_name_strategy: "append".to_string(),
+            },
+        }
+    }
+
+    fn args() -> ImportArgs {
+        ImportArgs {
+            full: std::path::PathBuf::new(),
+            scope: None,
+            map_scope: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn round_trips_skills_a_conflict_and_notes_archival_state() {
+        let service = FakeService(Mutex::new(Vec::new()));
+        let conflicts = FakeConflicts(Mutex::new(Vec::new()));
+
+        let summary = run_full(&backup(), &args(), &service, &conflicts).await.unwrap();
+
+        assert_eq!(summary, ImportSummary { skills: 2, conflicts: 1 });
+
+        let skills = service.0.lock().unwrap();
+        let a = skills.iter().find(|s| s.name == "a").unwrap();
+        assert_eq!(a.content, "always be kind");
+        assert_eq!(a.notes.as_deref(), Some("pinned on purpose"));
+        assert!(!a.archived);
+        let b = skills.iter().find(|s| s.name == "b").unwrap();
+        assert!(b.archived);
+
+        let restored_conflicts = conflicts.0.lock().unwrap();
+        assert_eq!(restored_conflicts.len(), 1);
+        assert_eq!(restored_conflicts[0].skill_a_id, a.id);
+        assert_eq!(restored_conflicts[0].skill_b_id, b.id);
+        assert_eq!(restored_conflicts[0].status, ConflictStatus::Unresolved);
+    }
+
+    #[tokio::test]
+    async fn rejects_a_backup_with_an_unsupported_version() {
+        let service = FakeService(Mutex::new(Vec::new()));
+        let conflicts = FakeConflicts(Mutex::new(Vec::new()));
+        let mut mismatched = backup();
+        mismatched.version = FULL_BACKUP_VERSION + 1;
+
+        let err = run_full(&mismatched, &args(), &service, &conflicts).await.unwrap_err();
+
+        assert!(matches!(err, CsmError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn scope_global_rehomes_a_project_scoped_export_to_global() {
+        let service = FakeService(Mutex::new(Vec::new()));
+        let conflicts = FakeConflicts(Mutex::new(Vec::new()));
+        let mut export = backup();
+        export.skills[0].scope = "project".to_string();
+        let mut import_args = args();
+        import_args.scope = Some("global".to_string());
+
+        run_full(&export, &import_args, &service, &conflicts).await.unwrap();
+
+        let skills = service.0.lock().unwrap();
+        let a = skills.iter().find(|s| s.name == "a").unwrap();
+        assert_eq!(a.scope, SkillScope::Global);
+    }
+}
@@ -0,0 +1,93 @@
+use clap::Args;
+use std::path::Path;
+use std::process::Command;
+
+use crate::config::Config;
+use crate::error::{CsmError, Result};
+
+#[derive(Debug, Args)]
+pub struct InitArgs {
+    /// Initialize a git repository in the skills directory, so skill edits are tracked.
+    #[arg(long)]
+    pub git: bool,
+}
+
+/// Starter config written by `init`, commented so a first-time user can see
+/// a few of the recognized keys without having to check the docs.
+const STARTER_CONFIG: &str = r#"# csm configuration
+#
+# Uncomment and edit any of the following to override the defaults.
+
+# Merged skill content is written to CLAUDE.md in this directory.
+
+# SQLite PRAGMAs applied to every connection. Allowed keys: cache_size,
+# mmap_size, synchronous, journal_mode, busy_timeout.
+# [database]
+# pragmas = { cache_size = "-20000", synchronous = "NORMAL" }
+
+# GitHub Enterprise base URL, if you're not using api.github.com.
+# [github]
+# api_url = "https://github.mycorp.com/api/v3"
+"#;
+
+pub async fn run(args: &InitArgs, config: &Config) -> Result<()> {
+    std::fs::create_dir_all(&config.csm_home)?;
+    write_starter_config(&config.csm_home)?;
+
+    if args.git {
+        init_git_repo(&config.csm_home)?;
+    }
+
+    Ok(())
+}
+
+fn write_starter_config(dir: &Path) -> Result<()> {
+    let path = dir.join("config.toml");
+    if path.exists() {
+        return Ok(());
+    }
+    std::fs::write(path, STARTER_CONFIG)?;
+    Ok(())
+}
+
+fn init_git_repo(dir: &Path) -> Result<()> {
+    if dir.join(".git").exists() {
+        return Ok(());
+    }
+
+    let status = Command::new("git").arg("init").arg(dir).status()?;
+    if !status.success() {
+        return Err(CsmError::Other(format!(
+            "git init failed with status {status}"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn init_git_repo_is_idempotent_when_git_dir_exists() {
+        let dir = std::env::temp_dir().join("csm_test_init_git_idempotent");
+        std::fs::create_dir_all(dir.join(".git")).unwrap();
+
+        init_git_repo(&dir).unwrap();
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn write_starter_config_does_not_clobber_an_existing_file() {
+        let dir = std::env::temp_dir().join("csm_test_init_starter_config");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("config.toml"), "custom = true\n").unwrap();
+
+        write_starter_config(&dir).unwrap();
+
+        let contents = std::fs::read_to_string(dir.join("config.toml")).unwrap();
+        assert_eq!(contents, "custom = true\n");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,379 @@
+use clap::Args;
+use serde::Serialize;
+
+use crate::cli::output::OutputStyle;
+use crate::error::{CsmError, Result};
+use crate::models::{Skill, SkillScope, SkillSource, SkillView, UpdateMode};
+use crate::services::SkillService;
+use crate::utils::hash::hash_file_streaming;
+
+#[derive(Debug, Args)]
+pub struct ListArgs {
+    /// Print results as a JSON array of `SkillView` instead of a table.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Group skills under their scope (Global, then Project) instead of a flat list.
+    #[arg(long)]
+    pub tree: bool,
+
+    /// Show archived (soft-deleted) skills instead of the active set.
+    #[arg(long)]
+    pub archived: bool,
+
+    /// Show only skills whose `SkillSource::Local` file no longer hashes to
+    /// the stored `content_hash`, i.e. was hand-edited after `csm add`.
+    #[arg(long, conflicts_with_all = ["tree"])]
+    pub changed: bool,
+
+    /// Show only skills with this `update_mode` ("auto", "notify", or
+    /// "manual"). Combines with `--archived`/`--tree`/`--changed`.
+    #[arg(long = "update-mode")]
+    pub update_mode: Option<String>,
+}
+
+/// A skill reported by `list --changed`: the hash `csm` last stored for it,
+/// versus what its `SkillSource::Local` file hashes to right now.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangedSkill {
+    pub name: String,
+    pub old_hash: String,
+    pub new_hash: String,
+}
+
+/// Re-hashes `skill`'s backing file (only meaningful for `SkillSource::Local`
+/// skills; other sources have no local file to drift from) and reports it as
+/// changed if that no longer matches the stored `content_hash`.
+fn detect_drift(skill: &Skill) -> Option<ChangedSkill> {
+    let SkillSource::Local(path) = &skill.source else {
+        return None;
+    };
+    let current_hash = hash_file_streaming(std::path::Path::new(path)).ok()?;
+    if current_hash == skill.content_hash {
+        return None;
+    }
+    Some(ChangedSkill {
+        name: skill.name.clone(),
+        old_hash: skill.content_hash.clone(),
+        new_hash: current_hash,
+    })
+}
+
+fn render_changed_text(changed: &[ChangedSkill]) -> String {
+    changed
+        .iter()
+        .map(|c| format!("{} {} -> {}", c.name, c.old_hash, c.new_hash))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Skills grouped by scope, Global first, in `--tree`'s fixed display order.
+const TREE_SCOPES: [SkillScope; 2] = [SkillScope::Global, SkillScope::Project];
+
+pub async fn run(args: &ListArgs, service: &dyn SkillService, style: OutputStyle) -> Result<String> {
+    let update_mode = args
+        .update_mode
+        .as_deref()
+        .map(|m| {
+            m.parse::<UpdateMode>()
+                .map_err(|e: crate::models::ParseUpdateModeError| CsmError::Validation(e.to_string()))
+        })
+        .transpose()?;
+
+    let skills: Vec<Skill> = service
+        .list()
+        .await?
+        .into_iter()
+        .filter(|s| s.archived == args.archived)
+        .filter(|s| update_mode.map_or(true, |m| s.update_mode == m))
+        .collect();
+
+    if args.changed {
+        let changed: Vec<ChangedSkill> = skills.iter().filter_map(detect_drift).collect();
+        return Ok(if args.json {
+            serde_json::to_string_pretty(&changed)?
+        } else {
+            render_changed_text(&changed)
+        });
+    }
+
+    if args.tree {
+        return Ok(if args.json {
+            render_tree_json(&skills)?
+        } else {
+            render_tree_text(&skills, style)
+        });
+    }
+
+    if args.json {
+        let views: Vec<SkillView> = skills.iter().map(SkillView::from).collect();
+        Ok(serde_json::to_string_pretty(&views)?)
+    } else {
+        Ok(skills
+            .iter()
+            .map(|s| format!("{} {}", style.enabled_marker(s.enabled), s.name))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+fn by_scope(skills: &[Skill], scope: SkillScope) -> Vec<&Skill> {
+    skills.iter().filter(|s| s.scope == scope).collect()
+}
+
+fn render_tree_text(skills: &[Skill], style: OutputStyle) -> String {
+    TREE_SCOPES
+        .iter()
+        .map(|&scope| {
+            let group = by_scope(skills, scope);
+            let mut lines = vec![format!("{scope} ({})", group.len())];
+            lines.extend(
+                group
+                    .iter()
+                    .map(|s| format!("  {} {}", style.enabled_marker(s.enabled), s.name)),
+            );
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_tree_json(skills: &[Skill]) -> Result<String> {
+    let grouped: std::collections::BTreeMap<String, Vec<SkillView>> = TREE_SCOPES
+        .iter()
+        .map(|&scope| {
+            let views = by_scope(skills, scope).into_iter().map(SkillView::from).collect();
+            (scope.to_string(), views)
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&grouped)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_support::StubSkillService;
+
+    fn a_skill() -> crate::models::Skill {
+        let now = chrono::Utc::now();
+        crate::models::Skill {
+            id: 1,
+            name: "example".to_string(),
+            source: crate::models::SkillSource::Inline,
+            scope: crate::models::SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 5,
+            update_mode: crate::models::UpdateMode::Auto,
+            update_trigger: crate::models::UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: vec!["a".to_string()],
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn json_output_includes_every_skill_view_field() {
+        let service = StubSkillService::new(vec![a_skill()]);
+        let output = run(
+            &ListArgs {
+                json: true,
+                tree: false,
+                archived: false,
+                changed: false,
+                update_mode: None,
+            },
+            &service,
+            OutputStyle::Emoji,
+        )
+            .await
+            .unwrap();
+        let parsed: Vec<serde_json::Value> = serde_json::from_str(&output).unwrap();
+        let view = &parsed[0];
+
+        for field in [
+            "name",
+            "content_hash",
+            "source",
+            "scope",
+            "enabled",
+            "priority",
+            "update_mode",
+            "tags",
+            "created_at",
+            "updated_at",
+        ] {
+            assert!(view.get(field).is_some(), "missing field: {field}");
+        }
+    }
+
+    fn skill_in(scope: crate::models::SkillScope, name: &str) -> crate::models::Skill {
+        let mut skill = a_skill();
+        skill.scope = scope;
+        skill.name = name.to_string();
+        skill
+    }
+
+    #[tokio::test]
+    async fn tree_text_groups_skills_under_their_scope_with_counts() {
+        let service = StubSkillService::new(vec![
+            skill_in(crate::models::SkillScope::Global, "shared"),
+            skill_in(crate::models::SkillScope::Project, "local-a"),
+            skill_in(crate::models::SkillScope::Project, "local-b"),
+        ]);
+
+        let output = run(
+            &ListArgs {
+                json: false,
+                tree: true,
+                archived: false,
+                changed: false,
+                update_mode: None,
+            },
+            &service,
+            OutputStyle::Plain,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            output,
+            "global (1)\n  [enabled] shared\nproject (2)\n  [enabled] local-a\n  [enabled] local-b"
+        );
+    }
+
+    #[tokio::test]
+    async fn tree_json_nests_skill_views_by_scope() {
+        let service = StubSkillService::new(vec![
+            skill_in(crate::models::SkillScope::Global, "shared"),
+            skill_in(crate::models::SkillScope::Project, "local-a"),
+        ]);
+
+        let output = run(
+            &ListArgs {
+                json: true,
+                tree: true,
+                archived: false,
+                changed: false,
+                update_mode: None,
+            },
+            &service,
+            OutputStyle::Emoji,
+        )
+        .await
+        .unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(parsed["global"].as_array().unwrap().len(), 1);
+        assert_eq!(parsed["global"][0]["name"], "shared");
+        assert_eq!(parsed["project"].as_array().unwrap().len(), 1);
+        assert_eq!(parsed["project"][0]["name"], "local-a");
+    }
+
+    #[tokio::test]
+    async fn archived_flag_shows_archived_skills_instead_of_active_ones() {
+        let mut archived = a_skill();
+        archived.name = "gone".to_string();
+        archived.archived = true;
+        let service = StubSkillService::new(vec![a_skill(), archived]);
+
+        let active = run(
+            &ListArgs {
+                json: false,
+                tree: false,
+                archived: false,
+                changed: false,
+                update_mode: None,
+            },
+            &service,
+            OutputStyle::Plain,
+        )
+        .await
+        .unwrap();
+        assert_eq!(active, "[enabled] example");
+
+        let archived_output = run(
+            &ListArgs {
+                json: false,
+                tree: false,
+                archived: true,
+                changed: false,
+                update_mode: None,
+            },
+            &service,
+            OutputStyle::Plain,
+        )
+        .await
+        .unwrap();
+        assert_eq!(archived_output, "[enabled] gone");
+    }
+
+    #[tokio::test]
+    async fn update_mode_filters_to_only_matching_skills() {
+        let mut auto = a_skill();
+        auto.name = "auto-skill".to_string();
+        auto.update_mode = crate::models::UpdateMode::Auto;
+        let mut manual = a_skill();
+        manual.name = "manual-skill".to_string();
+        manual.update_mode = crate::models::UpdateMode::Manual;
+        let service = StubSkillService::new(vec![auto, manual]);
+
+        let output = run(
+            &ListArgs {
+                json: false,
+                tree: false,
+                archived: false,
+                changed: false,
+                update_mode: Some("manual".to_string()),
+            },
+            &service,
+            OutputStyle::Plain,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output, "[enabled] manual-skill");
+    }
+
+    #[tokio::test]
+    async fn hand_editing_a_local_skills_file_makes_it_appear_under_changed() {
+        let path = std::env::temp_dir().join("csm_test_list_changed.md");
+        std::fs::write(&path, "original content").unwrap();
+
+        let mut local_skill = a_skill();
+        local_skill.source = crate::models::SkillSource::Local(path.to_string_lossy().into_owned());
+        local_skill.content_hash = crate::utils::hash::hash_content("original content");
+        let service = StubSkillService::new(vec![local_skill]);
+
+        let unchanged = run(
+            &ListArgs { json: false, tree: false, archived: false, changed: true, update_mode: None },
+            &service,
+            OutputStyle::Plain,
+        )
+        .await
+        .unwrap();
+        assert_eq!(unchanged, "");
+
+        std::fs::write(&path, "hand-edited content").unwrap();
+
+        let changed = run(
+            &ListArgs { json: false, tree: false, archived: false, changed: true, update_mode: None },
+            &service,
+            OutputStyle::Plain,
+        )
+        .await
+        .unwrap();
+        assert!(changed.starts_with("example "), "expected drift line, got: {changed}");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
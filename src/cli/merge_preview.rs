@@ -0,0 +1,22 @@
+use clap::Args;
+
+use crate::error::Result;
+use crate::models::SkillScope;
+use crate::services::SkillService;
+
+#[derive(Debug, Args)]
+pub struct MergePreviewArgs {
+    /// `global`, `project`, or `all` (the default).
+    #[arg(long, default_value = "all")]
+    pub scope: String,
+}
+
+pub async fn run(args: &MergePreviewArgs, service: &dyn SkillService) -> Result<String> {
+    let scope = args.scope.parse::<SkillScope>().ok();
+
+    let stats = service.merge_preview(scope).await?;
+    Ok(format!(
+        "{} skills ({} enabled), {} bytes would be merged",
+        stats.skill_count, stats.enabled_count, stats.total_bytes
+    ))
+}
@@ -0,0 +1,115 @@
+pub mod add;
+pub mod add_summary;
+pub mod config;
+pub mod conflicts;
+pub mod diff;
+pub mod doctor;
+pub mod env;
+pub mod export;
+pub mod import;
+pub mod init;
+pub mod list;
+pub mod merge_preview;
+pub mod note;
+pub mod output;
+pub mod priority;
+pub mod remove;
+pub mod rename;
+pub mod resolve;
+pub mod restore;
+pub mod search;
+pub mod show;
+pub mod sync;
+pub mod tag;
+pub mod update;
+
+use clap::{Parser, Subcommand};
+
+pub use add::AddArgs;
+pub use add_summary::AddSummary;
+pub use config::ConfigArgs;
+pub use conflicts::ConflictsArgs;
+pub use diff::DiffArgs;
+pub use doctor::DoctorArgs;
+pub use env::EnvArgs;
+pub use export::ExportArgs;
+pub use import::ImportArgs;
+pub use init::InitArgs;
+pub use list::ListArgs;
+pub use merge_preview::MergePreviewArgs;
+pub use note::NoteArgs;
+pub use output::OutputStyle;
+pub use priority::PriorityArgs;
+pub use remove::RemoveArgs;
+pub use rename::RenameArgs;
+pub use resolve::resolve_skill_name;
+pub use restore::RestoreArgs;
+pub use search::SearchArgs;
+pub use show::ShowArgs;
+pub use sync::SyncArgs;
+pub use tag::TagArgs;
+pub use update::UpdateArgs;
+
+#[derive(Debug, Parser)]
+#[command(name = "csm", about = "Manage Claude skills across global and project scopes")]
+pub struct Cli {
+    /// Path to a specific config file, overriding CSM_HOME/~/.csm detection.
+    #[arg(long, global = true)]
+    pub config: Option<std::path::PathBuf>,
+
+    /// Disable emoji in human-readable output.
+    #[arg(long, alias = "no-emoji", global = true)]
+    pub plain: bool,
+
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    /// List stored skills.
+    List(ListArgs),
+    /// Add a new skill.
+    Add(AddArgs),
+    /// Initialize the csm home directory.
+    Init(InitArgs),
+    /// Preview aggregate stats for a merge without writing anything.
+    MergePreview(MergePreviewArgs),
+    /// Show a single skill, including any conflicts it's involved in.
+    Show(ShowArgs),
+    /// Show a unified diff between two skills' stored contents.
+    Diff(DiffArgs),
+    /// Export skills, either all of them or a chosen handful by name.
+    /// `--full` writes a full backup (skills, conflicts, config) instead.
+    Export(ExportArgs),
+    /// Restore skills and conflicts from a `csm export --full` backup (its config is not applied).
+    Import(ImportArgs),
+    /// Search skills by name, tags, and optionally content.
+    Search(SearchArgs),
+    /// Check that `CLAUDE.md` matches the enabled skill set, optionally repairing it.
+    Doctor(DoctorArgs),
+    /// Check (or with --rebuild, regenerate) CLAUDE.md against the stored
+    /// skill set, with a per-scope summary. Runs the same engine as `doctor`.
+    Sync(SyncArgs),
+    /// Set or clear a skill's local note, e.g. why it's pinned/disabled.
+    Note(NoteArgs),
+    /// Archive a skill (or permanently delete it with `--purge`).
+    Remove(RemoveArgs),
+    /// Rename a skill in place, keeping its id, timestamps, and history.
+    Rename(RenameArgs),
+    /// Add or remove a skill's tags.
+    Tag(TagArgs),
+    /// Set a skill's merge priority.
+    Priority(PriorityArgs),
+    /// Bring an archived skill back into normal listings.
+    Restore(RestoreArgs),
+    /// Detect conflicts between enabled skills and print or export the report.
+    Conflicts(ConflictsArgs),
+    /// Check enabled skills against their upstream sources and apply any updates.
+    Update(UpdateArgs),
+    /// Get or set persisted config values, e.g. `csm config set github.token <value>`.
+    /// `csm config path` prints the resolved config/database/cache paths.
+    Config(ConfigArgs),
+    /// Print effective environment inputs (CSM_HOME, token presence), for support.
+    Env(EnvArgs),
+}
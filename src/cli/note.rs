@@ -0,0 +1,110 @@
+use clap::Args;
+
+use crate::cli::resolve::resolve_skill_name;
+use crate::error::{CsmError, Result};
+use crate::models::SkillScope;
+use crate::services::SkillService;
+
+#[derive(Debug, Args)]
+pub struct NoteArgs {
+    pub name: String,
+
+    /// The note text. Omit to clear the skill's existing note.
+    pub text: Option<String>,
+
+    /// `global` or `project` (the default).
+    #[arg(long, default_value = "project")]
+    pub scope: String,
+}
+
+pub async fn run(args: &NoteArgs, service: &dyn SkillService) -> Result<String> {
+    let scope: SkillScope = args
+        .scope
+        .parse()
+        .map_err(|e: crate::models::ParseSkillScopeError| CsmError::Validation(e.to_string()))?;
+    let all = service.list().await?;
+    let name = resolve_skill_name(&all, &args.name)?.name.clone();
+    let skill = service.set_note(&name, scope, args.text.clone()).await?;
+
+    Ok(match &skill.notes {
+        Some(notes) => format!("note set on '{}': {notes}", skill.name),
+        None => format!("note cleared on '{}'", skill.name),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Skill, SkillSource, UpdateMode, UpdateTrigger};
+
+    use crate::test_support::StubSkillService;
+
+    fn skill() -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: "pinned".to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::Manual,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn setting_and_reading_back_a_note_round_trips() {
+        let service = StubSkillService::new(vec![skill()]);
+
+        let output = run(
+            &NoteArgs {
+                name: "pinned".to_string(),
+                text: Some("pinned until v2 lands".to_string()),
+                scope: "global".to_string(),
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output, "note set on 'pinned': pinned until v2 lands");
+        assert_eq!(
+            service.skills()[0].notes.as_deref(),
+            Some("pinned until v2 lands")
+        );
+    }
+
+    #[tokio::test]
+    async fn omitting_the_text_clears_the_note() {
+        let mut with_note = skill();
+        with_note.notes = Some("old note".to_string());
+        let service = StubSkillService::new(vec![with_note]);
+
+        let output = run(
+            &NoteArgs {
+                name: "pinned".to_string(),
+                text: None,
+                scope: "global".to_string(),
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output, "note cleared on 'pinned'");
+        assert!(service.skills()[0].notes.is_none());
+    }
+}
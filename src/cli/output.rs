@@ -0,0 +1,25 @@
+/// Controls whether human-readable output may use emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStyle {
+    Emoji,
+    Plain,
+}
+
+impl OutputStyle {
+    pub fn from_flag(plain: bool) -> Self {
+        if plain {
+            OutputStyle::Plain
+        } else {
+            OutputStyle::Emoji
+        }
+    }
+
+    pub fn enabled_marker(self, enabled: bool) -> &'static str {
+        match (self, enabled) {
+            (OutputStyle::Emoji, true) => "✅",
+            (OutputStyle::Emoji, false) => "❌",
+            (OutputStyle::Plain, true) => "[enabled]",
+            (OutputStyle::Plain, false) => "[disabled]",
+        }
+    }
+}
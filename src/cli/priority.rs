@@ -0,0 +1,91 @@
+use clap::{Args, Subcommand};
+
+use crate::cli::resolve::resolve_skill_name;
+use crate::error::{CsmError, Result};
+use crate::models::SkillScope;
+use crate::services::SkillService;
+
+#[derive(Debug, Args)]
+pub struct PriorityArgs {
+    #[command(subcommand)]
+    pub command: PriorityCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PriorityCommand {
+    /// Sets a skill's merge priority.
+    Set {
+        name: String,
+        value: i32,
+        #[arg(long, default_value = "project")]
+        scope: String,
+    },
+}
+
+pub async fn run(args: &PriorityArgs, service: &dyn SkillService) -> Result<String> {
+    let PriorityCommand::Set { name, value, scope } = &args.command;
+    let scope: SkillScope = scope
+        .parse()
+        .map_err(|e: crate::models::ParseSkillScopeError| CsmError::Validation(e.to_string()))?;
+
+    let all = service.list().await?;
+    let resolved_name = resolve_skill_name(&all, name)?.name.clone();
+
+    let skill = service.set_priority(&resolved_name, scope, *value).await?;
+    Ok(format!("set priority of '{}' to {}", skill.name, skill.priority))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Skill, SkillSource, UpdateMode, UpdateTrigger};
+
+    use crate::test_support::StubSkillService;
+
+    fn skill() -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: "prioritized".to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::Manual,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn setting_priority_updates_and_reports_the_new_value() {
+        let service = StubSkillService::new(vec![skill()]);
+
+        let output = run(
+            &PriorityArgs {
+                command: PriorityCommand::Set {
+                    name: "prioritized".to_string(),
+                    value: 7,
+                    scope: "global".to_string(),
+                },
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output, "set priority of 'prioritized' to 7");
+        assert_eq!(service.skills()[0].priority, 7);
+    }
+}
@@ -0,0 +1,112 @@
+use clap::Args;
+
+use crate::cli::resolve::resolve_skill_name;
+use crate::error::{CsmError, Result};
+use crate::models::SkillScope;
+use crate::services::SkillService;
+
+#[derive(Debug, Args)]
+pub struct RemoveArgs {
+    pub name: String,
+
+    /// `global` or `project` (the default).
+    #[arg(long, default_value = "project")]
+    pub scope: String,
+
+    /// Permanently delete the skill instead of archiving it. Cannot be undone.
+    #[arg(long)]
+    pub purge: bool,
+}
+
+pub async fn run(args: &RemoveArgs, service: &dyn SkillService) -> Result<String> {
+    let scope: SkillScope = args
+        .scope
+        .parse()
+        .map_err(|e: crate::models::ParseSkillScopeError| CsmError::Validation(e.to_string()))?;
+
+    let all = service.list().await?;
+    let name = resolve_skill_name(&all, &args.name)?.name.clone();
+
+    if args.purge {
+        service.purge(&name, scope).await?;
+        return Ok(format!("permanently removed '{name}'"));
+    }
+
+    let skill = service.archive(&name, scope).await?;
+    Ok(format!(
+        "archived '{}'; restore with `csm restore {}`",
+        skill.name, skill.name
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Skill, SkillSource, UpdateMode, UpdateTrigger};
+
+    use crate::test_support::StubSkillService;
+
+    fn skill() -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: "stale".to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::Manual,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn removing_without_purge_archives_the_skill() {
+        let service = StubSkillService::new(vec![skill()]);
+
+        let output = run(
+            &RemoveArgs {
+                name: "stale".to_string(),
+                scope: "global".to_string(),
+                purge: false,
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output, "archived 'stale'; restore with `csm restore stale`");
+        assert!(service.skills()[0].archived);
+    }
+
+    #[tokio::test]
+    async fn removing_with_purge_deletes_the_skill_permanently() {
+        let service = StubSkillService::new(vec![skill()]);
+
+        let output = run(
+            &RemoveArgs {
+                name: "stale".to_string(),
+                scope: "global".to_string(),
+                purge: true,
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output, "permanently removed 'stale'");
+        assert!(service.skills().is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+use clap::Args;
+
+use crate::cli::resolve::resolve_skill_name;
+use crate::error::{CsmError, Result};
+use crate::models::SkillScope;
+use crate::services::SkillService;
+
+#[derive(Debug, Args)]
+pub struct RenameArgs {
+    pub old_name: String,
+
+    pub new_name: String,
+
+    /// `global` or `project` (the default).
+    #[arg(long, default_value = "project")]
+    pub scope: String,
+}
+
+pub async fn run(args: &RenameArgs, service: &dyn SkillService) -> Result<String> {
+    let scope: SkillScope = args
+        .scope
+        .parse()
+        .map_err(|e: crate::models::ParseSkillScopeError| CsmError::Validation(e.to_string()))?;
+
+    let all = service.list().await?;
+    let name = resolve_skill_name(&all, &args.old_name)?.name.clone();
+
+    let skill = service.rename(&name, scope, &args.new_name).await?;
+    Ok(format!("renamed '{name}' to '{}'", skill.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Skill, SkillSource, UpdateMode, UpdateTrigger};
+
+    use crate::test_support::StubSkillService;
+
+    fn skill(name: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::Manual,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn renaming_updates_the_name_the_old_one_no_longer_resolves() {
+        let service = StubSkillService::new(vec![skill("old-name")]);
+
+        let output = run(
+            &RenameArgs {
+                old_name: "old-name".to_string(),
+                new_name: "new-name".to_string(),
+                scope: "global".to_string(),
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output, "renamed 'old-name' to 'new-name'");
+
+        let all = service.list().await.unwrap();
+        assert!(resolve_skill_name(&all, "new-name").is_ok());
+        assert!(matches!(
+            resolve_skill_name(&all, "old-name"),
+            Err(CsmError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn renaming_to_a_name_already_taken_by_another_skill_is_rejected() {
+        let service = StubSkillService::new(vec![skill("a"), skill("b")]);
+
+        let err = run(
+            &RenameArgs {
+                old_name: "a".to_string(),
+                new_name: "b".to_string(),
+                scope: "global".to_string(),
+            },
+            &service,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, CsmError::AlreadyExists(_)));
+    }
+}
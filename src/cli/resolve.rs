@@ -0,0 +1,96 @@
+use crate::error::{CsmError, Result};
+use crate::models::Skill;
+
+/// Resolves `query` against `skills`' names for commands that take a skill
+/// name (`show`/`remove`/`note`/`restore`): an exact match wins outright;
+/// otherwise a unique prefix match resolves, so `csm show hel` can find
+/// `hello-world` without typing it out. Two or more prefix matches is an
+/// error listing the candidates, so an ambiguous abbreviation never
+/// silently picks one.
+pub fn resolve_skill_name<'a>(skills: &'a [Skill], query: &str) -> Result<&'a Skill> {
+    if let Some(exact) = skills.iter().find(|s| s.name == query) {
+        return Ok(exact);
+    }
+
+    let matches: Vec<&Skill> = skills.iter().filter(|s| s.name.starts_with(query)).collect();
+    match matches.as_slice() {
+        [] => Err(CsmError::NotFound(query.to_string())),
+        [only] => Ok(only),
+        many => {
+            let mut names: Vec<&str> = many.iter().map(|s| s.name.as_str()).collect();
+            names.sort();
+            Err(CsmError::Validation(format!(
+                "'{query}' matches multiple skills: {}",
+                names.join(", ")
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SkillScope, SkillSource, UpdateMode, UpdateTrigger};
+
+    fn skill(name: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::Manual,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn an_exact_match_wins_even_when_it_is_also_a_prefix_of_another_name() {
+        let skills = vec![skill("hello"), skill("hello-world")];
+        let resolved = resolve_skill_name(&skills, "hello").unwrap();
+        assert_eq!(resolved.name, "hello");
+    }
+
+    #[test]
+    fn a_unique_prefix_resolves_to_its_only_match() {
+        let skills = vec![skill("hello-world"), skill("deploy-notes")];
+        let resolved = resolve_skill_name(&skills, "hel").unwrap();
+        assert_eq!(resolved.name, "hello-world");
+    }
+
+    #[test]
+    fn an_ambiguous_prefix_errors_listing_every_candidate() {
+        let skills = vec![skill("deploy-staging"), skill("deploy-prod")];
+        let err = resolve_skill_name(&skills, "deploy").unwrap_err();
+        match err {
+            CsmError::Validation(msg) => {
+                assert!(msg.contains("deploy-prod"));
+                assert!(msg.contains("deploy-staging"));
+            }
+            other => panic!("expected Validation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn no_match_at_all_is_not_found() {
+        let skills = vec![skill("hello-world")];
+        assert!(matches!(
+            resolve_skill_name(&skills, "bogus"),
+            Err(CsmError::NotFound(_))
+        ));
+    }
+}
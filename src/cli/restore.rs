@@ -0,0 +1,82 @@
+use clap::Args;
+
+use crate::cli::resolve::resolve_skill_name;
+use crate::error::{CsmError, Result};
+use crate::models::SkillScope;
+use crate::services::SkillService;
+
+#[derive(Debug, Args)]
+pub struct RestoreArgs {
+    pub name: String,
+
+    /// `global` or `project` (the default).
+    #[arg(long, default_value = "project")]
+    pub scope: String,
+}
+
+pub async fn run(args: &RestoreArgs, service: &dyn SkillService) -> Result<String> {
+    let scope: SkillScope = args
+        .scope
+        .parse()
+        .map_err(|e: crate::models::ParseSkillScopeError| CsmError::Validation(e.to_string()))?;
+
+    let all = service.list().await?;
+    let name = resolve_skill_name(&all, &args.name)?.name.clone();
+
+    let skill = service.restore(&name, scope).await?;
+    Ok(format!("restored '{}'", skill.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Skill, SkillSource, UpdateMode, UpdateTrigger};
+
+    use crate::test_support::StubSkillService;
+
+    fn archived_skill() -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: "stale".to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::Manual,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: true,
+            archived_at: Some(now),
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn restoring_clears_the_archived_flag() {
+        let service = StubSkillService::new(vec![archived_skill()]);
+
+        let output = run(
+            &RestoreArgs {
+                name: "stale".to_string(),
+                scope: "global".to_string(),
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output, "restored 'stale'");
+        let restored = service.skills();
+        assert!(!restored[0].archived);
+        assert!(restored[0].archived_at.is_none());
+    }
+}
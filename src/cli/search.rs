@@ -0,0 +1,175 @@
+use clap::Args;
+use regex::Regex;
+
+use crate::error::{CsmError, Result};
+use crate::models::Skill;
+use crate::services::SkillService;
+
+#[derive(Debug, Args)]
+pub struct SearchArgs {
+    /// Substring, or (with `--regex`) a regular expression to match.
+    pub query: String,
+
+    /// Treat `query` as a regex instead of a plain substring.
+    #[arg(long)]
+    pub regex: bool,
+
+    /// Also match against skill content, not just name and tags.
+    #[arg(long)]
+    pub content: bool,
+}
+
+pub async fn run(args: &SearchArgs, service: &dyn SkillService) -> Result<Vec<Skill>> {
+    let skills = service.list().await?;
+
+    // `--regex` needs a full scan regardless of source, since neither the
+    // in-memory fakes nor SqliteSkillRepository's FTS5 index support
+    // arbitrary regex matching - only plain `--content` searches are worth
+    // routing through `search_content_only` (FTS5-backed there, so it scales
+    // with the index instead of a full table scan).
+    if args.content && !args.regex {
+        let mut matched = service.search_content_only(&args.query).await?;
+        for skill in &skills {
+            let already_matched = matched.iter().any(|m| m.id == skill.id);
+            if !already_matched
+                && (skill.name.contains(&args.query) || skill.tags.iter().any(|t| t.contains(&args.query)))
+            {
+                matched.push(skill.clone());
+            }
+        }
+        return Ok(matched);
+    }
+
+    let matches: Box<dyn Fn(&Skill) -> bool> = if args.regex {
+        let pattern = Regex::new(&args.query)
+            .map_err(|e| CsmError::Validation(format!("invalid regex '{}': {e}", args.query)))?;
+        Box::new(move |skill: &Skill| {
+            pattern.is_match(&skill.name)
+                || skill.tags.iter().any(|t| pattern.is_match(t))
+                || (args.content && pattern.is_match(&skill.content))
+        })
+    } else {
+        let needle = args.query.clone();
+        Box::new(move |skill: &Skill| {
+            skill.name.contains(&needle) || skill.tags.iter().any(|t| t.contains(&needle))
+        })
+    };
+
+    Ok(skills.into_iter().filter(|s| matches(s)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SkillScope, SkillSource, UpdateMode, UpdateTrigger};
+
+    use crate::test_support::StubSkillService;
+
+    fn skill(name: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn regex_anchors_match_only_the_intended_skill() {
+        let service = StubSkillService::new(vec![skill("hello-world"), skill("say-hello"), skill("other")]);
+        let result = run(
+            &SearchArgs {
+                query: "^hello-".to_string(),
+                regex: true,
+                content: false,
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "hello-world");
+    }
+
+    #[tokio::test]
+    async fn invalid_regex_is_rejected_with_a_clear_error() {
+        let service = StubSkillService::new(vec![skill("a")]);
+        let err = run(
+            &SearchArgs {
+                query: "(unclosed".to_string(),
+                regex: true,
+                content: false,
+            },
+            &service,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, CsmError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn percent_and_underscore_in_a_plain_query_are_matched_literally() {
+        // Non-regex mode matches via `str::contains`, not a SQL `LIKE`, so
+        // `%`/`_` are already ordinary characters here rather than
+        // wildcards that would need escaping.
+        let service = StubSkillService::new(vec![skill("100%-done"), skill("100x-done")]);
+        let result = run(
+            &SearchArgs {
+                query: "100%".to_string(),
+                regex: false,
+                content: false,
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "100%-done");
+    }
+
+    #[tokio::test]
+    async fn content_flag_delegates_to_search_content_only_case_insensitively() {
+        // SkillService::search_content_only lowercases both sides (matching
+        // SqliteSkillRepository's FTS5-backed override), whereas the plain
+        // name/tag fallback here matches case-sensitively - so this only
+        // passes if --content is actually routed through the service method
+        // instead of an in-memory `content.contains` scan.
+        let mut with_content = skill("ops-runbook");
+        with_content.content = "run the DEPLOY-FLEET script before merging".to_string();
+        let service = StubSkillService::new(vec![with_content, skill("unrelated")]);
+
+        let result = run(
+            &SearchArgs {
+                query: "deploy-fleet".to_string(),
+                regex: false,
+                content: true,
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "ops-runbook");
+    }
+}
@@ -0,0 +1,534 @@
+use clap::Args;
+use serde::Serialize;
+
+use crate::cli::resolve::resolve_skill_name;
+use crate::error::{CsmError, Result};
+use crate::models::{Conflict, SkillView};
+use crate::services::{ConflictService, SkillService};
+use crate::utils::headings::{extract_headings, extract_section};
+use crate::utils::relative_time::humanize;
+
+#[derive(Debug, Args)]
+pub struct ShowArgs {
+    pub name: String,
+
+    #[arg(long)]
+    pub json: bool,
+
+    /// Print the skill's heading outline instead of its full content.
+    #[arg(long)]
+    pub section_list: bool,
+
+    /// Print only the named section's body.
+    #[arg(long)]
+    pub section: Option<String>,
+
+    /// Also print the skill's full content body.
+    #[arg(long)]
+    pub content: bool,
+
+    /// Show `created`/`updated` as exact RFC3339 timestamps only, without
+    /// the relative phrasing ("3 days ago") appended by default. Has no effect with `--json`,
+    /// which always uses RFC3339.
+    #[arg(long)]
+    pub absolute: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ShowView {
+    #[serde(flatten)]
+    skill: SkillView,
+    /// Local annotation, e.g. why this skill is pinned/disabled. Not part of
+    /// `SkillView` since it's excluded from `list`/`search`/`export` output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notes: Option<String>,
+    conflicts: Vec<ConflictSummary>,
+    /// Present only when `--content` is passed; `SkillView` has no body field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ConflictSummary {
+    with_skill_id: i64,
+    status: String,
+    description: String,
+}
+
+pub async fn run(
+    args: &ShowArgs,
+    skills: &dyn SkillService,
+    conflicts: &dyn ConflictService,
+) -> Result<String> {
+    let all = skills.list().await?;
+    let skill = resolve_skill_name(&all, &args.name)?.clone();
+
+    if args.section_list {
+        let outline = extract_headings(&skill.content);
+        return if args.json {
+            Ok(serde_json::to_string_pretty(&outline)?)
+        } else {
+            Ok(outline
+                .iter()
+                .map(|h| format!("{}{}", "  ".repeat((h.level - 1) as usize), h.title))
+                .collect::<Vec<_>>()
+                .join("\n"))
+        };
+    }
+
+    if let Some(title) = &args.section {
+        let body = extract_section(&skill.content, title)
+            .ok_or_else(|| CsmError::NotFound(format!("section '{title}' in skill '{}'", skill.name)))?;
+        return if args.json {
+            Ok(serde_json::to_string_pretty(&serde_json::json!({
+                "section": title,
+                "content": body,
+            }))?)
+        } else {
+            Ok(body)
+        };
+    }
+
+    let related: Vec<Conflict> = conflicts.conflicts_for_skill(skill.id).await?;
+    let summaries = related
+        .into_iter()
+        .map(|c| ConflictSummary {
+            with_skill_id: if c.skill_a_id == skill.id {
+                c.skill_b_id
+            } else {
+                c.skill_a_id
+            },
+            status: format!("{:?}", c.status),
+            description: c.description,
+        })
+        .collect();
+
+    let view = ShowView {
+        skill: SkillView::from(&skill),
+        notes: skill.notes.clone(),
+        conflicts: summaries,
+        content: args.content.then(|| skill.content.clone()),
+    };
+
+    if args.json {
+        Ok(serde_json::to_string_pretty(&view)?)
+    } else {
+        let now = chrono::Utc::now();
+        let created = if args.absolute {
+            view.skill.created_at.to_rfc3339()
+        } else {
+            format!("{} ({})", view.skill.created_at.to_rfc3339(), humanize(view.skill.created_at, now))
+        };
+        let updated = if args.absolute {
+            view.skill.updated_at.to_rfc3339()
+        } else {
+            format!("{} ({})", view.skill.updated_at.to_rfc3339(), humanize(view.skill.updated_at, now))
+        };
+        let mut out = format!(
+            "{}\nsource: {}\nscope: {}\nenabled: {}\npriority: {}\ntags: {}\ncontent hash: {}\ncreated: {}\nupdated: {}\nconflicts: {}",
+            view.skill.name,
+            view.skill.source,
+            view.skill.scope,
+            view.skill.enabled,
+            view.skill.priority,
+            if view.skill.tags.is_empty() { "-".to_string() } else { view.skill.tags.join(", ") },
+            view.skill.content_hash,
+            created,
+            updated,
+            view.conflicts.len(),
+        );
+        if let Some(notes) = &view.notes {
+            out.push_str(&format!("\nnote: {notes}"));
+        }
+        if let Some(content) = &view.content {
+            out.push_str(&format!("\n\n{content}"));
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Skill, SkillScope, SkillSource, UpdateMode, UpdateTrigger};
+    use async_trait::async_trait;
+
+    use crate::test_support::StubSkillService;
+
+    struct FakeConflicts(Vec<Conflict>);
+
+    #[async_trait]
+    impl ConflictService for FakeConflicts {
+        async fn detect(&self) -> Result<Vec<Conflict>> {
+            unimplemented!()
+        }
+        async fn conflicts_for_skill(&self, skill_id: i64) -> Result<Vec<Conflict>> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|c| c.skill_a_id == skill_id || c.skill_b_id == skill_id)
+                .cloned()
+                .collect())
+        }
+        async fn restore(&self, _conflict: Conflict) -> Result<Conflict> {
+            unimplemented!()
+        }
+        async fn ignore(&self, _conflict_id: i64) -> Result<crate::models::Conflict> {
+            unimplemented!()
+        }
+        async fn clear_whitelist(&self) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn json_show_includes_conflicts_involving_the_skill() {
+        let now = chrono::Utc::now();
+        let skill = Skill {
+            id: 1,
+            name: "a".to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        };
+        let conflict = crate::models::Conflict {
+            id: 1,
+            skill_a_id: 1,
+            skill_b_id: 2,
+            description: "clash".to_string(),
+            status: crate::models::ConflictStatus::Unresolved,
+            detected_at: now,
+            severity: 200,
+        };
+
+        let output = run(
+            &ShowArgs {
+                name: "a".to_string(),
+                json: true,
+                section_list: false,
+                section: None,
+                content: false,
+                absolute: false,
+            },
+            &StubSkillService::new(vec![skill]),
+            &FakeConflicts(vec![conflict]),
+        )
+        .await
+        .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["conflicts"].as_array().unwrap().len(), 1);
+        assert_eq!(parsed["conflicts"][0]["with_skill_id"], 2);
+    }
+
+    #[tokio::test]
+    async fn plain_show_prints_the_note_when_one_is_set() {
+        let now = chrono::Utc::now();
+        let skill = Skill {
+            id: 1,
+            name: "a".to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: false,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: Some("pinned until upstream fixes the regression".to_string()),
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        let output = run(
+            &ShowArgs {
+                name: "a".to_string(),
+                json: false,
+                section_list: false,
+                section: None,
+                content: false,
+                absolute: false,
+            },
+            &StubSkillService::new(vec![skill]),
+            &FakeConflicts(Vec::new()),
+        )
+        .await
+        .unwrap();
+
+        assert!(output.contains("note: pinned until upstream fixes the regression"));
+    }
+
+    #[tokio::test]
+    async fn plain_show_displays_created_and_updated_relative_to_now() {
+        let now = chrono::Utc::now();
+        let three_days_ago = now - chrono::Duration::days(3);
+        let skill = Skill {
+            id: 1,
+            name: "a".to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: three_days_ago,
+            updated_at: three_days_ago,
+        };
+
+        let output = run(
+            &ShowArgs {
+                name: "a".to_string(),
+                json: false,
+                section_list: false,
+                section: None,
+                content: false,
+                absolute: false,
+            },
+            &StubSkillService::new(vec![skill]),
+            &FakeConflicts(Vec::new()),
+        )
+        .await
+        .unwrap();
+
+        assert!(output.contains("3 days ago"));
+    }
+
+    #[tokio::test]
+    async fn absolute_flag_omits_the_relative_phrasing() {
+        let now = chrono::Utc::now();
+        let three_days_ago = now - chrono::Duration::days(3);
+        let skill = Skill {
+            id: 1,
+            name: "a".to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: three_days_ago,
+            updated_at: three_days_ago,
+        };
+
+        let output = run(
+            &ShowArgs {
+                name: "a".to_string(),
+                json: false,
+                section_list: false,
+                section: None,
+                content: false,
+                absolute: true,
+            },
+            &StubSkillService::new(vec![skill]),
+            &FakeConflicts(Vec::new()),
+        )
+        .await
+        .unwrap();
+
+        assert!(!output.contains("ago"));
+    }
+
+    #[tokio::test]
+    async fn content_flag_appends_the_full_body_after_the_summary_fields() {
+        let now = chrono::Utc::now();
+        let skill = Skill {
+            id: 1,
+            name: "a".to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "the full skill body".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 3,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: vec!["ops".to_string()],
+            created_at: now,
+            updated_at: now,
+        };
+
+        let output = run(
+            &ShowArgs {
+                name: "a".to_string(),
+                json: false,
+                section_list: false,
+                section: None,
+                content: true,
+                absolute: false,
+            },
+            &StubSkillService::new(vec![skill]),
+            &FakeConflicts(Vec::new()),
+        )
+        .await
+        .unwrap();
+
+        assert!(output.contains("priority: 3"));
+        assert!(output.contains("tags: ops"));
+        assert!(output.contains("the full skill body"));
+    }
+
+    fn named(name: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::Manual,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn a_unique_prefix_of_the_name_resolves_the_skill() {
+        let output = run(
+            &ShowArgs {
+                name: "hel".to_string(),
+                json: false,
+                section_list: false,
+                section: None,
+                content: false,
+                absolute: false,
+            },
+            &StubSkillService::new(vec![named("hello-world"), named("deploy-notes")]),
+            &FakeConflicts(Vec::new()),
+        )
+        .await
+        .unwrap();
+
+        assert!(output.starts_with("hello-world\n"));
+    }
+
+    #[tokio::test]
+    async fn an_ambiguous_prefix_errors_instead_of_guessing() {
+        let err = run(
+            &ShowArgs {
+                name: "deploy".to_string(),
+                json: false,
+                section_list: false,
+                section: None,
+                content: false,
+                absolute: false,
+            },
+            &StubSkillService::new(vec![named("deploy-staging"), named("deploy-prod")]),
+            &FakeConflicts(Vec::new()),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, CsmError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn section_list_prints_the_json_outline_of_a_multi_heading_skill() {
+        let now = chrono::Utc::now();
+        let skill = Skill {
+            id: 1,
+            name: "a".to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "# Title\nintro\n\n## Setup\nsteps\n".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        let output = run(
+            &ShowArgs {
+                name: "a".to_string(),
+                json: true,
+                section_list: true,
+                section: None,
+                content: false,
+                absolute: false,
+            },
+            &StubSkillService::new(vec![skill]),
+            &FakeConflicts(vec![]),
+        )
+        .await
+        .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let outline = parsed.as_array().unwrap();
+        assert_eq!(outline.len(), 2);
+        assert_eq!(outline[0]["level"], 1);
+        assert_eq!(outline[0]["title"], "Title");
+        assert_eq!(outline[1]["level"], 2);
+        assert_eq!(outline[1]["title"], "Setup");
+    }
+}
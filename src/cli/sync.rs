@@ -0,0 +1,395 @@
+use std::path::Path;
+
+use clap::Args;
+
+use crate::error::Result;
+use crate::models::{MergePreviewStats, SkillScope};
+use crate::services::{MergeService, SkillService};
+
+use super::doctor::{self, DoctorArgs, DoctorReport};
+
+/// `csm sync`: check (`--verify`, the default) or regenerate (`--rebuild`)
+/// `CLAUDE.md` against the stored skill set. This tree has one merge target
+/// shared by every scope rather than a file per scope, so `sync` runs the
+/// same engine as `csm doctor` (see `DoctorReport` for what "in sync"
+/// means) instead of a separate implementation. There's no symlink or
+/// output-storage layer here, so `--verify` is that DB-vs-file consistency
+/// check, not a filesystem repair.
+#[derive(Debug, Args)]
+pub struct SyncArgs {
+    /// Regenerate CLAUDE.md from the enabled skill set.
+    #[arg(long, conflicts_with = "verify")]
+    pub rebuild: bool,
+
+    /// Check CLAUDE.md against the stored skill set without writing.
+    /// This is what happens when neither flag is passed, too; passing it
+    /// explicitly also wins over --rebuild in `run` itself, not just via
+    /// clap's `conflicts_with` (which only fires at CLI-parse time, not when
+    /// `SyncArgs` is constructed directly, as the tests below do).
+    #[arg(long, conflicts_with = "rebuild")]
+    pub verify: bool,
+
+    /// With --rebuild, report how many lines would change instead of
+    /// writing anything. Exits nonzero (via the same rule as `doctor
+    /// --dry-run`) when CLAUDE.md isn't already up to date.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// `doctor`'s consistency report, plus (for `--rebuild`) a per-scope merge
+/// summary from `SkillService::merge_preview_all`.
+#[derive(Debug, Default, PartialEq)]
+pub struct SyncReport {
+    pub doctor: DoctorReport,
+    /// Empty unless `--rebuild` was passed.
+    pub scopes: Vec<(SkillScope, MergePreviewStats)>,
+}
+
+impl std::fmt::Display for SyncReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.doctor)?;
+        for (scope, stats) in &self.scopes {
+            write!(
+                f,
+                "\n  {scope}: {}/{} skill(s) enabled, {} byte(s)",
+                stats.enabled_count, stats.skill_count, stats.total_bytes
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub async fn run(
+    args: &SyncArgs,
+    service: &dyn SkillService,
+    merger: &dyn MergeService,
+    claude_md_path: &Path,
+    schema_version: Option<i64>,
+) -> Result<SyncReport> {
+    // --verify wins over --rebuild even here, not just via clap's
+    // conflicts_with, so constructing SyncArgs directly with both set can
+    // never silently rebuild instead of just checking.
+    let rebuild = args.rebuild && !args.verify;
+
+    let doctor_args = DoctorArgs {
+        // --dry-run must never also set fix, or doctor would both write the
+        // rebuild and report it as a no-op dry run in the same breath.
+        fix: rebuild && !args.dry_run,
+        dry_run: args.dry_run,
+        restore_backup: false,
+        diff: false,
+    };
+    let doctor = doctor::run(&doctor_args, service, merger, claude_md_path, schema_version).await?;
+
+    let scopes = if rebuild {
+        service.merge_preview_all().await?
+    } else {
+        Vec::new()
+    };
+
+    Ok(SyncReport { doctor, scopes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    use crate::models::{MergePreviewStats, Skill, SkillScope, SkillSource};
+    use crate::services::merge_service::RebuildSummary;
+
+    struct FakeSkillService {
+        skills: Vec<Skill>,
+    }
+
+    #[async_trait]
+    impl SkillService for FakeSkillService {
+        async fn add(&self, _n: &str, _s: SkillSource, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_or_overwrite(&self, _n: &str, _s: SkillSource, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_with_content(
+            &self,
+            _n: &str,
+            _s: SkillSource,
+            _sc: SkillScope,
+            _c: String,
+        ) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn list(&self) -> Result<Vec<Skill>> {
+            Ok(self.skills.clone())
+        }
+        async fn update_content(&self, _n: &str, _sc: SkillScope, _c: String) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn merge_preview(&self, scope: Option<SkillScope>) -> Result<MergePreviewStats> {
+            let mut stats = MergePreviewStats::default();
+            for skill in self.skills.iter().filter(|s| scope.map_or(true, |sc| sc == s.scope)) {
+                stats.skill_count += 1;
+                if skill.enabled {
+                    stats.enabled_count += 1;
+                    stats.total_bytes += skill.content.len();
+                }
+            }
+            Ok(stats)
+        }
+        async fn effective_list(&self) -> Result<Vec<Skill>> {
+            Ok(self.skills.clone())
+        }
+        async fn set_note(&self, _n: &str, _sc: SkillScope, _note: Option<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn archive(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn restore(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn purge(&self, _n: &str, _sc: SkillScope) -> Result<()> {
+            unimplemented!()
+        }
+        async fn rename(&self, _n: &str, _sc: SkillScope, _new: &str) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_tags(&self, _n: &str, _sc: SkillScope, _tags: Vec<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_priority(&self, _n: &str, _sc: SkillScope, _p: i32) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_enabled(&self, _n: &str, _sc: SkillScope, _e: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn record_update_result(&self, _n: &str, _sc: SkillScope, _failed: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn rollback_content(&self, _n: &str, _sc: SkillScope) -> Result<bool> {
+            unimplemented!()
+        }
+    }
+
+    struct FakeMerger {
+        rebuilt_with: Mutex<Option<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl MergeService for FakeMerger {
+        async fn merge(&self, _skill: &Skill) -> Result<()> {
+            unimplemented!()
+        }
+        async fn rebuild(&self, skills: &[Skill]) -> Result<RebuildSummary> {
+            *self.rebuilt_with.lock().unwrap() = Some(skills.iter().map(|s| s.name.clone()).collect());
+            Ok(RebuildSummary {
+                skill_count: skills.len(),
+                bytes: 0,
+            })
+        }
+        async fn restore_backup(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn skill(name: &str, scope: SkillScope, content: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope,
+            content: content.to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: crate::models::UpdateMode::Auto,
+            update_trigger: crate::models::UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_reports_missing_skills_without_writing() {
+        let path = std::env::temp_dir().join("csm_test_sync_verify.md");
+        let _ = std::fs::remove_file(&path);
+
+        let service = FakeSkillService {
+            skills: vec![skill("fresh-skill", SkillScope::Global, "content")],
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(
+            &SyncArgs { rebuild: false, verify: true, dry_run: false },
+            &service,
+            &merger,
+            &path,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.doctor.missing, vec!["fresh-skill".to_string()]);
+        assert!(merger.rebuilt_with.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn rebuild_writes_claude_md_to_match_the_enabled_skill_set() {
+        let path = std::env::temp_dir().join("csm_test_sync_rebuild.md");
+        let _ = std::fs::remove_file(&path);
+
+        let service = FakeSkillService {
+            skills: vec![skill("fresh-skill", SkillScope::Global, "content")],
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(
+            &SyncArgs { rebuild: true, verify: false, dry_run: false },
+            &service,
+            &merger,
+            &path,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert!(report.doctor.fixed);
+        assert_eq!(
+            merger.rebuilt_with.lock().unwrap().as_ref().unwrap(),
+            &vec!["fresh-skill".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn rebuild_reports_a_per_scope_merge_summary() {
+        let path = std::env::temp_dir().join("csm_test_sync_rebuild_scopes.md");
+        let _ = std::fs::remove_file(&path);
+
+        let service = FakeSkillService {
+            skills: vec![
+                skill("global-skill", SkillScope::Global, "12345"),
+                skill("project-skill", SkillScope::Project, "1234567"),
+            ],
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(
+            &SyncArgs { rebuild: true, verify: false, dry_run: false },
+            &service,
+            &merger,
+            &path,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let global = report.scopes.iter().find(|(scope, _)| *scope == SkillScope::Global).unwrap();
+        let project = report.scopes.iter().find(|(scope, _)| *scope == SkillScope::Project).unwrap();
+        assert_eq!(global.1.total_bytes, 5);
+        assert_eq!(project.1.total_bytes, 7);
+        assert!(report.to_string().contains("global: 1/1 skill(s) enabled, 5 byte(s)"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn verify_reports_no_scope_summary() {
+        let path = std::env::temp_dir().join("csm_test_sync_verify_no_scopes.md");
+        let _ = std::fs::remove_file(&path);
+
+        let service = FakeSkillService {
+            skills: vec![skill("fresh-skill", SkillScope::Global, "content")],
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(
+            &SyncArgs { rebuild: false, verify: true, dry_run: false },
+            &service,
+            &merger,
+            &path,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert!(report.scopes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn verify_wins_over_rebuild_when_both_are_set_outside_clap_parsing() {
+        // clap's `conflicts_with` only fires when SyncArgs is parsed from
+        // real CLI args; this exercises the same guarantee for a directly
+        // constructed SyncArgs, e.g. from another Rust caller.
+        let path = std::env::temp_dir().join("csm_test_sync_verify_wins.md");
+        let _ = std::fs::remove_file(&path);
+
+        let service = FakeSkillService {
+            skills: vec![skill("fresh-skill", SkillScope::Global, "content")],
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(
+            &SyncArgs { rebuild: true, verify: true, dry_run: false },
+            &service,
+            &merger,
+            &path,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert!(!report.doctor.fixed, "verify must block the rebuild");
+        assert!(merger.rebuilt_with.lock().unwrap().is_none());
+        assert!(report.scopes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn rebuild_with_dry_run_reports_the_stale_scope_without_writing() {
+        let path = std::env::temp_dir().join("csm_test_sync_rebuild_dry_run.md");
+        std::fs::write(&path, "\n## fresh-skill\n\nstale content\n").unwrap();
+
+        let service = FakeSkillService {
+            skills: vec![skill("fresh-skill", SkillScope::Global, "new content")],
+        };
+        let merger = FakeMerger {
+            rebuilt_with: Mutex::new(None),
+        };
+
+        let report = run(
+            &SyncArgs { rebuild: true, verify: false, dry_run: true },
+            &service,
+            &merger,
+            &path,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert!(!report.doctor.fixed, "dry run must not set fix");
+        assert!(merger.rebuilt_with.lock().unwrap().is_none(), "dry run must not write");
+        assert!(report.doctor.lines_changed.unwrap_or(0) > 0);
+        assert!(!report.doctor.is_up_to_date());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
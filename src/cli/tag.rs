@@ -0,0 +1,151 @@
+use clap::{Args, Subcommand};
+
+use crate::cli::resolve::resolve_skill_name;
+use crate::error::{CsmError, Result};
+use crate::models::SkillScope;
+use crate::services::SkillService;
+
+#[derive(Debug, Args)]
+pub struct TagArgs {
+    #[command(subcommand)]
+    pub command: TagCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TagCommand {
+    /// Adds a tag to a skill, if it isn't already present.
+    Add {
+        name: String,
+        tag: String,
+        #[arg(long, default_value = "project")]
+        scope: String,
+    },
+    /// Removes a tag from a skill, if present.
+    Remove {
+        name: String,
+        tag: String,
+        #[arg(long, default_value = "project")]
+        scope: String,
+    },
+}
+
+pub async fn run(args: &TagArgs, service: &dyn SkillService) -> Result<String> {
+    let (name, tag, scope, adding) = match &args.command {
+        TagCommand::Add { name, tag, scope } => (name, tag, scope, true),
+        TagCommand::Remove { name, tag, scope } => (name, tag, scope, false),
+    };
+    let scope: SkillScope = scope
+        .parse()
+        .map_err(|e: crate::models::ParseSkillScopeError| CsmError::Validation(e.to_string()))?;
+
+    let all = service.list().await?;
+    let skill = resolve_skill_name(&all, name)?;
+    let resolved_name = skill.name.clone();
+    let mut tags = skill.tags.clone();
+
+    if adding {
+        if !tags.iter().any(|t| t == tag) {
+            tags.push(tag.clone());
+        }
+    } else {
+        tags.retain(|t| t != tag);
+    }
+
+    service.set_tags(&resolved_name, scope, tags).await?;
+    Ok(if adding {
+        format!("added tag '{tag}' to '{resolved_name}'")
+    } else {
+        format!("removed tag '{tag}' from '{resolved_name}'")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Skill, SkillSource, UpdateMode, UpdateTrigger};
+
+    use crate::test_support::StubSkillService;
+
+    fn skill() -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: "tagged".to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::Manual,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: vec!["ops".to_string()],
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn adding_a_tag_appends_it_without_duplicating() {
+        let service = StubSkillService::new(vec![skill()]);
+
+        run(
+            &TagArgs {
+                command: TagCommand::Add {
+                    name: "tagged".to_string(),
+                    tag: "ops".to_string(),
+                    scope: "global".to_string(),
+                },
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+        assert_eq!(service.skills()[0].tags, vec!["ops".to_string()]);
+
+        run(
+            &TagArgs {
+                command: TagCommand::Add {
+                    name: "tagged".to_string(),
+                    tag: "deploy".to_string(),
+                    scope: "global".to_string(),
+                },
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            service.skills()[0].tags,
+            vec!["ops".to_string(), "deploy".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn removing_a_tag_drops_it_from_the_set() {
+        let service = StubSkillService::new(vec![skill()]);
+
+        let output = run(
+            &TagArgs {
+                command: TagCommand::Remove {
+                    name: "tagged".to_string(),
+                    tag: "ops".to_string(),
+                    scope: "global".to_string(),
+                },
+            },
+            &service,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output, "removed tag 'ops' from 'tagged'");
+        assert!(service.skills()[0].tags.is_empty());
+    }
+}
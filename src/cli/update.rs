@@ -0,0 +1,339 @@
+use clap::Args;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::models::Skill;
+use crate::services::{SkillService, SkillUpdateStatus, UpdateAllSummary, UpdateService};
+use crate::utils::cancellation::CancellationToken;
+
+/// Exit code for `update --check --exit-code` when at least one skill has a
+/// pending update. Distinct from the generic `1` used elsewhere so CI can
+/// tell "some skills are outdated" apart from "the command itself failed".
+pub const UPDATES_PENDING_EXIT_CODE: i32 = 3;
+
+#[derive(Debug, Args)]
+pub struct UpdateArgs {
+    /// Only check for available updates; don't fetch or apply them.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Exit with `UPDATES_PENDING_EXIT_CODE` when updates are pending (or
+    /// were applied). Off by default so existing scripts checking for a
+    /// plain success/failure exit code aren't broken.
+    #[arg(long)]
+    pub exit_code: bool,
+
+    /// Check every skill regardless of failure backoff, ignoring any
+    /// pending skip window from recent repeated failures.
+    #[arg(long)]
+    pub force: bool,
+
+    /// Print the apply result as `{"updated": [...], "failed": [...],
+    /// "unchanged": [...]}` instead of a human summary, for the `update_all`
+    /// run over every enabled skill (there is no single-skill update here).
+    /// Ignored under `--check`.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Reject (instead of warn about and still apply) fetched content that
+    /// looks like an HTML error/login page rather than a skill body.
+    #[arg(long)]
+    pub strict: bool,
+}
+
+/// `--json` view of an apply run, derived from `UpdateAllSummary`. Folds
+/// `notified` (Notify-mode skills with a pending update) and
+/// `skipped_backoff` in with `unchanged`, since none of them touched a
+/// skill's content.
+#[derive(Debug, Serialize)]
+struct UpdateJsonReport {
+    updated: Vec<String>,
+    failed: Vec<String>,
+    unchanged: Vec<String>,
+}
+
+impl UpdateJsonReport {
+    fn from_summary(summary: &UpdateAllSummary, enabled: &[Skill]) -> Self {
+        let unchanged = enabled
+            .iter()
+            .map(|s| s.name.clone())
+            .filter(|name| !summary.changed.contains(name) && !summary.failed.contains(name))
+            .collect();
+
+        Self {
+            updated: summary.changed.clone(),
+            failed: summary.failed.clone(),
+            unchanged,
+        }
+    }
+}
+
+/// What `run` prints, plus the raw summary so the caller can still make its
+/// `--exit-code` decision off `summary.changed` regardless of `--json`.
+pub struct UpdateOutcome {
+    pub summary: UpdateAllSummary,
+    /// Set when `--json` was passed to an apply run (never under `--check`).
+    pub json: Option<String>,
+}
+
+/// Checks every enabled skill against its upstream source and, unless
+/// `--check` is set, updates the ones with a new ref. Under `--check`,
+/// `summary.changed` lists skills with a pending update rather than ones
+/// actually applied, and `--json` is ignored.
+pub async fn run(
+    args: &UpdateArgs,
+    updater: &dyn UpdateService,
+    skills: &dyn SkillService,
+    cancel: &CancellationToken,
+) -> Result<UpdateOutcome> {
+    let enabled: Vec<_> = skills
+        .effective_list()
+        .await?
+        .into_iter()
+        .filter(|s| s.enabled)
+        .collect();
+
+    if args.check {
+        let summary = check_all(&enabled, updater, cancel).await?;
+        return Ok(UpdateOutcome { summary, json: None });
+    }
+
+    let summary = updater.update_all(&enabled, cancel, args.force, args.strict).await?;
+    let json = args
+        .json
+        .then(|| serde_json::to_string_pretty(&UpdateJsonReport::from_summary(&summary, &enabled)))
+        .transpose()?;
+
+    Ok(UpdateOutcome { summary, json })
+}
+
+async fn check_all(
+    skills: &[Skill],
+    updater: &dyn UpdateService,
+    cancel: &CancellationToken,
+) -> Result<UpdateAllSummary> {
+    let mut summary = UpdateAllSummary::default();
+
+    for skill in skills {
+        if cancel.is_cancelled() {
+            summary.interrupted = true;
+            break;
+        }
+
+        summary.checked += 1;
+        if let SkillUpdateStatus::UpdateAvailable { .. } = updater.check_skill_update(skill).await? {
+            summary.changed.push(skill.name.clone());
+        }
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    use crate::models::{MergePreviewStats, Skill, SkillScope, SkillSource};
+    use crate::services::SkillUpdateStatus;
+
+    struct FakeSkillService {
+        skills: Vec<Skill>,
+    }
+
+    #[async_trait]
+    impl SkillService for FakeSkillService {
+        async fn add(&self, _n: &str, _s: SkillSource, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_or_overwrite(&self, _n: &str, _s: SkillSource, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_with_content(
+            &self,
+            _n: &str,
+            _s: SkillSource,
+            _sc: SkillScope,
+            _c: String,
+        ) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn list(&self) -> Result<Vec<Skill>> {
+            Ok(self.skills.clone())
+        }
+        async fn update_content(&self, _n: &str, _sc: SkillScope, _c: String) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn merge_preview(&self, _scope: Option<SkillScope>) -> Result<MergePreviewStats> {
+            unimplemented!()
+        }
+        async fn effective_list(&self) -> Result<Vec<Skill>> {
+            Ok(self.skills.clone())
+        }
+        async fn set_note(&self, _n: &str, _sc: SkillScope, _note: Option<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn archive(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn restore(&self, _n: &str, _sc: SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn purge(&self, _n: &str, _sc: SkillScope) -> Result<()> {
+            unimplemented!()
+        }
+        async fn rename(&self, _n: &str, _sc: SkillScope, _new: &str) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_tags(&self, _n: &str, _sc: SkillScope, _tags: Vec<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_priority(&self, _n: &str, _sc: SkillScope, _p: i32) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_enabled(&self, _n: &str, _sc: SkillScope, _e: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn record_update_result(&self, _n: &str, _sc: SkillScope, _failed: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn rollback_content(&self, _n: &str, _sc: SkillScope) -> Result<bool> {
+            unimplemented!()
+        }
+
+    }
+
+    struct FakeUpdater {
+        seen: Mutex<usize>,
+        pending: Vec<&'static str>,
+        /// Names `update_all` should report as `changed`/`failed`, so a test
+        /// can exercise `UpdateJsonReport` without a real `UpdateServiceImpl`.
+        changed: Vec<&'static str>,
+        failed: Vec<&'static str>,
+    }
+
+    impl FakeUpdater {
+        fn new(pending: Vec<&'static str>) -> Self {
+            Self {
+                seen: Mutex::new(0),
+                pending,
+                changed: Vec::new(),
+                failed: Vec::new(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl UpdateService for FakeUpdater {
+        async fn check_skill_update(&self, skill: &Skill) -> Result<SkillUpdateStatus> {
+            if self.pending.contains(&skill.name.as_str()) {
+                Ok(SkillUpdateStatus::UpdateAvailable {
+                    new_ref: "new-ref".to_string(),
+                })
+            } else {
+                Ok(SkillUpdateStatus::UpToDate)
+            }
+        }
+        async fn update_all(
+            &self,
+            skills: &[Skill],
+            _cancel: &CancellationToken,
+            _force: bool,
+            _strict: bool,
+        ) -> Result<crate::services::UpdateAllSummary> {
+            *self.seen.lock().unwrap() = skills.len();
+            Ok(crate::services::UpdateAllSummary {
+                checked: skills.len(),
+                changed: self.changed.iter().map(|s| s.to_string()).collect(),
+                notified: Vec::new(),
+                skipped_backoff: Vec::new(),
+                failed: self.failed.iter().map(|s| s.to_string()).collect(),
+                bytes_changed: 0,
+                interrupted: false,
+            })
+        }
+    }
+
+    fn skill(name: &str, enabled: bool) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled,
+            priority: 0,
+            update_mode: crate::models::UpdateMode::Auto,
+            update_trigger: crate::models::UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn only_enabled_skills_are_passed_to_update_all() {
+        let skills = FakeSkillService {
+            skills: vec![skill("on", true), skill("off", false)],
+        };
+        let updater = FakeUpdater::new(Vec::new());
+
+        let args = UpdateArgs { check: false, exit_code: false, force: false, json: false, strict: false };
+        let outcome = run(&args, &updater, &skills, &CancellationToken::default())
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.summary.checked, 1);
+        assert_eq!(*updater.seen.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn check_mode_reports_pending_updates_without_calling_update_all() {
+        let skills = FakeSkillService {
+            skills: vec![skill("outdated", true), skill("current", true)],
+        };
+        let updater = FakeUpdater::new(vec!["outdated"]);
+
+        let args = UpdateArgs { check: true, exit_code: false, force: false, json: false, strict: false };
+        let outcome = run(&args, &updater, &skills, &CancellationToken::default())
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.summary.checked, 2);
+        assert_eq!(outcome.summary.changed, vec!["outdated".to_string()]);
+        assert!(outcome.json.is_none(), "--check ignores --json");
+        assert_eq!(*updater.seen.lock().unwrap(), 0, "update_all must not run under --check");
+    }
+
+    #[tokio::test]
+    async fn json_output_reports_updated_failed_and_unchanged_skills() {
+        let skills = FakeSkillService {
+            skills: vec![skill("changed-skill", true), skill("failed-skill", true), skill("quiet-skill", true)],
+        };
+        let mut updater = FakeUpdater::new(Vec::new());
+        updater.changed = vec!["changed-skill"];
+        updater.failed = vec!["failed-skill"];
+
+        let args = UpdateArgs { check: false, exit_code: false, force: false, json: true, strict: false };
+        let outcome = run(&args, &updater, &skills, &CancellationToken::default())
+            .await
+            .unwrap();
+
+        let json = outcome.json.expect("--json must produce a rendered report");
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed["updated"], serde_json::json!(["changed-skill"]));
+        assert_eq!(parsed["failed"], serde_json::json!(["failed-skill"]));
+        assert_eq!(parsed["unchanged"], serde_json::json!(["quiet-skill"]));
+    }
+}
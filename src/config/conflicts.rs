@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+/// `[conflicts]` section of `config.toml`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConflictsConfig {
+    /// Extra word pairs, formatted `"word|opposite"`, whose simultaneous
+    /// presence across two skills' content is treated as a likely
+    /// contradiction, in addition to `ConflictServiceImpl`'s built-in pairs.
+    #[serde(default)]
+    pub contradiction_pairs: Vec<String>,
+}
@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::error::{CsmError, Result};
+
+/// PRAGMAs `csm` will apply on every SQLite connection when configured.
+/// Kept to a narrow allow-list: these tune performance without touching
+/// correctness-affecting behavior (e.g. `foreign_keys`, `writable_schema`).
+pub const ALLOWED_PRAGMAS: &[&str] = &["cache_size", "mmap_size", "synchronous", "journal_mode", "busy_timeout"];
+
+/// `[database]` section of `config.toml`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DatabaseConfig {
+    #[serde(default)]
+    pragmas: BTreeMap<String, String>,
+}
+
+impl DatabaseConfig {
+    /// Validates the configured pragmas against `ALLOWED_PRAGMAS` and, for
+    /// each accepted name, against that pragma's own value grammar (see
+    /// `validate_pragma_value`), returning them as `(name, value)` pairs
+    /// ready to splice into a `PRAGMA name = value` statement. Rejecting
+    /// values here, not just names, is what keeps that splice safe: a
+    /// `config.toml` value never reaches `apply_pragmas` unless it's already
+    /// known to be one of a pragma's legal forms.
+    pub fn validated_pragmas(&self) -> Result<Vec<(String, String)>> {
+        self.pragmas
+            .iter()
+            .map(|(name, value)| {
+                if !ALLOWED_PRAGMAS.contains(&name.as_str()) {
+                    return Err(CsmError::Validation(format!(
+                        "unsupported pragma '{name}': allowed pragmas are {}",
+                        ALLOWED_PRAGMAS.join(", ")
+                    )));
+                }
+                validate_pragma_value(name, value)?;
+                Ok((name.clone(), value.clone()))
+            })
+            .collect()
+    }
+}
+
+/// Constrains `value` to the legal grammar for the SQLite pragma `name`, so
+/// that `apply_pragmas`'s `format!("PRAGMA {name} = {value}")` can never
+/// splice in anything beyond a known-safe token. `name` is assumed to
+/// already be a member of `ALLOWED_PRAGMAS`.
+fn validate_pragma_value(name: &str, value: &str) -> Result<()> {
+    let valid = match name {
+        "cache_size" | "mmap_size" | "busy_timeout" => value.parse::<i64>().is_ok(),
+        "synchronous" => matches!(
+            value.to_ascii_uppercase().as_str(),
+            "OFF" | "NORMAL" | "FULL" | "EXTRA" | "0" | "1" | "2" | "3"
+        ),
+        "journal_mode" => matches!(
+            value.to_ascii_uppercase().as_str(),
+            "DELETE" | "TRUNCATE" | "PERSIST" | "MEMORY" | "WAL" | "OFF"
+        ),
+        _ => false,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(CsmError::Validation(format!(
+            "invalid value '{value}' for pragma '{name}'"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_pragmas_on_the_allow_list() {
+        let mut pragmas = BTreeMap::new();
+        pragmas.insert("cache_size".to_string(), "-20000".to_string());
+        let config = DatabaseConfig { pragmas };
+
+        let validated = config.validated_pragmas().unwrap();
+        assert_eq!(validated, vec![("cache_size".to_string(), "-20000".to_string())]);
+    }
+
+    #[test]
+    fn rejects_pragmas_outside_the_allow_list() {
+        let mut pragmas = BTreeMap::new();
+        pragmas.insert("writable_schema".to_string(), "1".to_string());
+        let config = DatabaseConfig { pragmas };
+
+        assert!(config.validated_pragmas().is_err());
+    }
+
+    #[test]
+    fn accepts_journal_mode_and_synchronous_enum_values_case_insensitively() {
+        let mut pragmas = BTreeMap::new();
+        pragmas.insert("journal_mode".to_string(), "wal".to_string());
+        pragmas.insert("synchronous".to_string(), "NORMAL".to_string());
+        let config = DatabaseConfig { pragmas };
+
+        assert_eq!(config.validated_pragmas().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn rejects_a_non_integer_value_for_an_integer_pragma() {
+        let mut pragmas = BTreeMap::new();
+        pragmas.insert("cache_size".to_string(), "-20000; DROP TABLE skills".to_string());
+        let config = DatabaseConfig { pragmas };
+
+        assert!(config.validated_pragmas().is_err());
+    }
+
+    #[test]
+    fn rejects_a_value_outside_journal_modes_enum() {
+        let mut pragmas = BTreeMap::new();
+        pragmas.insert("journal_mode".to_string(), "wal; DROP TABLE skills".to_string());
+        let config = DatabaseConfig { pragmas };
+
+        assert!(config.validated_pragmas().is_err());
+    }
+}
@@ -0,0 +1,42 @@
+use serde::Deserialize;
+
+fn default_enable_on_add() -> bool {
+    true
+}
+
+fn default_compress_content_threshold_bytes() -> usize {
+    4096
+}
+
+/// `[general]` section of `config.toml`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GeneralConfig {
+    /// Whether `csm add` enables a newly added skill immediately, making it
+    /// take effect in the next merge. Set to `false` to add skills disabled
+    /// by default, pending review; overridden per invocation by `add
+    /// --disabled`.
+    #[serde(default = "default_enable_on_add")]
+    pub enable_on_add: bool,
+
+    /// Whether stored skill content is gzip-compressed on disk once it
+    /// reaches `compress_content_threshold_bytes`. See
+    /// `utils::compression`.
+    #[serde(default)]
+    pub compress_content: bool,
+
+    /// Minimum uncompressed content size, in bytes, before
+    /// `compress_content` kicks in. Small skills aren't worth the gzip
+    /// framing overhead.
+    #[serde(default = "default_compress_content_threshold_bytes")]
+    pub compress_content_threshold_bytes: usize,
+}
+
+impl Default for GeneralConfig {
+    fn default() -> Self {
+        Self {
+            enable_on_add: default_enable_on_add(),
+            compress_content: false,
+            compress_content_threshold_bytes: default_compress_content_threshold_bytes(),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use serde::Deserialize;
+
+use crate::error::{CsmError, Result};
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+/// `[github]` section of `config.toml`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubConfig {
+    /// Base API URL, for GitHub Enterprise instances (e.g.
+    /// `https://github.mycorp.com/api/v3`). Defaults to `api.github.com`.
+    pub api_url: Option<String>,
+
+    /// How many times `GitHubClientImpl` retries a retryable error
+    /// (rate limits, network errors) before giving up.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// Plaintext fallback for `GITHUB_TOKEN`, checked before the system
+    /// keyring and `gh auth token`. Prefer `csm config set github.token`,
+    /// which stores into the keyring instead when one is available.
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+impl Default for GithubConfig {
+    fn default() -> Self {
+        Self {
+            api_url: None,
+            max_retries: default_max_retries(),
+            token: None,
+        }
+    }
+}
+
+impl GithubConfig {
+    /// Rejects a malformed `api_url` at load time rather than failing on
+    /// the first request that uses it.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(url) = &self.api_url {
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                return Err(CsmError::Validation(format!(
+                    "invalid github.api_url '{url}': must be an http(s) URL"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_a_well_formed_enterprise_url() {
+        let config = GithubConfig {
+            api_url: Some("https://github.mycorp.com/api/v3".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_a_url_missing_a_scheme() {
+        let config = GithubConfig {
+            api_url: Some("github.mycorp.com/api/v3".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,66 @@
+use serde::Deserialize;
+
+use crate::models::SameNameStrategy;
+
+fn default_true() -> bool {
+    true
+}
+
+/// `[merge]` section of `config.toml`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MergeConfig {
+    /// When true, a `##`+ section that's byte-identical (after trimming) to
+    /// one already emitted by an earlier skill is skipped rather than
+    /// repeated, with a note pointing at the skill that owns it.
+    #[serde(default)]
+    pub dedupe_sections: bool,
+
+    /// Text written at the top of the merged `CLAUDE.md`, before any skill
+    /// sections. Typically set per-project (via `.csm/config.toml`) rather
+    /// than globally, e.g. to identify which repo the file was generated for.
+    #[serde(default)]
+    pub header_text: Option<String>,
+
+    /// How `effective_list` reconciles a project skill with a global skill
+    /// of the same name. Defaults to `override`.
+    #[serde(default)]
+    pub same_name_strategy: SameNameStrategy,
+
+    /// Format string rendered above each enabled skill's content in the
+    /// merged `CLAUDE.md`. Supports `{name}`, `{source}`, and `{priority}`
+    /// placeholders. Defaults to `## {name}` when unset.
+    #[serde(default)]
+    pub skill_header: Option<String>,
+
+    /// When true, a non-heading, non-blank line already emitted by a
+    /// higher-priority skill (e.g. a shared "Be concise" bullet) is dropped
+    /// instead of repeated.
+    #[serde(default)]
+    pub dedupe_lines: bool,
+
+    /// When true, a table of contents linking to each enabled skill's
+    /// section heading is prepended after `header_text`.
+    #[serde(default)]
+    pub toc: bool,
+
+    /// When true (the default), `effective_list` folds enabled global
+    /// skills into a project's effective set (reconciled with same-named
+    /// project skills via `same_name_strategy`) instead of considering only
+    /// the project's own skills.
+    #[serde(default = "default_true")]
+    pub inherit_global: bool,
+}
+
+impl Default for MergeConfig {
+    fn default() -> Self {
+        Self {
+            dedupe_sections: false,
+            header_text: None,
+            same_name_strategy: SameNameStrategy::default(),
+            skill_header: None,
+            dedupe_lines: false,
+            toc: false,
+            inherit_global: true,
+        }
+    }
+}
@@ -0,0 +1,179 @@
+pub mod conflicts;
+pub mod database;
+pub mod general;
+pub mod github;
+pub mod merge;
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+pub use conflicts::ConflictsConfig;
+pub use database::DatabaseConfig;
+pub use general::GeneralConfig;
+pub use github::GithubConfig;
+pub use merge::MergeConfig;
+
+use crate::error::Result;
+
+/// Resolved `csm` configuration: where skills and their SQLite index live.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub csm_home: PathBuf,
+    pub database: DatabaseConfig,
+    pub github: GithubConfig,
+    pub merge: MergeConfig,
+    pub general: GeneralConfig,
+    pub conflicts: ConflictsConfig,
+}
+
+/// Shape of `config.toml`. Every section is optional so a config file only
+/// needs to mention the settings it overrides.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    database: DatabaseConfig,
+    #[serde(default)]
+    github: GithubConfig,
+    #[serde(default)]
+    merge: MergeConfig,
+    #[serde(default)]
+    general: GeneralConfig,
+    #[serde(default)]
+    conflicts: ConflictsConfig,
+}
+
+impl Config {
+    /// Resolves `csm_home` using the default precedence (see `detect_csm_home`).
+    pub fn load() -> Result<Self> {
+        Self::load_with_config_override(None)
+    }
+
+    /// Resolves `csm_home`, letting an explicit `--config` path win over
+    /// `CSM_HOME`/`~/.csm`, then reads `config.toml` from it if present.
+    ///
+    /// If the current directory has a `.csm/config.toml`, its settings are
+    /// layered on top of the global config (project > global precedence),
+    /// so a project can override things like `[merge]` without touching the
+    /// user's global settings.
+    pub fn load_with_config_override(explicit_config: Option<&Path>) -> Result<Self> {
+        let csm_home = detect_csm_home(explicit_config);
+        let mut merged = load_config_toml(&csm_home.join("config.toml"))?;
+
+        if let Ok(cwd) = std::env::current_dir() {
+            let project_config_path = cwd.join(".csm").join("config.toml");
+            let project = load_config_toml(&project_config_path)?;
+            merge_toml_tables(&mut merged, project);
+        }
+
+        let file: ConfigFile = merged.try_into().map_err(|e| {
+            crate::error::CsmError::Other(format!("invalid config: {e}"))
+        })?;
+        file.github.validate()?;
+
+        Ok(Self {
+            csm_home,
+            database: file.database,
+            github: file.github,
+            merge: file.merge,
+            general: file.general,
+            conflicts: file.conflicts,
+        })
+    }
+}
+
+fn load_config_toml(path: &Path) -> Result<toml::Value> {
+    if !path.exists() {
+        return Ok(toml::Value::Table(toml::value::Table::new()));
+    }
+    let contents = std::fs::read_to_string(path)?;
+    toml::from_str(&contents).map_err(|e| crate::error::CsmError::Other(format!("invalid config at {}: {e}", path.display())))
+}
+
+/// Recursively overlays `overlay` onto `base` in place, with `overlay`'s
+/// values winning. Nested tables are merged key-by-key rather than replaced
+/// wholesale, so a project config only needs to mention the keys it changes.
+fn merge_toml_tables(base: &mut toml::Value, overlay: toml::Value) {
+    match (base, overlay) {
+        (toml::Value::Table(base_table), toml::Value::Table(overlay_table)) => {
+            for (key, overlay_value) in overlay_table {
+                match base_table.get_mut(&key) {
+                    Some(base_value) => merge_toml_tables(base_value, overlay_value),
+                    None => {
+                        base_table.insert(key, overlay_value);
+                    }
+                }
+            }
+        }
+        (base_value, overlay_value) => {
+            *base_value = overlay_value;
+        }
+    }
+}
+
+/// Resolution order: `--config`'s directory, then `$CSM_HOME`, then `~/.csm`.
+///
+/// `--config` must win outright, since it's the one signal the user gave
+/// explicitly on this invocation.
+pub fn detect_csm_home(explicit_config: Option<&Path>) -> PathBuf {
+    if let Some(config_path) = explicit_config {
+        if let Some(dir) = config_path.parent() {
+            return dir.to_path_buf();
+        }
+        return config_path.to_path_buf();
+    }
+
+    if let Ok(env_home) = std::env::var("CSM_HOME") {
+        return PathBuf::from(env_home);
+    }
+
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".csm")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_config_directory_wins_over_env_var() {
+        std::env::set_var("CSM_HOME", "/env/csm");
+
+        let resolved = detect_csm_home(Some(Path::new("/explicit/config.toml")));
+
+        assert_eq!(resolved, PathBuf::from("/explicit"));
+        std::env::remove_var("CSM_HOME");
+    }
+
+    #[test]
+    fn a_project_csm_config_toml_overrides_merge_header_text_only_within_that_project() {
+        let root = std::env::temp_dir().join("csm_test_project_config_override");
+        let global_dir = root.join("global");
+        let project_dir = root.join("project");
+        std::fs::create_dir_all(&global_dir).unwrap();
+        std::fs::create_dir_all(project_dir.join(".csm")).unwrap();
+
+        let global_config_path = global_dir.join("config.toml");
+        std::fs::write(&global_config_path, "[merge]\nheader_text = \"GLOBAL HEADER\"\n").unwrap();
+        std::fs::write(
+            project_dir.join(".csm").join("config.toml"),
+            "[merge]\nheader_text = \"PROJECT HEADER\"\n",
+        )
+        .unwrap();
+
+        let original_cwd = std::env::current_dir().unwrap();
+
+        std::env::set_current_dir(&project_dir).unwrap();
+        let project_config = Config::load_with_config_override(Some(&global_config_path)).unwrap();
+
+        std::env::set_current_dir(&global_dir).unwrap();
+        let global_config = Config::load_with_config_override(Some(&global_config_path)).unwrap();
+
+        std::env::set_current_dir(&original_cwd).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(project_config.merge.header_text.as_deref(), Some("PROJECT HEADER"));
+        assert_eq!(global_config.merge.header_text.as_deref(), Some("GLOBAL HEADER"));
+    }
+}
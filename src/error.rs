@@ -0,0 +1,55 @@
+use thiserror::Error;
+
+/// Top-level error type for all `csm` operations.
+#[derive(Debug, Error)]
+pub enum CsmError {
+    #[error("skill not found: {0}")]
+    NotFound(String),
+
+    #[error("skill already exists: {0}")]
+    AlreadyExists(String),
+
+    #[error("invalid skill source: {0}")]
+    InvalidSource(String),
+
+    #[error("source not accessible: {0}")]
+    SourceNotAccessible(String),
+
+    #[error("invalid content: {0}")]
+    InvalidContent(String),
+
+    #[error("validation failed: {0}")]
+    Validation(String),
+
+    #[error("invalid skill name: {0}")]
+    InvalidName(String),
+
+    #[error("rate limited, retry after {retry_after_secs}s")]
+    RateLimited { retry_after_secs: u64 },
+
+    #[error("network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("{0}")]
+    Other(String),
+}
+
+impl CsmError {
+    /// Whether retrying the same operation might succeed: rate limits are
+    /// expected to clear, and network errors (including timeouts) are often
+    /// transient. Everything else (validation, not-found, ...) is not.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, CsmError::RateLimited { .. } | CsmError::Network(_))
+    }
+}
+
+pub type Result<T> = std::result::Result<T, CsmError>;
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+
+use crate::error::{CsmError, Result};
+use crate::utils::hash::hash_content;
+
+/// Fetches file content from arbitrary `git`-reachable repos (SSH remotes,
+/// self-hosted instances with no REST API) for `SkillSource::Git` sources,
+/// by shelling out to the `git` binary rather than an HTTP API.
+#[async_trait]
+pub trait GitClient: Send + Sync {
+    /// Clones `url` at `ref_spec` (depth 1) into the cache dir, reads
+    /// `path` from the checkout, and returns its content alongside the
+    /// commit SHA `ref_spec` resolved to, for `update`'s drift check.
+    async fn fetch_file(&self, url: &str, path: &str, ref_spec: &str) -> Result<(String, String)>;
+}
+
+/// Default `GitClient`, shelling out to the system `git` binary.
+pub struct GitClientImpl {
+    cache_dir: std::path::PathBuf,
+}
+
+impl GitClientImpl {
+    pub fn new(cache_dir: std::path::PathBuf) -> Self {
+        Self { cache_dir }
+    }
+
+    /// A stable, collision-resistant checkout directory per clone URL.
+    /// `fetch_file` removes it and clones afresh on every call, so the cache
+    /// holds at most one checkout per repo.
+    fn clone_dir(&self, url: &str) -> std::path::PathBuf {
+        self.cache_dir.join(hash_content(url))
+    }
+
+    fn run_git(args: &[&str], cwd: Option<&std::path::Path>) -> Result<std::process::Output> {
+        let mut command = std::process::Command::new("git");
+        command.args(args);
+        if let Some(dir) = cwd {
+            command.current_dir(dir);
+        }
+        command.output().map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                CsmError::SourceNotAccessible("git is not installed".to_string())
+            } else {
+                CsmError::SourceNotAccessible(format!("failed to run git: {e}"))
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl GitClient for GitClientImpl {
+    async fn fetch_file(&self, url: &str, path: &str, ref_spec: &str) -> Result<(String, String)> {
+        let dir = self.clone_dir(url);
+        std::fs::create_dir_all(&self.cache_dir)?;
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let clone = Self::run_git(
+            &[
+                "clone",
+                "--depth",
+                "1",
+                "--branch",
+                ref_spec,
+                url,
+                dir.to_str().unwrap_or_default(),
+            ],
+            None,
+        )?;
+        if !clone.status.success() {
+            return Err(CsmError::SourceNotAccessible(format!(
+                "git clone of {url}@{ref_spec} failed: {}",
+                String::from_utf8_lossy(&clone.stderr)
+            )));
+        }
+
+        let rev_parse = Self::run_git(&["rev-parse", "HEAD"], Some(&dir))?;
+        if !rev_parse.status.success() {
+            return Err(CsmError::SourceNotAccessible(format!(
+                "failed to resolve HEAD for {url}@{ref_spec}"
+            )));
+        }
+        let commit_sha = String::from_utf8_lossy(&rev_parse.stdout).trim().to_string();
+
+        let content = std::fs::read_to_string(dir.join(path)).map_err(|e| {
+            CsmError::SourceNotAccessible(format!("{path} not found in {url}@{ref_spec}: {e}"))
+        })?;
+
+        Ok((content, commit_sha))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_dir_is_stable_and_scoped_per_url() {
+        let client = GitClientImpl::new(std::env::temp_dir().join("csm_test_git_cache"));
+
+        let a = client.clone_dir("git@github.com:acme/skills.git");
+        let b = client.clone_dir("git@github.com:acme/skills.git");
+        let c = client.clone_dir("git@github.com:acme/other.git");
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[tokio::test]
+    async fn missing_git_binary_surfaces_as_source_not_accessible() {
+        let original_path = std::env::var("PATH").unwrap_or_default();
+        std::env::set_var("PATH", "/nonexistent");
+
+        let client = GitClientImpl::new(std::env::temp_dir().join("csm_test_git_missing_binary"));
+        let result = client
+            .fetch_file("git@github.com:acme/skills.git", "SKILL.md", "main")
+            .await;
+
+        std::env::set_var("PATH", original_path);
+
+        assert!(matches!(result, Err(CsmError::SourceNotAccessible(_))));
+    }
+}
@@ -0,0 +1,503 @@
+use async_trait::async_trait;
+use base64::Engine;
+
+use crate::error::{CsmError, Result};
+use crate::utils::http_cache::HttpCache;
+use crate::utils::retry::{with_backoff, RetryPolicy};
+
+/// Resolves a GitHub token to authenticate requests with, trying each
+/// source in order and stopping at the first hit: `GITHUB_TOKEN`, the
+/// `github.token` config key, the system keyring (service `csm`), and
+/// finally `gh auth token`. Returns `None` to continue unauthenticated,
+/// same as today, if none of them produce a token.
+pub fn resolve_token(config_token: Option<&str>) -> Option<String> {
+    if let Ok(env_token) = std::env::var("GITHUB_TOKEN") {
+        if !env_token.is_empty() {
+            return Some(env_token);
+        }
+    }
+
+    if let Some(token) = config_token {
+        if !token.is_empty() {
+            return Some(token.to_string());
+        }
+    }
+
+    if let Ok(entry) = keyring::Entry::new("csm", "github.token") {
+        if let Ok(token) = entry.get_password() {
+            return Some(token);
+        }
+    }
+
+    gh_auth_token()
+}
+
+/// Shells out to `gh auth token`, the last-resort source. Absent CLI,
+/// unauthenticated `gh`, or a non-UTF8 token are all treated as "no token"
+/// rather than hard errors, since this is only ever a convenience fallback.
+fn gh_auth_token() -> Option<String> {
+    let output = std::process::Command::new("gh")
+        .args(["auth", "token"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let token = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    if token.is_empty() {
+        None
+    } else {
+        Some(token)
+    }
+}
+
+/// Whether a `raw` media-type contents response is standing in for a file
+/// past GitHub's inline-content size ceiling: GitHub streams an empty body
+/// rather than an error in that case, which would otherwise be silently
+/// mistaken for a genuinely empty file.
+fn is_large_file_placeholder(body: &str) -> bool {
+    body.is_empty()
+}
+
+/// Pulls the base64 `content` field out of a Git Blobs API response,
+/// surfacing a descriptive error instead of silently treating a malformed
+/// or unexpected response shape as empty content.
+fn blob_content<'a>(
+    blob: &'a serde_json::Value,
+    owner: &str,
+    repo: &str,
+    path: &str,
+    r#ref: &str,
+    sha: &str,
+) -> Result<&'a str> {
+    blob["content"].as_str().ok_or_else(|| {
+        CsmError::Other(format!(
+            "GitHub blob response missing 'content' for {owner}/{repo}/{path}@{ref} (sha {sha})"
+        ))
+    })
+}
+
+/// Decodes a Git Blobs API `content` field: base64 with embedded newlines
+/// GitHub inserts every 60 characters, which the decoder otherwise rejects.
+fn decode_blob_content(encoded: &str) -> Result<String> {
+    let stripped: String = encoded.chars().filter(|c| !c.is_whitespace()).collect();
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(stripped)
+        .map_err(|e| CsmError::Other(format!("failed to decode blob content: {e}")))?;
+    String::from_utf8(bytes)
+        .map_err(|e| CsmError::Other(format!("blob content was not valid UTF-8: {e}")))
+}
+
+/// Fetches file content and revision metadata from GitHub for `SkillSource::GitHub` sources.
+#[async_trait]
+pub trait GitHubClient: Send + Sync {
+    async fn fetch_file(&self, owner: &str, repo: &str, path: &str, r#ref: &str) -> Result<String>;
+
+    /// SHA of the latest commit on `r#ref`.
+    async fn latest_commit_sha(&self, owner: &str, repo: &str, r#ref: &str) -> Result<String>;
+
+    /// Name of the most recently created tag, if any.
+    async fn latest_tag(&self, owner: &str, repo: &str) -> Result<Option<String>>;
+
+    /// Commit messages reachable from `to` but not from `from`, newest first.
+    async fn commits_between(
+        &self,
+        owner: &str,
+        repo: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<String>>;
+
+    /// Tag names followed by branch names, each in the order the API lists
+    /// them, paginating through the full result set. The list is not sorted
+    /// by recency. Callers that only want a handful (e.g. `csm add
+    /// --list-versions`) should truncate the returned `Vec` themselves.
+    async fn list_refs(&self, owner: &str, repo: &str) -> Result<Vec<String>>;
+
+    /// Every `.md` file at or beneath `path` at `ref_spec`, as
+    /// `(path, content)` pairs, for `csm add ... --recursive`.
+    async fn fetch_directory(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_spec: &str,
+    ) -> Result<Vec<(String, String)>>;
+}
+
+/// Result of a single `fetch_file` HTTP attempt, before the cache is consulted.
+enum FetchOutcome {
+    NotModified,
+    Fresh { body: String, etag: Option<String> },
+}
+
+/// Default `GitHubClient` against the REST API, defaulting to
+/// `api.github.com` but honoring a GitHub Enterprise base URL override.
+pub struct GitHubClientImpl {
+    http: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+    cache: Option<HttpCache>,
+    retry_policy: RetryPolicy,
+}
+
+impl GitHubClientImpl {
+    /// Targets the public `api.github.com`.
+    pub fn new() -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            base_url: "https://api.github.com".to_string(),
+            token: std::env::var("GITHUB_TOKEN").ok(),
+            cache: None,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Targets a GitHub Enterprise instance, e.g. `https://github.mycorp.com/api/v3`.
+    pub fn with_base_url(base_url: String) -> Self {
+        Self {
+            base_url,
+            ..Self::new()
+        }
+    }
+
+    /// Caches `fetch_file` responses under `cache_dir`, sending `If-None-Match`
+    /// on subsequent calls so an unchanged file short-circuits on `304`.
+    pub fn with_cache_dir(mut self, cache_dir: std::path::PathBuf) -> Self {
+        self.cache = Some(HttpCache::new(cache_dir));
+        self
+    }
+
+    /// Overrides how many times a retryable request (rate limits, network
+    /// errors) is retried before giving up. Defaults to 3.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.retry_policy.max_retries = max_retries;
+        self
+    }
+
+    /// Overrides the bearer token used to authenticate requests, e.g. with
+    /// [`resolve_token`]'s result. `None` continues unauthenticated.
+    pub fn with_token(mut self, token: Option<String>) -> Self {
+        self.token = token;
+        self
+    }
+
+    fn authed(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.token {
+            Some(token) => builder.header("Authorization", format!("Bearer {token}")),
+            None => builder,
+        }
+    }
+}
+
+/// If GitHub returned a rate-limit response (`x-ratelimit-remaining: 0`),
+/// the number of seconds until `x-ratelimit-reset`, else `None`.
+fn rate_limit_wait(response: &reqwest::Response) -> Option<u64> {
+    let remaining = response.headers().get("x-ratelimit-remaining")?.to_str().ok()?;
+    if remaining != "0" {
+        return None;
+    }
+    let reset_epoch: u64 = response.headers().get("x-ratelimit-reset")?.to_str().ok()?.parse().ok()?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?
+        .as_secs();
+    Some(reset_epoch.saturating_sub(now))
+}
+
+impl Default for GitHubClientImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl GitHubClient for GitHubClientImpl {
+    async fn fetch_file(&self, owner: &str, repo: &str, path: &str, r#ref: &str) -> Result<String> {
+        let cache_key = format!("{owner}/{repo}/{path}@{ref}");
+        let cached_etag = self.cache.as_ref().and_then(|c| c.etag(&cache_key));
+        let url = format!("{}/repos/{owner}/{repo}/contents/{path}?ref={ref}", self.base_url);
+
+        let outcome = with_backoff(self.retry_policy, || async {
+            let mut builder = self
+                .authed(self.http.get(&url))
+                .header("Accept", "application/vnd.github.raw");
+            if let Some(etag) = &cached_etag {
+                builder = builder.header("If-None-Match", etag);
+            }
+            let response = builder.send().await?;
+
+            if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+                return Ok(FetchOutcome::NotModified);
+            }
+            if let Some(wait) = rate_limit_wait(&response) {
+                return Err(CsmError::RateLimited { retry_after_secs: wait });
+            }
+            if !response.status().is_success() {
+                return Err(CsmError::Other(format!(
+                    "GitHub fetch_file failed with status {}: {owner}/{repo}/{path}@{ref}",
+                    response.status()
+                )));
+            }
+
+            let etag = response
+                .headers()
+                .get(reqwest::header::ETAG)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let body = response.text().await?;
+            Ok(FetchOutcome::Fresh { body, etag })
+        })
+        .await?;
+
+        match outcome {
+            FetchOutcome::NotModified => self
+                .cache
+                .as_ref()
+                .and_then(|c| c.body(&cache_key))
+                .ok_or_else(|| {
+                    CsmError::Other(format!(
+                        "GitHub returned 304 for {cache_key} but no cached body was found"
+                    ))
+                }),
+            FetchOutcome::Fresh { body, etag } => {
+                // The raw media type streams an empty body instead of erroring
+                // once a file crosses GitHub's size ceiling; the Git Blobs API
+                // has no such limit, so fall back to it keyed on the file's
+                // sha rather than the contents API's `download_url` (which
+                // would mean a third, unauthenticated request outside the
+                // retry/rate-limit handling above).
+                let body = if is_large_file_placeholder(&body) {
+                    self.fetch_large_file(owner, repo, path, r#ref).await?
+                } else {
+                    body
+                };
+                if let Some(cache) = &self.cache {
+                    cache.store(&cache_key, etag.as_deref(), &body);
+                }
+                Ok(body)
+            }
+        }
+    }
+
+    async fn latest_commit_sha(&self, owner: &str, repo: &str, r#ref: &str) -> Result<String> {
+        let url = format!("{}/repos/{owner}/{repo}/commits/{ref}", self.base_url);
+        let response = self.authed(self.http.get(&url)).send().await?;
+        let body: serde_json::Value = response.json().await?;
+        body["sha"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| CsmError::Other(format!("GitHub commit response missing 'sha' for {owner}/{repo}@{ref}")))
+    }
+
+    async fn latest_tag(&self, owner: &str, repo: &str) -> Result<Option<String>> {
+        let url = format!("{}/repos/{owner}/{repo}/tags?per_page=1", self.base_url);
+        let response = self.authed(self.http.get(&url)).send().await?;
+        let tags: Vec<serde_json::Value> = response.json().await?;
+        Ok(tags.first().and_then(|t| t["name"].as_str()).map(str::to_string))
+    }
+
+    async fn commits_between(
+        &self,
+        owner: &str,
+        repo: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<String>> {
+        let url = format!("{}/repos/{owner}/{repo}/compare/{from}...{to}", self.base_url);
+        let response = self.authed(self.http.get(&url)).send().await?;
+        let body: serde_json::Value = response.json().await?;
+        Ok(body["commits"]
+            .as_array()
+            .map(|commits| {
+                commits
+                    .iter()
+                    .filter_map(|c| c["commit"]["message"].as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn list_refs(&self, owner: &str, repo: &str) -> Result<Vec<String>> {
+        let mut refs = self.paginated_names(owner, repo, "tags").await?;
+        refs.extend(self.paginated_names(owner, repo, "branches").await?);
+        Ok(refs)
+    }
+
+    async fn fetch_directory(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_spec: &str,
+    ) -> Result<Vec<(String, String)>> {
+        let url = format!(
+            "{}/repos/{owner}/{repo}/git/trees/{ref_spec}?recursive=1",
+            self.base_url
+        );
+        let response = self.authed(self.http.get(&url)).send().await?;
+        let body: serde_json::Value = response.json().await?;
+
+        let prefix = format!("{}/", path.trim_end_matches('/'));
+        let paths: Vec<String> = body["tree"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|entry| entry["type"] == "blob")
+            .filter_map(|entry| entry["path"].as_str().map(str::to_string))
+            .filter(|entry_path| {
+                entry_path.ends_with(".md")
+                    && (entry_path == path || entry_path.starts_with(&prefix))
+            })
+            .collect();
+
+        let mut files = Vec::with_capacity(paths.len());
+        for entry_path in paths {
+            let content = self.fetch_file(owner, repo, &entry_path, ref_spec).await?;
+            files.push((entry_path, content));
+        }
+        Ok(files)
+    }
+}
+
+impl GitHubClientImpl {
+    /// Looks up the blob `sha` for `path@ref` via the JSON contents API, then
+    /// fetches and decodes it through `/git/blobs/{sha}`, which GitHub
+    /// supports for files well past the raw endpoint's practical ceiling.
+    async fn fetch_large_file(&self, owner: &str, repo: &str, path: &str, r#ref: &str) -> Result<String> {
+        let meta_url = format!("{}/repos/{owner}/{repo}/contents/{path}?ref={ref}", self.base_url);
+        let meta: serde_json::Value = self.authed(self.http.get(&meta_url)).send().await?.json().await?;
+        let sha = meta["sha"].as_str().ok_or_else(|| {
+            CsmError::Other(format!(
+                "GitHub contents response missing 'sha' for {owner}/{repo}/{path}@{ref}"
+            ))
+        })?;
+
+        let blob_url = format!("{}/repos/{owner}/{repo}/git/blobs/{sha}", self.base_url);
+        let blob: serde_json::Value = self.authed(self.http.get(&blob_url)).send().await?.json().await?;
+        let encoded = blob_content(&blob, owner, repo, path, r#ref, sha)?;
+        decode_blob_content(encoded)
+    }
+
+    /// Walks every page of a `tags`/`branches` listing, following GitHub's
+    /// `per_page`/`page` convention and stopping once a page comes back short.
+    async fn paginated_names(&self, owner: &str, repo: &str, kind: &str) -> Result<Vec<String>> {
+        const PER_PAGE: usize = 100;
+        let mut names = Vec::new();
+        let mut page = 1;
+        loop {
+            let url = format!(
+                "{}/repos/{owner}/{repo}/{kind}?per_page={PER_PAGE}&page={page}",
+                self.base_url
+            );
+            let response = self.authed(self.http.get(&url)).send().await?;
+            let entries: Vec<serde_json::Value> = response.json().await?;
+            let count = entries.len();
+            names.extend(entries.iter().filter_map(|e| e["name"].as_str().map(str::to_string)));
+            if count < PER_PAGE {
+                break;
+            }
+            page += 1;
+        }
+        Ok(names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_base_url_targets_the_configured_enterprise_host() {
+        let client = GitHubClientImpl::with_base_url("https://github.mycorp.com/api/v3".to_string());
+        assert_eq!(client.base_url, "https://github.mycorp.com/api/v3");
+    }
+
+    #[test]
+    fn new_defaults_to_the_public_api_host() {
+        let client = GitHubClientImpl::new();
+        assert_eq!(client.base_url, "https://api.github.com");
+    }
+
+    #[test]
+    fn with_cache_dir_carries_an_etag_forward_for_the_next_conditional_request() {
+        let dir = std::env::temp_dir().join("csm_test_github_client_cache");
+        let _ = std::fs::remove_dir_all(&dir);
+        let client = GitHubClientImpl::new().with_cache_dir(dir.clone());
+
+        let cache_key = "acme/skills/SKILL.md@main";
+        client
+            .cache
+            .as_ref()
+            .unwrap()
+            .store(cache_key, Some("\"etag-1\""), "cached content");
+
+        assert_eq!(
+            client.cache.as_ref().unwrap().etag(cache_key).as_deref(),
+            Some("\"etag-1\"")
+        );
+        assert_eq!(
+            client.cache.as_ref().unwrap().body(cache_key).as_deref(),
+            Some("cached content")
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn with_max_retries_overrides_the_default_retry_policy() {
+        let client = GitHubClientImpl::new().with_max_retries(7);
+        assert_eq!(client.retry_policy.max_retries, 7);
+    }
+
+    #[test]
+    fn decode_blob_content_strips_githubs_embedded_newlines() {
+        // "large file content\n" base64-encoded, then wrapped as GitHub does.
+        let encoded = "bGFyZ2Ug\nZmlsZSBj\nb250ZW50\nCg==\n";
+        assert_eq!(
+            decode_blob_content(encoded).unwrap(),
+            "large file content\n"
+        );
+    }
+
+    #[test]
+    fn decode_blob_content_rejects_invalid_base64() {
+        assert!(decode_blob_content("not-base64!!!").is_err());
+    }
+
+    #[test]
+    fn is_large_file_placeholder_flags_only_an_empty_body() {
+        assert!(is_large_file_placeholder(""));
+        assert!(!is_large_file_placeholder("fn main() {}"));
+    }
+
+    #[test]
+    fn blob_content_reports_the_coordinates_of_a_response_missing_the_content_field() {
+        let blob = serde_json::json!({ "sha": "deadbeef", "size": 42 });
+
+        let err = blob_content(&blob, "acme", "skills", "SKILL.md", "main", "deadbeef")
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("acme/skills/SKILL.md@main"));
+        assert!(err.contains("deadbeef"));
+    }
+
+    #[test]
+    fn resolve_token_prefers_the_env_var_over_the_config_value() {
+        std::env::set_var("GITHUB_TOKEN", "env-token");
+
+        let resolved = resolve_token(Some("config-token"));
+
+        std::env::remove_var("GITHUB_TOKEN");
+        assert_eq!(resolved.as_deref(), Some("env-token"));
+    }
+
+    #[test]
+    fn resolve_token_falls_back_to_the_config_value_when_unset() {
+        std::env::remove_var("GITHUB_TOKEN");
+
+        let resolved = resolve_token(Some("config-token"));
+
+        assert_eq!(resolved.as_deref(), Some("config-token"));
+    }
+}
@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+
+use crate::error::{CsmError, Result};
+
+/// Fetches file content and revision metadata from GitLab for
+/// `SkillSource::GitLab` sources. Mirrors `GitHubClient`'s shape so the
+/// two forges can share callers (see `services::update_service`).
+#[async_trait]
+pub trait GitLabClient: Send + Sync {
+    async fn fetch_file(&self, owner: &str, repo: &str, path: &str, r#ref: &str) -> Result<String>;
+
+    /// SHA of the latest commit on `r#ref`.
+    async fn latest_commit_sha(&self, owner: &str, repo: &str, r#ref: &str) -> Result<String>;
+
+    /// Name of the most recently created tag, if any.
+    async fn latest_tag(&self, owner: &str, repo: &str) -> Result<Option<String>>;
+
+    /// Commit messages reachable from `to` but not from `from`, newest first.
+    async fn commits_between(
+        &self,
+        owner: &str,
+        repo: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<String>>;
+}
+
+/// Default `GitLabClient` against the GitLab REST API (v4), defaulting to
+/// `gitlab.com` but honoring a self-hosted `base_url` override.
+pub struct GitLabClientImpl {
+    http: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+}
+
+impl GitLabClientImpl {
+    pub fn new(base_url: Option<String>, token: Option<String>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            base_url: base_url.unwrap_or_else(|| "https://gitlab.com".to_string()),
+            token,
+        }
+    }
+
+    /// Reads `GITLAB_BASE_URL`/`GITLAB_TOKEN` from the environment, falling
+    /// back to `gitlab.com` and no auth (public repos only).
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var("GITLAB_BASE_URL").ok(),
+            std::env::var("GITLAB_TOKEN").ok(),
+        )
+    }
+
+    /// GitLab's API addresses a project by its full path with `/` percent-encoded.
+    fn project_path(owner: &str, repo: &str) -> String {
+        format!("{owner}%2F{repo}")
+    }
+
+    fn authed(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.token {
+            Some(token) => builder.header("PRIVATE-TOKEN", token),
+            None => builder,
+        }
+    }
+}
+
+#[async_trait]
+impl GitLabClient for GitLabClientImpl {
+    async fn fetch_file(&self, owner: &str, repo: &str, path: &str, r#ref: &str) -> Result<String> {
+        let project = Self::project_path(owner, repo);
+        let file = path.replace('/', "%2F");
+        let url = format!(
+            "{}/api/v4/projects/{project}/repository/files/{file}/raw?ref={ref}",
+            self.base_url
+        );
+        let response = self.authed(self.http.get(&url)).send().await?;
+        if !response.status().is_success() {
+            return Err(CsmError::Other(format!(
+                "GitLab fetch_file failed with status {}: {owner}/{repo}/{path}@{ref}",
+                response.status()
+            )));
+        }
+        Ok(response.text().await?)
+    }
+
+    async fn latest_commit_sha(&self, owner: &str, repo: &str, r#ref: &str) -> Result<String> {
+        let project = Self::project_path(owner, repo);
+        let url = format!("{}/api/v4/projects/{project}/repository/commits/{ref}", self.base_url);
+        let response = self.authed(self.http.get(&url)).send().await?;
+        let body: serde_json::Value = response.json().await?;
+        body["id"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| CsmError::Other(format!("GitLab commit response missing 'id' for {owner}/{repo}@{ref}")))
+    }
+
+    async fn latest_tag(&self, owner: &str, repo: &str) -> Result<Option<String>> {
+        let project = Self::project_path(owner, repo);
+        let url = format!(
+            "{}/api/v4/projects/{project}/repository/tags?order_by=updated&per_page=1",
+            self.base_url
+        );
+        let response = self.authed(self.http.get(&url)).send().await?;
+        let tags: Vec<serde_json::Value> = response.json().await?;
+        Ok(tags.first().and_then(|t| t["name"].as_str()).map(str::to_string))
+    }
+
+    async fn commits_between(
+        &self,
+        owner: &str,
+        repo: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<String>> {
+        let project = Self::project_path(owner, repo);
+        let url = format!(
+            "{}/api/v4/projects/{project}/repository/compare?from={from}&to={to}",
+            self.base_url
+        );
+        let response = self.authed(self.http.get(&url)).send().await?;
+        let body: serde_json::Value = response.json().await?;
+        Ok(body["commits"]
+            .as_array()
+            .map(|commits| {
+                commits
+                    .iter()
+                    .filter_map(|c| c["message"].as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
@@ -0,0 +1,13 @@
+pub mod cli;
+pub mod config;
+pub mod error;
+pub mod git;
+pub mod github;
+pub mod gitlab;
+pub mod models;
+pub mod repository;
+pub mod services;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod utils;
+pub mod wiring;
@@ -0,0 +1,177 @@
+use clap::Parser;
+use csm::cli::{Cli, Commands};
+use csm::config::Config;
+use csm::utils::cancellation::CancellationToken;
+
+/// Spawns a background listener that cancels `token` on Ctrl-C, for commands
+/// that mutate several skills in a loop (`add --recursive`/archives, `update`).
+fn cancel_on_ctrl_c() -> CancellationToken {
+    let token = CancellationToken::new();
+    let watched = token.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            watched.cancel();
+        }
+    });
+    token
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let config = Config::load_with_config_override(cli.config.as_deref())?;
+    let style = csm::cli::OutputStyle::from_flag(cli.plain);
+
+    match cli.command {
+        Commands::List(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            println!(
+                "{}",
+                csm::cli::list::run(&args, service.as_ref(), style).await?
+            );
+        }
+        Commands::Add(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            let github = csm::wiring::build_github_client(&config);
+            let cancel = cancel_on_ctrl_c();
+            let summary = csm::cli::add::run(&args, service.as_ref(), github.as_ref(), &cancel).await?;
+            println!("{summary}");
+            if summary.all_failed() {
+                std::process::exit(1);
+            }
+        }
+        Commands::Init(args) => {
+            csm::cli::init::run(&args, &config).await?;
+            println!("initialized csm home at {}", config.csm_home.display());
+        }
+        Commands::MergePreview(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            println!(
+                "{}",
+                csm::cli::merge_preview::run(&args, service.as_ref()).await?
+            );
+        }
+        Commands::Show(args) => {
+            let skills = csm::wiring::build_skill_service(&config).await?;
+            let conflicts = csm::wiring::build_conflict_service(&config).await?;
+            println!(
+                "{}",
+                csm::cli::show::run(&args, skills.as_ref(), conflicts.as_ref()).await?
+            );
+        }
+        Commands::Diff(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            println!("{}", csm::cli::diff::run(&args, service.as_ref()).await?);
+        }
+        Commands::Export(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            if let Some(path) = &args.full {
+                let conflicts = csm::wiring::build_conflict_service(&config).await?;
+                let backup = csm::cli::export::run_full(service.as_ref(), conflicts.as_ref(), &config).await?;
+                std::fs::write(path, serde_json::to_string_pretty(&backup)?)?;
+                println!("wrote full backup to {}", path.display());
+            } else {
+                let skills = csm::cli::export::run(&args, service.as_ref()).await?;
+                let views: Vec<csm::models::SkillView> = skills.iter().map(csm::models::SkillView::from).collect();
+                println!("{}", serde_json::to_string_pretty(&views)?);
+            }
+        }
+        Commands::Import(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            let conflicts = csm::wiring::build_conflict_service(&config).await?;
+            let contents = std::fs::read_to_string(&args.full)?;
+            let backup: csm::cli::export::FullBackup = serde_json::from_str(&contents)?;
+            let summary = csm::cli::import::run_full(&backup, &args, service.as_ref(), conflicts.as_ref()).await?;
+            println!("{summary}");
+        }
+        Commands::Search(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            let skills = csm::cli::search::run(&args, service.as_ref()).await?;
+            for skill in skills {
+                println!("{}", skill.name);
+            }
+        }
+        Commands::Doctor(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            let merger = csm::wiring::build_merge_service(&config);
+            let claude_md_path = config.csm_home.join("CLAUDE.md");
+            let schema_version = Some(csm::repository::migrations::current_schema_version());
+            let report = csm::cli::doctor::run(&args, service.as_ref(), merger.as_ref(), &claude_md_path, schema_version).await?;
+            println!("{report}");
+            if !report.is_up_to_date() && !report.fixed {
+                std::process::exit(1);
+            }
+        }
+        Commands::Sync(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            let merger = csm::wiring::build_merge_service(&config);
+            let claude_md_path = config.csm_home.join("CLAUDE.md");
+            let schema_version = Some(csm::repository::migrations::current_schema_version());
+            let report = csm::cli::sync::run(&args, service.as_ref(), merger.as_ref(), &claude_md_path, schema_version).await?;
+            println!("{report}");
+            if !report.doctor.is_up_to_date() && !report.doctor.fixed {
+                std::process::exit(1);
+            }
+        }
+        Commands::Note(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            println!("{}", csm::cli::note::run(&args, service.as_ref()).await?);
+        }
+        Commands::Remove(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            println!("{}", csm::cli::remove::run(&args, service.as_ref()).await?);
+        }
+        Commands::Rename(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            println!("{}", csm::cli::rename::run(&args, service.as_ref()).await?);
+        }
+        Commands::Tag(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            println!("{}", csm::cli::tag::run(&args, service.as_ref()).await?);
+        }
+        Commands::Priority(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            println!("{}", csm::cli::priority::run(&args, service.as_ref()).await?);
+        }
+        Commands::Restore(args) => {
+            let service = csm::wiring::build_skill_service(&config).await?;
+            println!("{}", csm::cli::restore::run(&args, service.as_ref()).await?);
+        }
+        Commands::Conflicts(args) => {
+            let conflicts = csm::wiring::build_conflict_service(&config).await?;
+            let skills = csm::wiring::build_skill_service(&config).await?;
+            println!(
+                "{}",
+                csm::cli::conflicts::run(&args, conflicts.as_ref(), skills.as_ref()).await?
+            );
+        }
+        Commands::Update(args) => {
+            let updater = csm::wiring::build_update_service(&config).await?;
+            let skills = csm::wiring::build_skill_service(&config).await?;
+            let cancel = cancel_on_ctrl_c();
+            let exit_code = args.exit_code;
+            let outcome = csm::cli::update::run(&args, updater.as_ref(), skills.as_ref(), &cancel).await?;
+            match &outcome.json {
+                Some(json) => println!("{json}"),
+                None => println!("{}", outcome.summary),
+            }
+            if exit_code && !outcome.summary.changed.is_empty() {
+                std::process::exit(csm::cli::update::UPDATES_PENDING_EXIT_CODE);
+            }
+        }
+        Commands::Config(args) => match &args.command {
+            csm::cli::config::ConfigCommand::Path { json } => {
+                println!("{}", csm::cli::config::run_path(&config, *json));
+            }
+            _ => {
+                let config_path = config.csm_home.join("config.toml");
+                println!("{}", csm::cli::config::run(&args, &config_path)?);
+            }
+        },
+        Commands::Env(args) => {
+            println!("{}", csm::cli::env::run(&args, &config));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+
+/// Resolution state of a detected conflict between two skills.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictStatus {
+    Unresolved,
+    ResolvedDisableA,
+    ResolvedDisableB,
+    Ignored,
+    /// Was unresolved, but the underlying content no longer contradicts
+    /// (e.g. one skill's wording changed). Kept for history rather than deleted.
+    Stale,
+}
+
+impl fmt::Display for ConflictStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConflictStatus::Unresolved => write!(f, "unresolved"),
+            ConflictStatus::ResolvedDisableA => write!(f, "resolved-disable-a"),
+            ConflictStatus::ResolvedDisableB => write!(f, "resolved-disable-b"),
+            ConflictStatus::Ignored => write!(f, "ignored"),
+            ConflictStatus::Stale => write!(f, "stale"),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid conflict status '{0}': expected 'unresolved', 'resolved-disable-a', 'resolved-disable-b', 'ignored', or 'stale'")]
+pub struct ParseConflictStatusError(String);
+
+impl FromStr for ConflictStatus {
+    type Err = ParseConflictStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unresolved" => Ok(ConflictStatus::Unresolved),
+            "resolved-disable-a" => Ok(ConflictStatus::ResolvedDisableA),
+            "resolved-disable-b" => Ok(ConflictStatus::ResolvedDisableB),
+            "ignored" => Ok(ConflictStatus::Ignored),
+            "stale" => Ok(ConflictStatus::Stale),
+            other => Err(ParseConflictStatusError(other.to_string())),
+        }
+    }
+}
+
+/// A pairwise contradiction detected between two enabled skills' content.
+#[derive(Debug, Clone)]
+pub struct Conflict {
+    pub id: i64,
+    pub skill_a_id: i64,
+    pub skill_b_id: i64,
+    pub description: String,
+    pub status: ConflictStatus,
+    pub detected_at: DateTime<Utc>,
+    /// How urgent this conflict is, higher meaning more urgent. Computed by
+    /// `ConflictServiceImpl::contradicts` at detection time; `csm conflicts`
+    /// sorts by this, descending.
+    pub severity: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string_and_parse_round_trip() {
+        for status in [
+            ConflictStatus::Unresolved,
+            ConflictStatus::ResolvedDisableA,
+            ConflictStatus::ResolvedDisableB,
+            ConflictStatus::Ignored,
+            ConflictStatus::Stale,
+        ] {
+            assert_eq!(status.to_string().parse::<ConflictStatus>().unwrap(), status);
+        }
+    }
+
+    #[test]
+    fn unknown_status_is_rejected() {
+        assert!("bogus".parse::<ConflictStatus>().is_err());
+    }
+}
@@ -0,0 +1,7 @@
+/// Aggregate stats for what a merge would produce, without writing anything.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct MergePreviewStats {
+    pub skill_count: usize,
+    pub enabled_count: usize,
+    pub total_bytes: usize,
+}
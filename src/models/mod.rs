@@ -0,0 +1,19 @@
+mod conflict;
+mod merge_preview;
+mod same_name_strategy;
+mod scope;
+mod skill;
+mod skill_view;
+mod source;
+mod update_mode;
+mod update_trigger;
+
+pub use conflict::{Conflict, ConflictStatus, ParseConflictStatusError};
+pub use merge_preview::MergePreviewStats;
+pub use same_name_strategy::{ParseSameNameStrategyError, SameNameStrategy};
+pub use scope::{ParseSkillScopeError, SkillScope};
+pub use skill::Skill;
+pub use skill_view::SkillView;
+pub use source::{parse_source, SkillSource};
+pub use update_mode::{ParseUpdateModeError, UpdateMode};
+pub use update_trigger::{ParseUpdateTriggerError, UpdateTrigger};
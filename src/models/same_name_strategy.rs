@@ -0,0 +1,80 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+
+/// How to reconcile a project skill with a global skill of the same name
+/// during composition (see `SkillService::effective_list`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SameNameStrategy {
+    /// The project skill entirely replaces the global one.
+    Override,
+    /// The project skill's content is appended after the global one's.
+    Append,
+    /// The global skill wins outright; the project skill is dropped.
+    PreferGlobal,
+}
+
+impl Default for SameNameStrategy {
+    fn default() -> Self {
+        SameNameStrategy::Override
+    }
+}
+
+impl fmt::Display for SameNameStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SameNameStrategy::Override => write!(f, "override"),
+            SameNameStrategy::Append => write!(f, "append"),
+            SameNameStrategy::PreferGlobal => write!(f, "prefer-global"),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid merge.same_name_strategy '{0}': expected 'override', 'append', or 'prefer-global'")]
+pub struct ParseSameNameStrategyError(String);
+
+impl FromStr for SameNameStrategy {
+    type Err = ParseSameNameStrategyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "override" => Ok(SameNameStrategy::Override),
+            "append" => Ok(SameNameStrategy::Append),
+            "prefer-global" => Ok(SameNameStrategy::PreferGlobal),
+            other => Err(ParseSameNameStrategyError(other.to_string())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SameNameStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string_and_parse_round_trip() {
+        for strategy in [
+            SameNameStrategy::Override,
+            SameNameStrategy::Append,
+            SameNameStrategy::PreferGlobal,
+        ] {
+            assert_eq!(strategy.to_string().parse::<SameNameStrategy>().unwrap(), strategy);
+        }
+    }
+
+    #[test]
+    fn unknown_strategy_is_rejected() {
+        assert!("bogus".parse::<SameNameStrategy>().is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Where a skill lives: shared across all projects, or scoped to the current one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SkillScope {
+    Global,
+    Project,
+}
+
+impl fmt::Display for SkillScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkillScope::Global => write!(f, "global"),
+            SkillScope::Project => write!(f, "project"),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid scope '{0}': expected 'global', 'project', or 'local'")]
+pub struct ParseSkillScopeError(String);
+
+impl FromStr for SkillScope {
+    type Err = ParseSkillScopeError;
+
+    /// Parses `"global"` or `"project"`. `"local"` is also accepted as an
+    /// alias for `Project`, resolving to the current project root.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "global" => Ok(SkillScope::Global),
+            "project" | "local" => Ok(SkillScope::Project),
+            other => Err(ParseSkillScopeError(other.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string_and_parse_round_trip() {
+        for scope in [SkillScope::Global, SkillScope::Project] {
+            assert_eq!(scope.to_string().parse::<SkillScope>().unwrap(), scope);
+        }
+    }
+
+    #[test]
+    fn local_is_accepted_as_an_alias_for_project() {
+        assert_eq!("local".parse::<SkillScope>().unwrap(), SkillScope::Project);
+    }
+
+    #[test]
+    fn unknown_scope_is_rejected() {
+        assert!("bogus".parse::<SkillScope>().is_err());
+    }
+}
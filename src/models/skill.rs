@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+
+use super::scope::SkillScope;
+use super::source::SkillSource;
+use super::update_mode::UpdateMode;
+use super::update_trigger::UpdateTrigger;
+
+/// A single skill tracked by `csm`, with the content it will merge into `CLAUDE.md`.
+#[derive(Debug, Clone)]
+pub struct Skill {
+    pub id: i64,
+    pub name: String,
+    pub source: SkillSource,
+    pub scope: SkillScope,
+    pub content: String,
+    pub content_hash: String,
+    /// Content overwritten by the most recent `update_content` call, kept
+    /// around so `SkillService::rollback_content` can restore it. Cleared
+    /// once a rollback consumes it.
+    pub previous_content: Option<String>,
+    pub enabled: bool,
+    pub priority: i32,
+    pub update_mode: UpdateMode,
+    pub update_trigger: UpdateTrigger,
+    /// Soft-deleted: kept in storage with its content intact, but left out of
+    /// `list`/`effective_list`/merge output unless explicitly asked for.
+    pub archived: bool,
+    pub archived_at: Option<DateTime<Utc>>,
+    /// Commit SHA or tag the current content was last fetched from, used to detect drift.
+    pub last_known_ref: Option<String>,
+    /// Free-form local annotation, e.g. why a skill is pinned or disabled.
+    /// Never merged into `CLAUDE.md` and left out of exports by default.
+    pub notes: Option<String>,
+    pub tags: Vec<String>,
+    /// Consecutive failed `update_all` attempts against this skill's source,
+    /// reset to 0 on the next successful fetch. Drives exponential backoff
+    /// so a persistently-failing source doesn't get retried every run.
+    pub failure_count: i32,
+    /// When the most recent failed update attempt happened, used together
+    /// with `failure_count` to compute the backoff window.
+    pub last_failure_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::skill::Skill;
+
+/// Stable, serializable projection of a `Skill` for `--json` output.
+///
+/// Downstream scripts can rely on this shape without needing a separate
+/// `csm show` call per skill.
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillView {
+    /// Skill name, unique within its scope.
+    pub name: String,
+    /// SHA-256 of the stored content, for drift detection.
+    pub content_hash: String,
+    /// Human-readable form of the skill's `SkillSource` (see `SkillSource::display_string`).
+    pub source: String,
+    /// `"global"` or `"project"`.
+    pub scope: String,
+    pub enabled: bool,
+    pub priority: i32,
+    /// `"auto"`, `"notify"`, or `"manual"`.
+    pub update_mode: String,
+    pub archived: bool,
+    pub tags: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Skill> for SkillView {
+    fn from(skill: &Skill) -> Self {
+        Self {
+            name: skill.name.clone(),
+            content_hash: skill.content_hash.clone(),
+            source: skill.source.display_string(),
+            scope: skill.scope.to_string(),
+            enabled: skill.enabled,
+            priority: skill.priority,
+            update_mode: skill.update_mode.to_string(),
+            archived: skill.archived,
+            tags: skill.tags.clone(),
+            created_at: skill.created_at,
+            updated_at: skill.updated_at,
+        }
+    }
+}
@@ -0,0 +1,414 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Where a skill's content came from, so `update` knows how to refresh it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SkillSource {
+    GitHub {
+        owner: String,
+        repo: String,
+        path: String,
+        /// The tag, branch, or commit this skill is pinned to. Resolved
+        /// once at `add` time (or via `--list-versions`) and kept fixed so
+        /// later `update` checks compare against a stable baseline.
+        ref_spec: String,
+    },
+    GitLab {
+        owner: String,
+        repo: String,
+        path: String,
+        r#ref: String,
+        /// `None` means the default `gitlab.com` instance; `Some` is a
+        /// self-hosted base URL such as `https://gitlab.example.com`.
+        base_url: Option<String>,
+    },
+    /// A repo cloned directly with `git`, for hosts with no REST API
+    /// (self-hosted, SSH-only). `url` is the full `git clone` target, e.g.
+    /// `git@github.com:acme/skills.git` or `ssh://git@host/acme/skills.git`.
+    Git {
+        url: String,
+        path: String,
+        ref_spec: String,
+        /// The exact commit `ref_spec` resolved to on the last fetch, so
+        /// `update` can detect drift without re-cloning speculatively.
+        commit_sha: Option<String>,
+    },
+    Url(String),
+    Local(String),
+    Inline,
+}
+
+impl SkillSource {
+    /// Human-readable form used by `list`/`show` output.
+    pub fn display_string(&self) -> String {
+        match self {
+            SkillSource::GitHub {
+                owner,
+                repo,
+                path,
+                ref_spec,
+            } => format!("github:{owner}/{repo}/{path}@{ref_spec}"),
+            SkillSource::GitLab {
+                owner,
+                repo,
+                path,
+                r#ref,
+                base_url,
+            } => match base_url {
+                Some(base) => format!("gitlab:{owner}/{repo}/{path}@{ref} ({base})"),
+                None => format!("gitlab:{owner}/{repo}/{path}@{ref}"),
+            },
+            SkillSource::Git { url, path, ref_spec, .. } => format!("git:{url}/{path}@{ref_spec}"),
+            SkillSource::Url(url) => url.clone(),
+            SkillSource::Local(path) => path.clone(),
+            SkillSource::Inline => "inline".to_string(),
+        }
+    }
+
+    /// Whether `csm update` can meaningfully refresh this source, i.e. it
+    /// points at a specific ref of a version-controlled repo.
+    pub fn is_updatable(&self) -> bool {
+        matches!(
+            self,
+            SkillSource::GitHub { .. } | SkillSource::GitLab { .. } | SkillSource::Git { .. }
+        )
+    }
+
+    /// Whether `self` and `other` point at the same upstream file, ignoring
+    /// volatile tracking fields (`Git::commit_sha`) that drift between
+    /// fetches without the target itself changing. Plain `PartialEq` treats
+    /// those drifts as different sources, which breaks "is this the same
+    /// source?" checks like dedupe.
+    pub fn same_target(&self, other: &SkillSource) -> bool {
+        match (self, other) {
+            (
+                SkillSource::Git { url: a_url, path: a_path, ref_spec: a_ref, .. },
+                SkillSource::Git { url: b_url, path: b_path, ref_spec: b_ref, .. },
+            ) => a_url == b_url && a_path == b_path && a_ref == b_ref,
+            _ => self == other,
+        }
+    }
+
+    /// Name to default to when `--name` isn't given: the file stem of the path.
+    pub fn suggested_name(&self) -> Option<String> {
+        let path = match self {
+            SkillSource::GitHub { path, .. } => path.as_str(),
+            SkillSource::GitLab { path, .. } => path.as_str(),
+            SkillSource::Git { path, .. } => path.as_str(),
+            SkillSource::Local(path) => path.as_str(),
+            SkillSource::Url(_) | SkillSource::Inline => return None,
+        };
+        std::path::Path::new(path)
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+    }
+}
+
+impl fmt::Display for SkillSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_string())
+    }
+}
+
+/// Parses a `--source` argument into a `SkillSource`.
+///
+/// Recognizes `github:owner/repo[/path][@ref]` and `gitlab:owner/repo[/path][@ref]`
+/// prefixes (`ref` defaults to `main` when omitted), `git@`/`ssh://` clone
+/// URLs (see `parse_git_reference`), `http(s)://` URLs, and otherwise treats
+/// the argument as a local path.
+pub fn parse_source(raw: &str) -> SkillSource {
+    if let Some(rest) = raw.strip_prefix("github:") {
+        let (owner, repo, path, r#ref) = parse_forge_reference(rest);
+        return SkillSource::GitHub { owner, repo, path, ref_spec: r#ref };
+    }
+
+    if let Some(rest) = raw.strip_prefix("gitlab:") {
+        let (owner, repo, path, r#ref) = parse_forge_reference(rest);
+        return SkillSource::GitLab {
+            owner,
+            repo,
+            path,
+            r#ref,
+            base_url: std::env::var("GITLAB_BASE_URL").ok(),
+        };
+    }
+
+    if raw.starts_with("git@") || raw.starts_with("ssh://") {
+        return parse_git_reference(raw);
+    }
+
+    if raw.starts_with("http://") || raw.starts_with("https://") {
+        return SkillSource::Url(raw.to_string());
+    }
+
+    parse_local_source(raw)
+}
+
+/// Builds a `SkillSource::Local` from a raw local path, expanding `~` and
+/// `$VAR`/`${VAR}` environment references and resolving relative paths
+/// against the current directory, so `csm add ~/skills/foo.md` and
+/// `csm add ../foo.md` behave like a shell would. Already-absolute paths
+/// pass through unchanged apart from environment expansion.
+fn parse_local_source(raw: &str) -> SkillSource {
+    SkillSource::Local(expand_local_path(raw))
+}
+
+/// `$VAR`/`${VAR}`, matching a leading `$` followed by an optional `{...}`
+/// or a bare identifier. Unset variables are left untouched rather than
+/// collapsed to an empty string, so a typo'd `$VAR` stays visible.
+fn expand_env_vars(raw: &str) -> String {
+    let pattern = regex::Regex::new(r"\$\{(\w+)\}|\$(\w+)").unwrap();
+    pattern
+        .replace_all(raw, |caps: &regex::Captures| {
+            let name = caps.get(1).or_else(|| caps.get(2)).unwrap().as_str();
+            std::env::var(name).unwrap_or_else(|_| caps[0].to_string())
+        })
+        .into_owned()
+}
+
+fn expand_local_path(raw: &str) -> String {
+    let expanded = expand_env_vars(raw);
+
+    let expanded = if expanded == "~" || expanded.starts_with("~/") {
+        match dirs::home_dir() {
+            Some(home) => home.join(expanded.trim_start_matches('~').trim_start_matches('/')).to_string_lossy().into_owned(),
+            None => expanded,
+        }
+    } else {
+        expanded
+    };
+
+    let path = std::path::Path::new(&expanded);
+    if path.is_absolute() {
+        return expanded;
+    }
+
+    // Best-effort: resolve against the real filesystem when the path
+    // exists, so `..` components collapse; otherwise fall back to a
+    // lexical join against `cwd` for paths that don't exist yet.
+    std::env::current_dir()
+        .ok()
+        .map(|cwd| cwd.join(&expanded))
+        .map(|joined| std::fs::canonicalize(&joined).unwrap_or(joined))
+        .map(|resolved| resolved.to_string_lossy().into_owned())
+        .unwrap_or(expanded)
+}
+
+/// Parses an SSH clone URL, e.g. `git@github.com:acme/skills.git` or
+/// `ssh://git@host/acme/skills.git`, optionally followed by
+/// `//path[@ref]` to pick a file other than `SKILL.md` off a branch other
+/// than `main`.
+fn parse_git_reference(raw: &str) -> SkillSource {
+    let (url, rest) = match raw.split_once(".git") {
+        Some((base, rest)) => (format!("{base}.git"), rest),
+        None => (raw.to_string(), ""),
+    };
+    let rest = rest.trim_start_matches('/');
+
+    let (path, ref_spec) = if rest.is_empty() {
+        ("SKILL.md".to_string(), "main".to_string())
+    } else {
+        match rest.split_once('@') {
+            Some((path, r#ref)) => (path.to_string(), r#ref.to_string()),
+            None => (rest.to_string(), "main".to_string()),
+        }
+    };
+
+    SkillSource::Git {
+        url,
+        path,
+        ref_spec,
+        commit_sha: None,
+    }
+}
+
+/// Shared `owner/repo[/path][@ref]` parsing for the GitHub/GitLab prefixes.
+fn parse_forge_reference(rest: &str) -> (String, String, String, String) {
+    let (body, r#ref) = match rest.split_once('@') {
+        Some((body, r#ref)) => (body, r#ref.to_string()),
+        None => (rest, "main".to_string()),
+    };
+
+    let mut parts = body.splitn(3, '/');
+    let owner = parts.next().unwrap_or_default().to_string();
+    let repo = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    (owner, repo, path, r#ref)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_github_full_source() {
+        let source = parse_source("github:acme/skills/tools/deploy.md@v2");
+        assert_eq!(
+            source,
+            SkillSource::GitHub {
+                owner: "acme".to_string(),
+                repo: "skills".to_string(),
+                path: "tools/deploy.md".to_string(),
+                ref_spec: "v2".to_string(),
+            }
+        );
+        assert!(source.is_updatable());
+        assert_eq!(source.suggested_name(), Some("deploy".to_string()));
+    }
+
+    #[test]
+    fn parses_gitlab_full_source() {
+        std::env::remove_var("GITLAB_BASE_URL");
+        let source = parse_source("gitlab:acme/skills/tools/deploy.md@v2");
+        assert_eq!(
+            source,
+            SkillSource::GitLab {
+                owner: "acme".to_string(),
+                repo: "skills".to_string(),
+                path: "tools/deploy.md".to_string(),
+                r#ref: "v2".to_string(),
+                base_url: None,
+            }
+        );
+        assert!(source.is_updatable());
+        assert_eq!(source.suggested_name(), Some("deploy".to_string()));
+        assert_eq!(source.display_string(), "gitlab:acme/skills/tools/deploy.md@v2");
+    }
+
+    #[test]
+    fn gitlab_source_defaults_ref_to_main_and_honors_base_url_override() {
+        std::env::set_var("GITLAB_BASE_URL", "https://gitlab.example.com");
+        let source = parse_source("gitlab:acme/skills/tools/deploy.md");
+        std::env::remove_var("GITLAB_BASE_URL");
+
+        match source {
+            SkillSource::GitLab { r#ref, base_url, .. } => {
+                assert_eq!(r#ref, "main");
+                assert_eq!(base_url.as_deref(), Some("https://gitlab.example.com"));
+            }
+            other => panic!("expected GitLab source, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_a_scp_style_ssh_url_with_explicit_path_and_ref() {
+        let source = parse_source("git@github.com:acme/skills.git//tools/deploy.md@v2");
+        assert_eq!(
+            source,
+            SkillSource::Git {
+                url: "git@github.com:acme/skills.git".to_string(),
+                path: "tools/deploy.md".to_string(),
+                ref_spec: "v2".to_string(),
+                commit_sha: None,
+            }
+        );
+        assert!(source.is_updatable());
+        assert_eq!(source.suggested_name(), Some("deploy".to_string()));
+    }
+
+    #[test]
+    fn parses_a_full_ssh_url_defaulting_path_and_ref() {
+        let source = parse_source("ssh://git@gitlab.example.com/acme/skills.git");
+        assert_eq!(
+            source,
+            SkillSource::Git {
+                url: "ssh://git@gitlab.example.com/acme/skills.git".to_string(),
+                path: "SKILL.md".to_string(),
+                ref_spec: "main".to_string(),
+                commit_sha: None,
+            }
+        );
+    }
+
+    #[test]
+    fn git_sources_with_different_commit_shas_are_the_same_target() {
+        let a = SkillSource::Git {
+            url: "git@github.com:acme/skills.git".to_string(),
+            path: "SKILL.md".to_string(),
+            ref_spec: "main".to_string(),
+            commit_sha: Some("deadbeef".to_string()),
+        };
+        let b = SkillSource::Git {
+            commit_sha: Some("f00dcafe".to_string()),
+            ..a.clone()
+        };
+
+        assert_ne!(a, b);
+        assert!(a.same_target(&b));
+    }
+
+    #[test]
+    fn sources_pinned_to_different_refs_are_not_the_same_target() {
+        let a = SkillSource::Git {
+            url: "git@github.com:acme/skills.git".to_string(),
+            path: "SKILL.md".to_string(),
+            ref_spec: "main".to_string(),
+            commit_sha: None,
+        };
+        let b = SkillSource::Git {
+            ref_spec: "v2".to_string(),
+            ..a.clone()
+        };
+
+        assert!(!a.same_target(&b));
+    }
+
+    #[test]
+    fn non_git_sources_fall_back_to_full_equality() {
+        let github_a = SkillSource::GitHub {
+            owner: "acme".to_string(),
+            repo: "skills".to_string(),
+            path: "SKILL.md".to_string(),
+            ref_spec: "main".to_string(),
+        };
+        let github_b = github_a.clone();
+        assert!(github_a.same_target(&github_b));
+        assert!(!github_a.same_target(&SkillSource::Inline));
+    }
+
+    #[test]
+    fn non_prefixed_sources_fall_back_to_url_or_local() {
+        assert_eq!(
+            parse_source("https://example.com/skill.md"),
+            SkillSource::Url("https://example.com/skill.md".to_string())
+        );
+        assert_eq!(
+            parse_source("/abs/notes.md"),
+            SkillSource::Local("/abs/notes.md".to_string())
+        );
+    }
+
+    #[test]
+    fn expands_a_tilde_prefixed_local_path_against_the_home_directory() {
+        let home = dirs::home_dir().unwrap();
+        let expected = home.join("skills/foo.md").to_string_lossy().into_owned();
+        assert_eq!(
+            parse_source("~/skills/foo.md"),
+            SkillSource::Local(expected)
+        );
+    }
+
+    #[test]
+    fn expands_an_env_var_prefixed_local_path() {
+        std::env::set_var("CSM_TEST_SOURCE_HOME", "/home/csm-test-user");
+        assert_eq!(
+            parse_source("$CSM_TEST_SOURCE_HOME/skills/foo.md"),
+            SkillSource::Local("/home/csm-test-user/skills/foo.md".to_string())
+        );
+        assert_eq!(
+            parse_source("${CSM_TEST_SOURCE_HOME}/skills/foo.md"),
+            SkillSource::Local("/home/csm-test-user/skills/foo.md".to_string())
+        );
+        std::env::remove_var("CSM_TEST_SOURCE_HOME");
+    }
+
+    #[test]
+    fn canonicalizes_a_relative_local_path_against_the_current_directory() {
+        let cwd = std::env::current_dir().unwrap();
+        let expected = cwd.join("../foo.md");
+        let expected = std::fs::canonicalize(&expected).unwrap_or(expected).to_string_lossy().into_owned();
+        assert_eq!(parse_source("../foo.md"), SkillSource::Local(expected));
+    }
+}
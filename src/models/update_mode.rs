@@ -0,0 +1,60 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// How `csm update` should treat this skill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateMode {
+    Auto,
+    Notify,
+    Manual,
+}
+
+impl fmt::Display for UpdateMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpdateMode::Auto => write!(f, "auto"),
+            UpdateMode::Notify => write!(f, "notify"),
+            UpdateMode::Manual => write!(f, "manual"),
+        }
+    }
+}
+
+impl Default for UpdateMode {
+    fn default() -> Self {
+        UpdateMode::Auto
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid update mode '{0}': expected 'auto', 'notify', or 'manual'")]
+pub struct ParseUpdateModeError(String);
+
+impl FromStr for UpdateMode {
+    type Err = ParseUpdateModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(UpdateMode::Auto),
+            "notify" => Ok(UpdateMode::Notify),
+            "manual" => Ok(UpdateMode::Manual),
+            other => Err(ParseUpdateModeError(other.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string_and_parse_round_trip() {
+        for mode in [UpdateMode::Auto, UpdateMode::Notify, UpdateMode::Manual] {
+            assert_eq!(mode.to_string().parse::<UpdateMode>().unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn unknown_mode_is_rejected() {
+        assert!("bogus".parse::<UpdateMode>().is_err());
+    }
+}
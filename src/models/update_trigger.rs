@@ -0,0 +1,63 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// What upstream change should cause `update` to report a pending update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateTrigger {
+    /// Any new commit on the tracked ref.
+    OnCommit,
+    /// Only when a new tag appears.
+    OnTag,
+    /// Only when a new GitHub release is published.
+    OnRelease,
+}
+
+impl fmt::Display for UpdateTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpdateTrigger::OnCommit => write!(f, "on_commit"),
+            UpdateTrigger::OnTag => write!(f, "on_tag"),
+            UpdateTrigger::OnRelease => write!(f, "on_release"),
+        }
+    }
+}
+
+impl Default for UpdateTrigger {
+    fn default() -> Self {
+        UpdateTrigger::OnCommit
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid update trigger '{0}': expected 'on_commit', 'on_tag', or 'on_release'")]
+pub struct ParseUpdateTriggerError(String);
+
+impl FromStr for UpdateTrigger {
+    type Err = ParseUpdateTriggerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "on_commit" => Ok(UpdateTrigger::OnCommit),
+            "on_tag" => Ok(UpdateTrigger::OnTag),
+            "on_release" => Ok(UpdateTrigger::OnRelease),
+            other => Err(ParseUpdateTriggerError(other.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string_and_parse_round_trip() {
+        for trigger in [UpdateTrigger::OnCommit, UpdateTrigger::OnTag, UpdateTrigger::OnRelease] {
+            assert_eq!(trigger.to_string().parse::<UpdateTrigger>().unwrap(), trigger);
+        }
+    }
+
+    #[test]
+    fn unknown_trigger_is_rejected() {
+        assert!("bogus".parse::<UpdateTrigger>().is_err());
+    }
+}
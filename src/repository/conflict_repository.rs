@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+use crate::error::Result;
+use crate::models::Conflict;
+
+/// Persistence boundary for detected conflicts between skill pairs.
+#[async_trait]
+pub trait ConflictRepository: Send + Sync {
+    async fn create(&self, conflict: Conflict) -> Result<Conflict>;
+    async fn list(&self) -> Result<Vec<Conflict>>;
+    async fn find_by_pair(&self, skill_a_id: i64, skill_b_id: i64) -> Result<Option<Conflict>>;
+    async fn update(&self, conflict: Conflict) -> Result<Conflict>;
+
+    /// Durably records that `(skill_a_id, skill_b_id)` should never be
+    /// resurfaced by `detect`, so an `Ignore` resolution survives even
+    /// across a dedup reset. `skill_a_id`/`skill_b_id` may be given in
+    /// either order; implementations normalize internally.
+    async fn whitelist_pair(&self, skill_a_id: i64, skill_b_id: i64) -> Result<()>;
+
+    /// Whether `(skill_a_id, skill_b_id)` was previously whitelisted via
+    /// `whitelist_pair`.
+    async fn is_whitelisted(&self, skill_a_id: i64, skill_b_id: i64) -> Result<bool>;
+
+    /// Drops every whitelisted pair, so `detect` will surface them again.
+    async fn clear_whitelist(&self) -> Result<()>;
+}
@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::error::Result;
+use crate::models::Conflict;
+
+use super::conflict_repository::ConflictRepository;
+
+/// Orders a skill pair consistently regardless of which side it's given as,
+/// matching `ConflictServiceImpl::resolve_pair`'s `(lo, hi)` convention.
+fn ordered_pair(a: i64, b: i64) -> (i64, i64) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Simple in-process `ConflictRepository`, used until conflicts get their
+/// own SQLite table (see `SqliteSkillRepository` for the skills equivalent).
+#[derive(Default)]
+pub struct InMemoryConflictRepository {
+    conflicts: Mutex<Vec<Conflict>>,
+    whitelist: Mutex<HashSet<(i64, i64)>>,
+}
+
+#[async_trait]
+impl ConflictRepository for InMemoryConflictRepository {
+    async fn create(&self, mut conflict: Conflict) -> Result<Conflict> {
+        let mut conflicts = self.conflicts.lock().unwrap();
+        conflict.id = conflicts.len() as i64 + 1;
+        conflicts.push(conflict.clone());
+        Ok(conflict)
+    }
+
+    async fn list(&self) -> Result<Vec<Conflict>> {
+        Ok(self.conflicts.lock().unwrap().clone())
+    }
+
+    async fn find_by_pair(&self, skill_a_id: i64, skill_b_id: i64) -> Result<Option<Conflict>> {
+        Ok(self
+            .conflicts
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|c| c.skill_a_id == skill_a_id && c.skill_b_id == skill_b_id)
+            .cloned())
+    }
+
+    async fn update(&self, conflict: Conflict) -> Result<Conflict> {
+        let mut conflicts = self.conflicts.lock().unwrap();
+        if let Some(existing) = conflicts.iter_mut().find(|c| c.id == conflict.id) {
+            *existing = conflict.clone();
+        }
+        Ok(conflict)
+    }
+
+    async fn whitelist_pair(&self, skill_a_id: i64, skill_b_id: i64) -> Result<()> {
+        self.whitelist
+            .lock()
+            .unwrap()
+            .insert(ordered_pair(skill_a_id, skill_b_id));
+        Ok(())
+    }
+
+    async fn is_whitelisted(&self, skill_a_id: i64, skill_b_id: i64) -> Result<bool> {
+        Ok(self
+            .whitelist
+            .lock()
+            .unwrap()
+            .contains(&ordered_pair(skill_a_id, skill_b_id)))
+    }
+
+    async fn clear_whitelist(&self) -> Result<()> {
+        self.whitelist.lock().unwrap().clear();
+        Ok(())
+    }
+}
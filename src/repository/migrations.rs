@@ -0,0 +1,142 @@
+use sqlx::SqlitePool;
+
+use crate::error::Result;
+
+/// One `schema_version` -> DDL step, applied in order. Migration `n` moves
+/// the database from version `n - 1` to version `n`.
+struct Migration {
+    version: i64,
+    sql: &'static str,
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        sql: r#"
+        CREATE TABLE skills (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            source TEXT NOT NULL,
+            scope TEXT NOT NULL,
+            content TEXT NOT NULL,
+            content_hash TEXT NOT NULL,
+            enabled INTEGER NOT NULL DEFAULT 1,
+            priority INTEGER NOT NULL DEFAULT 0,
+            update_mode TEXT NOT NULL,
+            update_trigger TEXT NOT NULL,
+            archived INTEGER NOT NULL DEFAULT 0,
+            archived_at TEXT,
+            last_known_ref TEXT,
+            notes TEXT,
+            tags TEXT NOT NULL DEFAULT '[]',
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+    "#,
+    },
+    Migration {
+        version: 2,
+        sql: "ALTER TABLE skills ADD COLUMN failure_count INTEGER NOT NULL DEFAULT 0",
+    },
+    Migration {
+        version: 3,
+        sql: "ALTER TABLE skills ADD COLUMN last_failure_at TEXT",
+    },
+    Migration {
+        version: 4,
+        sql: "ALTER TABLE skills ADD COLUMN previous_content TEXT",
+    },
+    // The FTS5 index backing `SqliteSkillRepository::search_content_only`.
+    // Split into one statement per version, since each `Migration.sql` is
+    // run as a single `sqlx::query`, not a multi-statement script.
+    Migration {
+        version: 5,
+        sql: "CREATE VIRTUAL TABLE skills_fts USING fts5(content, content='skills', content_rowid='id')",
+    },
+    Migration {
+        version: 6,
+        sql: "CREATE TRIGGER skills_fts_after_insert AFTER INSERT ON skills BEGIN
+            INSERT INTO skills_fts (rowid, content) VALUES (new.id, new.content);
+        END",
+    },
+    Migration {
+        version: 7,
+        sql: "CREATE TRIGGER skills_fts_after_delete AFTER DELETE ON skills BEGIN
+            INSERT INTO skills_fts (skills_fts, rowid, content) VALUES ('delete', old.id, old.content);
+        END",
+    },
+    Migration {
+        version: 8,
+        sql: "CREATE TRIGGER skills_fts_after_update AFTER UPDATE ON skills BEGIN
+            INSERT INTO skills_fts (skills_fts, rowid, content) VALUES ('delete', old.id, old.content);
+            INSERT INTO skills_fts (rowid, content) VALUES (new.id, new.content);
+        END",
+    },
+    Migration {
+        version: 9,
+        sql: "INSERT INTO skills_fts (rowid, content) SELECT id, content FROM skills",
+    },
+];
+
+/// Highest version in `MIGRATIONS`, i.e. the schema this build of `csm`
+/// expects a fully-migrated database to be at.
+pub fn current_schema_version() -> i64 {
+    MIGRATIONS.last().map(|m| m.version).unwrap_or(0)
+}
+
+/// Creates `schema_version` if missing, then applies every migration newer
+/// than the recorded version inside a single transaction, bumping the
+/// recorded version as it goes. Safe to call on every startup: with nothing
+/// pending, it's a no-op past the initial `CREATE TABLE IF NOT EXISTS`.
+pub async fn run_migrations(pool: &SqlitePool) -> Result<i64> {
+    sqlx::query("CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL)")
+        .execute(pool)
+        .await?;
+
+    let mut tx = pool.begin().await?;
+
+    let recorded: Option<i64> = sqlx::query_scalar("SELECT version FROM schema_version LIMIT 1")
+        .fetch_optional(&mut *tx)
+        .await?;
+    let mut version = recorded.unwrap_or(0);
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > version) {
+        sqlx::query(migration.sql).execute(&mut *tx).await?;
+        version = migration.version;
+    }
+
+    if recorded.is_some() {
+        sqlx::query("UPDATE schema_version SET version = ?")
+            .bind(version)
+            .execute(&mut *tx)
+            .await?;
+    } else {
+        sqlx::query("INSERT INTO schema_version (version) VALUES (?)")
+            .bind(version)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+    Ok(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[tokio::test]
+    async fn running_migrations_twice_settles_on_the_current_version() {
+        let pool = SqlitePoolOptions::new()
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        let first = run_migrations(&pool).await.unwrap();
+        let second = run_migrations(&pool).await.unwrap();
+
+        assert_eq!(first, current_schema_version());
+        assert_eq!(second, current_schema_version());
+    }
+}
@@ -0,0 +1,10 @@
+mod conflict_repository;
+mod in_memory_conflict_repository;
+pub mod migrations;
+mod skill_repository;
+mod sqlite_skill_repository;
+
+pub use conflict_repository::ConflictRepository;
+pub use in_memory_conflict_repository::InMemoryConflictRepository;
+pub use skill_repository::SkillRepository;
+pub use sqlite_skill_repository::SqliteSkillRepository;
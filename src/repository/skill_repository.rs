@@ -0,0 +1,211 @@
+use async_trait::async_trait;
+
+use crate::error::Result;
+use crate::models::{Skill, SkillScope, UpdateMode};
+use crate::services::MergeService;
+
+/// Persistence boundary for skills. Backed by SQLite in `SqliteSkillRepository`.
+#[async_trait]
+pub trait SkillRepository: Send + Sync {
+    async fn create(&self, skill: Skill) -> Result<Skill>;
+
+    /// Inserts many skills in one round trip; used by archive/directory imports.
+    async fn bulk_create(&self, skills: Vec<Skill>) -> Result<Vec<Skill>> {
+        let mut created = Vec::with_capacity(skills.len());
+        for skill in skills {
+            created.push(self.create(skill).await?);
+        }
+        Ok(created)
+    }
+    async fn find_by_name(&self, name: &str, scope: SkillScope) -> Result<Option<Skill>>;
+    async fn list(&self) -> Result<Vec<Skill>>;
+    async fn update(&self, skill: Skill) -> Result<Skill>;
+    async fn delete(&self, id: i64) -> Result<()>;
+
+    /// Skills whose *content* contains `query` (case-insensitive), for the
+    /// CLI `search --content` path.
+    ///
+    /// This is a plain substring scan over `list()`, adequate for the
+    /// in-memory fakes used in tests. `SqliteSkillRepository` overrides it
+    /// with a real FTS5 `MATCH` query against a `skills_fts` virtual table
+    /// instead of inheriting this default.
+    async fn search_content_only(&self, query: &str) -> Result<Vec<Skill>> {
+        let needle = query.to_lowercase();
+        Ok(self
+            .list()
+            .await?
+            .into_iter()
+            .filter(|s| s.content.to_lowercase().contains(&needle))
+            .collect())
+    }
+
+    /// Skills whose `update_mode` is exactly `mode`, for `csm list --update-mode`.
+    ///
+    /// A plain filter over `list()` rather than a SQL `WHERE update_mode = ?`.
+    /// `SqliteSkillRepository` inherits this default: the result sets
+    /// involved are small enough that a dedicated query wouldn't pay for
+    /// itself, unlike `search_content_only`'s FTS5 override.
+    async fn list_by_update_mode(&self, mode: UpdateMode) -> Result<Vec<Skill>> {
+        Ok(self
+            .list()
+            .await?
+            .into_iter()
+            .filter(|s| s.update_mode == mode)
+            .collect())
+    }
+
+    /// Skills whose `scope` is exactly `scope`, for reconciling a project's
+    /// effective skill set against the global one.
+    ///
+    /// A plain filter over `list()` rather than a SQL `WHERE scope = ?`, for
+    /// the same reason as `list_by_update_mode`: `SqliteSkillRepository`
+    /// inherits this default too.
+    async fn list_by_scope(&self, scope: SkillScope) -> Result<Vec<Skill>> {
+        Ok(self
+            .list()
+            .await?
+            .into_iter()
+            .filter(|s| s.scope == scope)
+            .collect())
+    }
+
+    /// Raw-row corruption that predates the parsed `Skill` model, e.g. a
+    /// `scope` column value that no longer parses to a `SkillScope` variant,
+    /// as one message per bad row. For `csm doctor`.
+    ///
+    /// Every implementor here (the in-memory fakes used in tests, and
+    /// anything working off already-parsed `Skill` values) has no separate
+    /// raw representation to drift from the parsed one, so the default is
+    /// "nothing to report". Only `SqliteSkillRepository`, which owns the raw
+    /// columns, can meaningfully override this.
+    async fn find_integrity_issues(&self) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// Creates `skill`, then merges it via `merger`. If the merge fails, the
+    /// just-created row is deleted, so a failed merge never leaves a skill
+    /// stored whose content isn't reflected in `CLAUDE.md`.
+    ///
+    /// This is the transactional boundary for `add`: `create` and `merge`
+    /// either both take effect or neither does, from the caller's point of
+    /// view.
+    async fn create_and_merge(&self, skill: Skill, merger: &dyn MergeService) -> Result<Skill> {
+        let created = self.create(skill).await?;
+        if let Err(e) = merger.merge(&created).await {
+            let _ = self.delete(created.id).await;
+            return Err(e);
+        }
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeRepository(Vec<Skill>);
+
+    #[async_trait]
+    impl SkillRepository for FakeRepository {
+        async fn create(&self, skill: Skill) -> Result<Skill> {
+            Ok(skill)
+        }
+        async fn find_by_name(&self, _name: &str, _scope: SkillScope) -> Result<Option<Skill>> {
+            unimplemented!()
+        }
+        async fn list(&self) -> Result<Vec<Skill>> {
+            Ok(self.0.clone())
+        }
+        async fn update(&self, skill: Skill) -> Result<Skill> {
+            Ok(skill)
+        }
+        async fn delete(&self, _id: i64) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn skill(name: &str, content: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: name.to_string(),
+            source: crate::models::SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: content.to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: crate::models::UpdateMode::Auto,
+            update_trigger: crate::models::UpdateTrigger::Manual,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn search_content_only_finds_a_skill_by_a_word_present_only_in_its_body() {
+        let repository = FakeRepository(vec![
+            skill("deploy", "run the deploy-fleet script before merging"),
+            skill("unrelated", "nothing interesting here"),
+        ]);
+
+        let results = repository.search_content_only("DEPLOY-FLEET").await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "deploy");
+    }
+
+    #[tokio::test]
+    async fn list_by_update_mode_returns_only_matching_skills() {
+        let mut auto = skill("auto-skill", "content");
+        auto.update_mode = crate::models::UpdateMode::Auto;
+        let mut manual = skill("manual-skill", "content");
+        manual.update_mode = crate::models::UpdateMode::Manual;
+        let repository = FakeRepository(vec![auto, manual]);
+
+        let results = repository
+            .list_by_update_mode(crate::models::UpdateMode::Manual)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "manual-skill");
+    }
+
+    #[tokio::test]
+    async fn list_by_scope_returns_only_matching_skills() {
+        let mut global = skill("global-skill", "content");
+        global.scope = SkillScope::Global;
+        let mut project = skill("project-skill", "content");
+        project.scope = SkillScope::Project;
+        let repository = FakeRepository(vec![global, project]);
+
+        let results = repository.list_by_scope(SkillScope::Project).await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "project-skill");
+    }
+
+    #[tokio::test]
+    async fn percent_and_underscore_in_the_query_are_matched_literally() {
+        // Matches via `str::contains` over content already loaded through
+        // `list()`, not a SQL `LIKE`, so wildcard characters can't leak in.
+        let repository = FakeRepository(vec![
+            skill("discount", "up to 100% off this week"),
+            skill("other", "100x off this week"),
+        ]);
+
+        let results = repository.search_content_only("100%").await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "discount");
+    }
+}
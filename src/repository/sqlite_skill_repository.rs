@@ -0,0 +1,500 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, Row, Sqlite, SqlitePool};
+
+use crate::error::{CsmError, Result};
+use crate::models::{Skill, SkillScope, UpdateMode, UpdateTrigger};
+
+use super::skill_repository::SkillRepository;
+
+const INSERT_SKILL: &str = "INSERT INTO skills (
+    name, source, scope, content, content_hash, previous_content,
+    enabled, priority, update_mode, update_trigger, archived, archived_at,
+    last_known_ref, notes, tags, failure_count, last_failure_at,
+    created_at, updated_at
+) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)";
+
+const UPDATE_SKILL: &str = "UPDATE skills SET
+    name = ?, source = ?, scope = ?, content = ?, content_hash = ?, previous_content = ?,
+    enabled = ?, priority = ?, update_mode = ?, update_trigger = ?, archived = ?, archived_at = ?,
+    last_known_ref = ?, notes = ?, tags = ?, failure_count = ?, last_failure_at = ?, updated_at = ?
+    WHERE id = ?";
+
+/// SQLite-backed `SkillRepository`, one row per skill in the `skills` table.
+pub struct SqliteSkillRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteSkillRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Applies validated PRAGMA tuning (see `config::database::ALLOWED_PRAGMAS`)
+    /// to every connection in the pool. Called once, right after construction.
+    pub async fn apply_pragmas(&self, pragmas: &[(String, String)]) -> Result<()> {
+        for (name, value) in pragmas {
+            let statement = format!("PRAGMA {name} = {value}");
+            sqlx::query(&statement).execute(&self.pool).await?;
+        }
+        Ok(())
+    }
+
+    /// Applies any pending schema migrations (see `repository::migrations`)
+    /// inside a transaction, recording the resulting version, and returns
+    /// it. Called once, right after construction, alongside `apply_pragmas`.
+    pub async fn run_migrations(&self) -> Result<i64> {
+        super::migrations::run_migrations(&self.pool).await
+    }
+}
+
+/// Inserts `skill` (ignoring its `id`) via `executor`, returning the row id
+/// SQLite assigned. Shared by `create` (single connection) and `bulk_create`
+/// (one transaction), so the column list only lives in one place.
+async fn insert_skill<'e, E>(executor: E, skill: &Skill) -> Result<i64>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let source_json = serde_json::to_string(&skill.source)?;
+    let tags_json = serde_json::to_string(&skill.tags)?;
+
+    let result = sqlx::query(INSERT_SKILL)
+        .bind(&skill.name)
+        .bind(&source_json)
+        .bind(skill.scope.to_string())
+        .bind(&skill.content)
+        .bind(&skill.content_hash)
+        .bind(&skill.previous_content)
+        .bind(skill.enabled)
+        .bind(skill.priority)
+        .bind(skill.update_mode.to_string())
+        .bind(skill.update_trigger.to_string())
+        .bind(skill.archived)
+        .bind(skill.archived_at.map(|d| d.to_rfc3339()))
+        .bind(&skill.last_known_ref)
+        .bind(&skill.notes)
+        .bind(&tags_json)
+        .bind(skill.failure_count)
+        .bind(skill.last_failure_at.map(|d| d.to_rfc3339()))
+        .bind(skill.created_at.to_rfc3339())
+        .bind(skill.updated_at.to_rfc3339())
+        .execute(executor)
+        .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+fn parse_timestamp(raw: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| CsmError::Other(format!("corrupt timestamp '{raw}': {e}")))
+}
+
+/// Parses one `skills` row into a `Skill`, round-tripping `source`/`tags`
+/// through JSON and `scope`/`update_mode`/`update_trigger` through their
+/// `FromStr` impls.
+fn row_to_skill(row: &sqlx::sqlite::SqliteRow) -> Result<Skill> {
+    let source_json: String = row.get("source");
+    let scope_raw: String = row.get("scope");
+    let update_mode_raw: String = row.get("update_mode");
+    let update_trigger_raw: String = row.get("update_trigger");
+    let tags_json: String = row.get("tags");
+    let created_at_raw: String = row.get("created_at");
+    let updated_at_raw: String = row.get("updated_at");
+    let archived_at_raw: Option<String> = row.get("archived_at");
+    let last_failure_at_raw: Option<String> = row.get("last_failure_at");
+
+    Ok(Skill {
+        id: row.get("id"),
+        name: row.get("name"),
+        source: serde_json::from_str(&source_json)?,
+        scope: SkillScope::from_str(&scope_raw)
+            .map_err(|e| CsmError::Other(format!("corrupt row: {e}")))?,
+        content: row.get("content"),
+        content_hash: row.get("content_hash"),
+        previous_content: row.get("previous_content"),
+        enabled: row.get("enabled"),
+        priority: row.get("priority"),
+        update_mode: UpdateMode::from_str(&update_mode_raw)
+            .map_err(|e| CsmError::Other(format!("corrupt row: {e}")))?,
+        update_trigger: UpdateTrigger::from_str(&update_trigger_raw)
+            .map_err(|e| CsmError::Other(format!("corrupt row: {e}")))?,
+        archived: row.get("archived"),
+        archived_at: archived_at_raw.map(|s| parse_timestamp(&s)).transpose()?,
+        last_known_ref: row.get("last_known_ref"),
+        notes: row.get("notes"),
+        tags: serde_json::from_str(&tags_json).unwrap_or_default(),
+        failure_count: row.get("failure_count"),
+        last_failure_at: last_failure_at_raw.map(|s| parse_timestamp(&s)).transpose()?,
+        created_at: parse_timestamp(&created_at_raw)?,
+        updated_at: parse_timestamp(&updated_at_raw)?,
+    })
+}
+
+#[async_trait]
+impl SkillRepository for SqliteSkillRepository {
+    async fn create(&self, skill: Skill) -> Result<Skill> {
+        let id = insert_skill(&self.pool, &skill).await?;
+        Ok(Skill { id, ..skill })
+    }
+
+    async fn find_by_name(&self, name: &str, scope: SkillScope) -> Result<Option<Skill>> {
+        let row = sqlx::query("SELECT * FROM skills WHERE name = ? AND scope = ?")
+            .bind(name)
+            .bind(scope.to_string())
+            .fetch_optional(&self.pool)
+            .await?;
+        row.as_ref().map(row_to_skill).transpose()
+    }
+
+    async fn list(&self) -> Result<Vec<Skill>> {
+        sqlx::query("SELECT * FROM skills")
+            .fetch_all(&self.pool)
+            .await?
+            .iter()
+            .map(row_to_skill)
+            .collect()
+    }
+
+    async fn update(&self, skill: Skill) -> Result<Skill> {
+        let source_json = serde_json::to_string(&skill.source)?;
+        let tags_json = serde_json::to_string(&skill.tags)?;
+
+        sqlx::query(UPDATE_SKILL)
+            .bind(&skill.name)
+            .bind(&source_json)
+            .bind(skill.scope.to_string())
+            .bind(&skill.content)
+            .bind(&skill.content_hash)
+            .bind(&skill.previous_content)
+            .bind(skill.enabled)
+            .bind(skill.priority)
+            .bind(skill.update_mode.to_string())
+            .bind(skill.update_trigger.to_string())
+            .bind(skill.archived)
+            .bind(skill.archived_at.map(|d| d.to_rfc3339()))
+            .bind(&skill.last_known_ref)
+            .bind(&skill.notes)
+            .bind(&tags_json)
+            .bind(skill.failure_count)
+            .bind(skill.last_failure_at.map(|d| d.to_rfc3339()))
+            .bind(skill.updated_at.to_rfc3339())
+            .bind(skill.id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(skill)
+    }
+
+    async fn delete(&self, id: i64) -> Result<()> {
+        sqlx::query("DELETE FROM skills WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn bulk_create(&self, skills: Vec<Skill>) -> Result<Vec<Skill>> {
+        // A single transaction beats one round trip per skill for large imports.
+        let mut tx = self.pool.begin().await?;
+        let mut created = Vec::with_capacity(skills.len());
+        for skill in skills {
+            let id = insert_skill(&mut *tx, &skill).await?;
+            created.push(Skill { id, ..skill });
+        }
+        tx.commit().await?;
+        Ok(created)
+    }
+
+    /// Reads the raw `scope` column directly rather than going through
+    /// `list`/`row_to_skill`, since a row with an unparseable `scope` would
+    /// make `row_to_skill` fail the whole query instead of reporting just
+    /// that row. `source` isn't checked: unlike `scope`, it has no plain-text
+    /// encoding defined yet for what `SkillSource`'s richer, per-variant
+    /// fields would round-trip through.
+    async fn find_integrity_issues(&self) -> Result<Vec<String>> {
+        let rows = sqlx::query("SELECT id, scope FROM skills")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                let id: i64 = row.get("id");
+                let scope: String = row.get("scope");
+                SkillScope::from_str(&scope)
+                    .err()
+                    .map(|_| format!("skill id {id}: invalid scope '{scope}'"))
+            })
+            .collect())
+    }
+
+    /// Overrides the default `list()`-then-filter with a real `skills_fts`
+    /// query (see `repository::migrations`, versions 5-9), so content search
+    /// scales with the index instead of a full table scan.
+    async fn search_content_only(&self, query: &str) -> Result<Vec<Skill>> {
+        let rows = sqlx::query(
+            "SELECT skills.* FROM skills
+             JOIN skills_fts ON skills_fts.rowid = skills.id
+             WHERE skills_fts MATCH ?
+             ORDER BY rank",
+        )
+        .bind(fts5_match_literal(query))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_skill).collect()
+    }
+}
+
+/// Wraps `query` as a single FTS5 string literal so the caller's text is
+/// always matched literally, never parsed as FTS5 query syntax (column
+/// filters, `AND`/`OR`/`NOT`, `*` prefix matching, `NEAR`, etc.) — the FTS5
+/// analogue of escaping `%`/`_` before a `LIKE`. A double quote inside
+/// `query` is doubled, which is how FTS5 escapes a quote inside a phrase.
+fn fts5_match_literal(query: &str) -> String {
+    format!("\"{}\"", query.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SkillSource;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn repository() -> SqliteSkillRepository {
+        let pool = SqlitePoolOptions::new()
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let repository = SqliteSkillRepository::new(pool);
+        repository.run_migrations().await.unwrap();
+        repository
+    }
+
+    fn skill(name: &str, scope: SkillScope) -> Skill {
+        let now = Utc::now();
+        Skill {
+            id: 0,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: vec!["a".to_string(), "b".to_string()],
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_then_find_by_name_round_trips_every_field() {
+        let repository = repository().await;
+        let created = repository.create(skill("deploy", SkillScope::Global)).await.unwrap();
+        assert_ne!(created.id, 0, "sqlite should assign a real row id");
+
+        let found = repository
+            .find_by_name("deploy", SkillScope::Global)
+            .await
+            .unwrap()
+            .expect("just-created skill should be findable");
+
+        assert_eq!(found.id, created.id);
+        assert_eq!(found.name, "deploy");
+        assert_eq!(found.source, SkillSource::Inline);
+        assert_eq!(found.tags, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn find_by_name_is_scoped_and_returns_none_when_missing() {
+        let repository = repository().await;
+        repository.create(skill("deploy", SkillScope::Global)).await.unwrap();
+
+        assert!(repository.find_by_name("deploy", SkillScope::Project).await.unwrap().is_none());
+        assert!(repository.find_by_name("missing", SkillScope::Global).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn list_returns_every_created_skill() {
+        let repository = repository().await;
+        repository.create(skill("a", SkillScope::Global)).await.unwrap();
+        repository.create(skill("b", SkillScope::Project)).await.unwrap();
+
+        let mut names: Vec<String> = repository.list().await.unwrap().into_iter().map(|s| s.name).collect();
+        names.sort();
+        assert_eq!(names, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn update_persists_changed_fields() {
+        let repository = repository().await;
+        let mut created = repository.create(skill("deploy", SkillScope::Global)).await.unwrap();
+        created.content = "new content".to_string();
+        created.enabled = false;
+
+        repository.update(created.clone()).await.unwrap();
+
+        let found = repository.find_by_name("deploy", SkillScope::Global).await.unwrap().unwrap();
+        assert_eq!(found.content, "new content");
+        assert!(!found.enabled);
+    }
+
+    #[tokio::test]
+    async fn delete_removes_the_row() {
+        let repository = repository().await;
+        let created = repository.create(skill("deploy", SkillScope::Global)).await.unwrap();
+
+        repository.delete(created.id).await.unwrap();
+
+        assert!(repository.find_by_name("deploy", SkillScope::Global).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn bulk_create_inserts_every_skill_in_one_transaction() {
+        let repository = repository().await;
+
+        let created = repository
+            .bulk_create(vec![skill("a", SkillScope::Global), skill("b", SkillScope::Global)])
+            .await
+            .unwrap();
+
+        assert_eq!(created.len(), 2);
+        assert_ne!(created[0].id, created[1].id);
+        assert_eq!(repository.list().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn search_content_only_finds_a_skill_by_a_word_present_only_in_its_body() {
+        let repository = repository().await;
+        let mut deploy = skill("deploy", SkillScope::Global);
+        deploy.content = "run the deploy-fleet script before merging".to_string();
+        repository.create(deploy).await.unwrap();
+        let mut unrelated = skill("unrelated", SkillScope::Global);
+        unrelated.content = "nothing interesting here".to_string();
+        repository.create(unrelated).await.unwrap();
+
+        let results = repository.search_content_only("deploy-fleet").await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "deploy");
+    }
+
+    #[tokio::test]
+    async fn search_content_only_reflects_updates_and_deletes() {
+        let repository = repository().await;
+        let mut created = repository.create(skill("deploy", SkillScope::Global)).await.unwrap();
+        created.content = "renamed to launch-fleet now".to_string();
+        repository.update(created.clone()).await.unwrap();
+
+        assert!(repository.search_content_only("content").await.unwrap().is_empty());
+        assert_eq!(repository.search_content_only("launch-fleet").await.unwrap().len(), 1);
+
+        repository.delete(created.id).await.unwrap();
+        assert!(repository.search_content_only("launch-fleet").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn search_content_only_treats_special_characters_literally() {
+        let repository = repository().await;
+        let mut discount = skill("discount", SkillScope::Global);
+        discount.content = "up to 100% off this week".to_string();
+        repository.create(discount).await.unwrap();
+        let mut other = skill("other", SkillScope::Global);
+        other.content = "100x off this week, \"quoted\" too".to_string();
+        repository.create(other).await.unwrap();
+
+        let results = repository.search_content_only("100%").await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "discount");
+
+        // A literal double quote in the query must not break FTS5 syntax.
+        let results = repository.search_content_only("\"quoted\"").await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "other");
+    }
+
+    #[tokio::test]
+    async fn search_content_only_matches_are_scoped_to_the_query_not_wildcarded() {
+        // `_` and `*` are SQL `LIKE`/FTS5 wildcards respectively; a query
+        // containing them should not match unrelated skills the way an
+        // unescaped `LIKE '%_%'` or `content MATCH *` would.
+        let repository = repository().await;
+        let mut underscore = skill("underscore-skill", SkillScope::Global);
+        underscore.content = "config_value set here".to_string();
+        repository.create(underscore).await.unwrap();
+        let mut other = skill("other", SkillScope::Global);
+        other.content = "configXvalue set here".to_string();
+        repository.create(other).await.unwrap();
+
+        let results = repository.search_content_only("config_value").await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "underscore-skill");
+    }
+
+    #[tokio::test]
+    async fn a_row_with_an_unparseable_scope_is_reported_as_an_integrity_issue() {
+        let repository = repository().await;
+
+        sqlx::query(
+            "INSERT INTO skills (name, source, scope, content, content_hash, update_mode, update_trigger, created_at, updated_at)
+             VALUES ('corrupt', 'inline', 'sideways', 'content', 'hash', 'auto', 'manual', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z')",
+        )
+        .execute(&repository.pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO skills (name, source, scope, content, content_hash, update_mode, update_trigger, created_at, updated_at)
+             VALUES ('fine', 'inline', 'global', 'content', 'hash', 'auto', 'manual', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z')",
+        )
+        .execute(&repository.pool)
+        .await
+        .unwrap();
+
+        let issues = repository.find_integrity_issues().await.unwrap();
+
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].contains("invalid scope 'sideways'"));
+    }
+
+    #[tokio::test]
+    async fn run_migrations_settles_on_the_version_doctor_reports() {
+        // `doctor` prints `repository::migrations::current_schema_version()`
+        // as the schema version it expects; this pins that value to what
+        // actually ran against a real database through this repository's
+        // own `run_migrations` wrapper, not just the runner in isolation.
+        let repository = repository().await;
+        let version = repository.run_migrations().await.unwrap();
+        assert_eq!(version, crate::repository::migrations::current_schema_version());
+    }
+
+    #[tokio::test]
+    async fn configured_cache_size_pragma_is_applied() {
+        let repository = repository().await;
+
+        repository
+            .apply_pragmas(&[("cache_size".to_string(), "-20000".to_string())])
+            .await
+            .unwrap();
+
+        let row = sqlx::query("PRAGMA cache_size")
+            .fetch_one(&repository.pool)
+            .await
+            .unwrap();
+        let applied: i64 = row.get(0);
+        assert_eq!(applied, -20000);
+    }
+}
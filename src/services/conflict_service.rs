@@ -0,0 +1,680 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::error::Result;
+use crate::models::{Conflict, ConflictStatus, Skill};
+use crate::repository::{ConflictRepository, SkillRepository};
+
+/// Word pairs whose simultaneous presence across two skills' content is
+/// treated as a likely contradiction.
+const CONTRADICTION_PAIRS: &[(&str, &str)] = &[
+    ("always", "never"),
+    ("enable", "disable"),
+    ("must", "must not"),
+];
+
+/// Lines of surrounding context to capture on either side of the line
+/// containing a contradicting word, so `conflicts` output shows the
+/// offending instruction in context rather than a bare word match.
+const SNIPPET_CONTEXT_LINES: usize = 1;
+
+/// Severity for a direct word contradiction, `contradicts`' only conflict
+/// kind today. Scaled up by `overlap_bonus` so two skills that mostly
+/// restate each other outrank two that merely share one contradicting line.
+const CONTRADICTION_BASE_SEVERITY: u8 = 180;
+
+/// Upper bound on `overlap_bonus`'s contribution, keeping severity in `u8`.
+const MAX_OVERLAP_BONUS: u8 = 75;
+
+/// Word-overlap ratio between `a` and `b`: the Jaccard similarity of their
+/// whitespace-tokenized, lowercased words, in `0.0..=1.0`. Used to scale a
+/// conflict's severity — two skills that mostly restate each other are a
+/// more urgent contradiction than two that merely share one clashing line.
+fn same_topic(a: &str, b: &str) -> f64 {
+    let words_a: std::collections::HashSet<String> =
+        a.split_whitespace().map(str::to_lowercase).collect();
+    let words_b: std::collections::HashSet<String> =
+        b.split_whitespace().map(str::to_lowercase).collect();
+    if words_a.is_empty() || words_b.is_empty() {
+        return 0.0;
+    }
+    let intersection = words_a.intersection(&words_b).count();
+    let union = words_a.union(&words_b).count();
+    intersection as f64 / union as f64
+}
+
+/// `CONTRADICTION_BASE_SEVERITY` plus a bonus proportional to `overlap_ratio`.
+fn contradiction_severity(overlap_ratio: f64) -> u8 {
+    let bonus = (overlap_ratio * MAX_OVERLAP_BONUS as f64).round() as u8;
+    CONTRADICTION_BASE_SEVERITY.saturating_add(bonus)
+}
+
+/// Removes fenced code blocks (delimited by matching ``` or ~~~ lines) and
+/// inline code spans from `content` before contradiction scanning, so
+/// example code showing both sides of a convention doesn't register as a
+/// real contradiction between skills.
+fn strip_code_blocks(content: &str) -> String {
+    let mut out = String::new();
+    let mut fence: Option<&str> = None;
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            continue;
+        }
+        if trimmed.starts_with("```") {
+            fence = Some("```");
+            continue;
+        }
+        if trimmed.starts_with("~~~") {
+            fence = Some("~~~");
+            continue;
+        }
+        out.push_str(&strip_inline_code(line));
+        out.push('\n');
+    }
+    out
+}
+
+/// Drops the contents of any `` `...` `` inline code span on `line`, so a
+/// word mentioned only as example code doesn't count as a real instruction.
+fn strip_inline_code(line: &str) -> String {
+    let mut out = String::new();
+    let mut in_code = false;
+    for ch in line.chars() {
+        if ch == '`' {
+            in_code = !in_code;
+            continue;
+        }
+        if !in_code {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+/// Returns the line containing `needle` in `content`, padded with up to
+/// `context_lines` of surrounding lines on each side, joined back with
+/// newlines, along with the 1-indexed line number of the match. Falls back
+/// to `(content, 0)` if `needle` isn't found on any single line (e.g. it
+/// spans a line break).
+fn snippet_with_context(content: &str, needle: &str, context_lines: usize) -> (String, usize) {
+    let lines: Vec<&str> = content.lines().collect();
+    let Some(hit) = lines.iter().position(|line| line.contains(needle)) else {
+        return (content.to_string(), 0);
+    };
+    let start = hit.saturating_sub(context_lines);
+    let end = (hit + context_lines + 1).min(lines.len());
+    (lines[start..end].join("\n"), hit + 1)
+}
+
+#[async_trait]
+pub trait ConflictService: Send + Sync {
+    /// Re-scans all currently enabled skills for contradictions, creating
+    /// new `Unresolved` conflicts and reopening ones whose resolution no
+    /// longer applies.
+    async fn detect(&self) -> Result<Vec<Conflict>>;
+
+    /// All conflicts (any status) where `skill_id` is one of the two sides.
+    async fn conflicts_for_skill(&self, skill_id: i64) -> Result<Vec<Conflict>>;
+
+    /// Inserts `conflict` verbatim, bypassing `detect`'s re-scan. Used to
+    /// replay a previously detected conflict, e.g. when restoring a
+    /// `csm import --full` backup.
+    async fn restore(&self, conflict: Conflict) -> Result<Conflict>;
+
+    /// Marks `conflict_id` as `Ignored` and durably whitelists its skill
+    /// pair, so a later `detect` never resurfaces it even after dedup would
+    /// otherwise have recreated it. Use `clear_whitelist` to undo.
+    async fn ignore(&self, conflict_id: i64) -> Result<Conflict>;
+
+    /// Drops every whitelisted pair recorded by `ignore`, so `detect` will
+    /// surface them again if they still contradict.
+    async fn clear_whitelist(&self) -> Result<()>;
+}
+
+pub struct ConflictServiceImpl {
+    skills: Arc<dyn SkillRepository>,
+    conflicts: Arc<dyn ConflictRepository>,
+    contradiction_pairs: Vec<(String, String)>,
+}
+
+impl ConflictServiceImpl {
+    pub fn new(skills: Arc<dyn SkillRepository>, conflicts: Arc<dyn ConflictRepository>) -> Self {
+        Self {
+            skills,
+            conflicts,
+            contradiction_pairs: CONTRADICTION_PAIRS
+                .iter()
+                .map(|(word, opposite)| (word.to_string(), opposite.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Sets `[conflicts] contradiction_pairs`, a list of `"word|opposite"`
+    /// strings appended to the built-in pairs. Malformed entries (missing
+    /// the `|` separator) are skipped rather than rejected, since a typo in
+    /// one entry shouldn't block `detect` from running at all.
+    pub fn with_contradiction_pairs(mut self, pairs: &[String]) -> Self {
+        for pair in pairs {
+            if let Some((word, opposite)) = pair.split_once('|') {
+                self.contradiction_pairs
+                    .push((word.trim().to_string(), opposite.trim().to_string()));
+            }
+        }
+        self
+    }
+
+    async fn resolve_pair(
+        &self,
+        lo: i64,
+        hi: i64,
+        description: String,
+        severity: u8,
+    ) -> Result<Conflict> {
+        match self.conflicts.find_by_pair(lo, hi).await? {
+            // Both sides are enabled, so a `ResolvedDisableA/B` row no
+            // longer reflects reality: it must have been re-enabled since.
+            // Reopen it rather than treat the stale resolution as still
+            // closing the pair.
+            Some(existing)
+                if matches!(
+                    existing.status,
+                    ConflictStatus::ResolvedDisableA | ConflictStatus::ResolvedDisableB
+                ) =>
+            {
+                let mut reopened = existing;
+                reopened.status = ConflictStatus::Unresolved;
+                reopened.description = description;
+                reopened.severity = severity;
+                reopened.detected_at = Utc::now();
+                self.conflicts.update(reopened).await
+            }
+            Some(existing) => Ok(existing),
+            None => {
+                let conflict = Conflict {
+                    id: 0,
+                    skill_a_id: lo,
+                    skill_b_id: hi,
+                    description,
+                    status: ConflictStatus::Unresolved,
+                    detected_at: Utc::now(),
+                    severity,
+                };
+                self.conflicts.create(conflict).await
+            }
+        }
+    }
+
+    fn contradicts(&self, a: &Skill, b: &Skill) -> Option<(String, u8)> {
+        let content_a = strip_code_blocks(&a.content);
+        let content_b = strip_code_blocks(&b.content);
+        let severity = contradiction_severity(same_topic(&content_a, &content_b));
+        for (word, opposite) in &self.contradiction_pairs {
+            if content_a.contains(word.as_str()) && content_b.contains(opposite.as_str()) {
+                return Some((
+                    Self::describe(word, &a.name, &content_a, opposite, &b.name, &content_b),
+                    severity,
+                ));
+            }
+            if content_b.contains(word.as_str()) && content_a.contains(opposite.as_str()) {
+                return Some((
+                    Self::describe(word, &b.name, &content_b, opposite, &a.name, &content_a),
+                    severity,
+                ));
+            }
+        }
+        None
+    }
+
+    fn describe(
+        word: &str,
+        name_a: &str,
+        content_a: &str,
+        opposite: &str,
+        name_b: &str,
+        content_b: &str,
+    ) -> String {
+        let (snippet_a, line_a) = snippet_with_context(content_a, word, SNIPPET_CONTEXT_LINES);
+        let (snippet_b, line_b) = snippet_with_context(content_b, opposite, SNIPPET_CONTEXT_LINES);
+        format!(
+            "'{word}' in {name_a} (line {line_a}):\n{snippet_a}\nvs '{opposite}' in {name_b} (line {line_b}):\n{snippet_b}"
+        )
+    }
+}
+
+#[async_trait]
+impl ConflictService for ConflictServiceImpl {
+    async fn detect(&self) -> Result<Vec<Conflict>> {
+        let enabled: Vec<Skill> = self
+            .skills
+            .list()
+            .await?
+            .into_iter()
+            .filter(|s| s.enabled)
+            .collect();
+
+        // The O(n^2) content scan is pure CPU and stays sequential; only the
+        // per-pair repository round trips are worth farming out concurrently.
+        let mut contradicting_pairs = Vec::new();
+        for i in 0..enabled.len() {
+            for j in (i + 1)..enabled.len() {
+                let (a, b) = (&enabled[i], &enabled[j]);
+                if let Some((description, severity)) = self.contradicts(a, b) {
+                    let (lo, hi) = if a.id < b.id { (a.id, b.id) } else { (b.id, a.id) };
+                    contradicting_pairs.push((lo, hi, description, severity));
+                }
+            }
+        }
+
+        // Whitelisted pairs (from a prior `ignore`) are dropped before any
+        // conflict row is created or reopened for them, so they never
+        // resurface, even across a dedup reset.
+        let mut not_whitelisted = Vec::with_capacity(contradicting_pairs.len());
+        for (lo, hi, description, severity) in contradicting_pairs {
+            if !self.conflicts.is_whitelisted(lo, hi).await? {
+                not_whitelisted.push((lo, hi, description, severity));
+            }
+        }
+        let contradicting_pairs = not_whitelisted;
+
+        let still_contradicting: std::collections::HashSet<(i64, i64)> = contradicting_pairs
+            .iter()
+            .map(|(lo, hi, _, _)| (*lo, *hi))
+            .collect();
+
+        let mut found: Vec<Conflict> = futures::future::try_join_all(
+            contradicting_pairs
+                .into_iter()
+                .map(|(lo, hi, description, severity)| self.resolve_pair(lo, hi, description, severity)),
+        )
+        .await?;
+        found.sort_by(|a, b| b.severity.cmp(&a.severity));
+
+        // Anything still Unresolved but no longer reproducing must have had
+        // its content edited since detection; keep the history but stop
+        // surfacing it as active.
+        for conflict in self.conflicts.list().await? {
+            if conflict.status == ConflictStatus::Unresolved
+                && !still_contradicting.contains(&(conflict.skill_a_id, conflict.skill_b_id))
+            {
+                let mut stale = conflict;
+                stale.status = ConflictStatus::Stale;
+                self.conflicts.update(stale).await?;
+            }
+        }
+
+        Ok(found)
+    }
+
+    async fn conflicts_for_skill(&self, skill_id: i64) -> Result<Vec<Conflict>> {
+        Ok(self
+            .conflicts
+            .list()
+            .await?
+            .into_iter()
+            .filter(|c| c.skill_a_id == skill_id || c.skill_b_id == skill_id)
+            .collect())
+    }
+
+    async fn restore(&self, conflict: Conflict) -> Result<Conflict> {
+        self.conflicts.create(conflict).await
+    }
+
+    async fn ignore(&self, conflict_id: i64) -> Result<Conflict> {
+        let conflict = self
+            .conflicts
+            .list()
+            .await?
+            .into_iter()
+            .find(|c| c.id == conflict_id)
+            .ok_or_else(|| crate::error::CsmError::NotFound(format!("conflict #{conflict_id}")))?;
+
+        self.conflicts
+            .whitelist_pair(conflict.skill_a_id, conflict.skill_b_id)
+            .await?;
+
+        let mut ignored = conflict;
+        ignored.status = ConflictStatus::Ignored;
+        self.conflicts.update(ignored).await
+    }
+
+    async fn clear_whitelist(&self) -> Result<()> {
+        self.conflicts.clear_whitelist().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SkillScope, SkillSource, UpdateMode, UpdateTrigger};
+    use std::sync::Mutex;
+
+    struct FakeSkills(Mutex<Vec<Skill>>);
+
+    #[async_trait]
+    impl SkillRepository for FakeSkills {
+        async fn create(&self, skill: Skill) -> Result<Skill> {
+            self.0.lock().unwrap().push(skill.clone());
+            Ok(skill)
+        }
+        async fn find_by_name(&self, name: &str, scope: SkillScope) -> Result<Option<Skill>> {
+            Ok(self
+                .0
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|s| s.name == name && s.scope == scope)
+                .cloned())
+        }
+        async fn list(&self) -> Result<Vec<Skill>> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+        async fn update(&self, skill: Skill) -> Result<Skill> {
+            let mut skills = self.0.lock().unwrap();
+            if let Some(existing) = skills.iter_mut().find(|s| s.id == skill.id) {
+                *existing = skill.clone();
+            }
+            Ok(skill)
+        }
+        async fn delete(&self, _id: i64) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    struct FakeConflicts(Mutex<Vec<Conflict>>, Mutex<std::collections::HashSet<(i64, i64)>>);
+
+    impl FakeConflicts {
+        fn new(conflicts: Vec<Conflict>) -> Self {
+            Self(Mutex::new(conflicts), Mutex::new(std::collections::HashSet::new()))
+        }
+    }
+
+    #[async_trait]
+    impl ConflictRepository for FakeConflicts {
+        async fn create(&self, mut conflict: Conflict) -> Result<Conflict> {
+            let mut conflicts = self.0.lock().unwrap();
+            conflict.id = conflicts.len() as i64 + 1;
+            conflicts.push(conflict.clone());
+            Ok(conflict)
+        }
+        async fn list(&self) -> Result<Vec<Conflict>> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+        async fn find_by_pair(&self, a: i64, b: i64) -> Result<Option<Conflict>> {
+            Ok(self
+                .0
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|c| c.skill_a_id == a && c.skill_b_id == b)
+                .cloned())
+        }
+        async fn update(&self, conflict: Conflict) -> Result<Conflict> {
+            let mut conflicts = self.0.lock().unwrap();
+            if let Some(existing) = conflicts.iter_mut().find(|c| c.id == conflict.id) {
+                *existing = conflict.clone();
+            }
+            Ok(conflict)
+        }
+        async fn whitelist_pair(&self, skill_a_id: i64, skill_b_id: i64) -> Result<()> {
+            self.1.lock().unwrap().insert((skill_a_id, skill_b_id));
+            Ok(())
+        }
+        async fn is_whitelisted(&self, skill_a_id: i64, skill_b_id: i64) -> Result<bool> {
+            Ok(self.1.lock().unwrap().contains(&(skill_a_id, skill_b_id)))
+        }
+        async fn clear_whitelist(&self) -> Result<()> {
+            self.1.lock().unwrap().clear();
+            Ok(())
+        }
+    }
+
+    fn skill(id: i64, name: &str, content: &str, enabled: bool) -> Skill {
+        let now = Utc::now();
+        Skill {
+            id,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: content.to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn reopens_conflict_after_disabled_skill_is_re_enabled() {
+        let a = skill(1, "a", "always run tests", true);
+        let b = skill(2, "b", "never run tests", true);
+        let skills = Arc::new(FakeSkills(Mutex::new(vec![a.clone(), b.clone()])));
+        let conflicts = Arc::new(FakeConflicts::new(Vec::new()));
+        let service = ConflictServiceImpl::new(skills.clone(), conflicts.clone());
+
+        let first = service.detect().await.unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].status, ConflictStatus::Unresolved);
+
+        // Resolve by disabling B, as `resolve` would.
+        let mut disabled_b = b.clone();
+        disabled_b.enabled = false;
+        skills.0.lock().unwrap()[1] = disabled_b;
+        let mut resolved = conflicts.0.lock().unwrap()[0].clone();
+        resolved.status = ConflictStatus::ResolvedDisableB;
+        conflicts.update(resolved).await.unwrap();
+
+        // Re-detect with B still disabled: no pair to compare, conflict untouched.
+        let second = service.detect().await.unwrap();
+        assert!(second.is_empty());
+
+        // Re-enable B and re-detect: the stale resolution must not suppress it.
+        let mut re_enabled_b = b.clone();
+        re_enabled_b.enabled = true;
+        skills.0.lock().unwrap()[1] = re_enabled_b;
+
+        let third = service.detect().await.unwrap();
+        assert_eq!(third.len(), 1);
+        assert_eq!(third[0].status, ConflictStatus::Unresolved);
+    }
+
+    #[test]
+    fn a_conflict_between_near_identical_content_outranks_one_that_barely_overlaps() {
+        let high_overlap = contradiction_severity(same_topic(
+            "always run the full test suite before committing",
+            "never run the full test suite before committing",
+        ));
+        let low_overlap = contradiction_severity(same_topic("always be kind", "never say hello"));
+
+        assert!(
+            high_overlap > low_overlap,
+            "expected {high_overlap} > {low_overlap}"
+        );
+    }
+
+    #[tokio::test]
+    async fn conflicts_command_sorts_detected_conflicts_by_severity_descending() {
+        let low = skill(1, "low-a", "always be kind", true);
+        let low2 = skill(2, "low-b", "never say hello", true);
+        let high = skill(3, "high-a", "always run the full test suite before committing", true);
+        let high2 = skill(4, "high-b", "never run the full test suite before committing", true);
+        let skills = Arc::new(FakeSkills(Mutex::new(vec![low, low2, high, high2])));
+        let conflicts = Arc::new(FakeConflicts::new(Vec::new()));
+        let service = ConflictServiceImpl::new(skills, conflicts);
+
+        let found = service.detect().await.unwrap();
+
+        // Each "always" skill contradicts each "never" skill, so the two
+        // cross pairs are detected as well: four conflicts in all.
+        assert_eq!(found.len(), 4);
+        assert!(found[0].severity > found[1].severity);
+    }
+
+    #[test]
+    fn snippet_captures_neighboring_lines_around_the_matched_word() {
+        let content = "setup\nalways run tests\nteardown";
+        let (snippet, line) = snippet_with_context(content, "always", SNIPPET_CONTEXT_LINES);
+        assert_eq!(snippet, "setup\nalways run tests\nteardown");
+        assert_eq!(line, 2);
+    }
+
+    #[tokio::test]
+    async fn detected_conflict_description_includes_surrounding_lines() {
+        let a = skill(1, "a", "setup\nalways run tests\nteardown", true);
+        let b = skill(2, "b", "prep\nnever run tests\ncleanup", true);
+        let skills = Arc::new(FakeSkills(Mutex::new(vec![a, b])));
+        let conflicts = Arc::new(FakeConflicts::new(Vec::new()));
+        let service = ConflictServiceImpl::new(skills, conflicts);
+
+        let found = service.detect().await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert!(found[0].description.contains("setup"));
+        assert!(found[0].description.contains("teardown"));
+        assert!(found[0].description.contains("prep"));
+        assert!(found[0].description.contains("cleanup"));
+        assert!(found[0].description.contains("(line 2)"));
+    }
+
+    #[tokio::test]
+    async fn detecting_the_same_pair_twice_does_not_create_a_duplicate_conflict() {
+        let a = skill(1, "a", "always run tests", true);
+        let b = skill(2, "b", "never run tests", true);
+        let skills = Arc::new(FakeSkills(Mutex::new(vec![a, b])));
+        let conflicts = Arc::new(FakeConflicts::new(Vec::new()));
+        let service = ConflictServiceImpl::new(skills, conflicts.clone());
+
+        let first = service.detect().await.unwrap();
+        let second = service.detect().await.unwrap();
+
+        assert_eq!(first.len(), 1);
+        assert_eq!(second.len(), 1);
+        assert_eq!(first[0].id, second[0].id);
+        assert_eq!(conflicts.0.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn ignored_conflict_is_not_recreated_by_a_later_detect() {
+        let a = skill(1, "a", "always run tests", true);
+        let b = skill(2, "b", "never run tests", true);
+        let skills = Arc::new(FakeSkills(Mutex::new(vec![a, b])));
+        let conflicts = Arc::new(FakeConflicts::new(Vec::new()));
+        let service = ConflictServiceImpl::new(skills, conflicts.clone());
+
+        service.detect().await.unwrap();
+        let mut ignored = conflicts.0.lock().unwrap()[0].clone();
+        ignored.status = ConflictStatus::Ignored;
+        conflicts.update(ignored).await.unwrap();
+
+        let found = service.detect().await.unwrap();
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].status, ConflictStatus::Ignored);
+        assert_eq!(conflicts.0.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn ignoring_a_conflict_whitelists_its_pair_so_detect_never_recreates_it() {
+        let a = skill(1, "a", "always run tests", true);
+        let b = skill(2, "b", "never run tests", true);
+        let skills = Arc::new(FakeSkills(Mutex::new(vec![a, b])));
+        let conflicts = Arc::new(FakeConflicts::new(Vec::new()));
+        let service = ConflictServiceImpl::new(skills, conflicts.clone());
+
+        let first = service.detect().await.unwrap();
+        assert_eq!(first.len(), 1);
+
+        let ignored = service.ignore(first[0].id).await.unwrap();
+        assert_eq!(ignored.status, ConflictStatus::Ignored);
+
+        // Unlike a manually-set `Ignored` status, `ignore` also whitelists
+        // the pair, so a re-detect must not mark it `Stale` or otherwise
+        // resurface it as `Unresolved`.
+        let second = service.detect().await.unwrap();
+        assert!(second.is_empty());
+        assert_eq!(conflicts.0.lock().unwrap()[0].status, ConflictStatus::Ignored);
+    }
+
+    #[tokio::test]
+    async fn marks_conflict_stale_once_content_no_longer_contradicts() {
+        let a = skill(1, "a", "always run tests", true);
+        let b = skill(2, "b", "never run tests", true);
+        let skills = Arc::new(FakeSkills(Mutex::new(vec![a.clone(), b.clone()])));
+        let conflicts = Arc::new(FakeConflicts::new(Vec::new()));
+        let service = ConflictServiceImpl::new(skills.clone(), conflicts.clone());
+
+        let first = service.detect().await.unwrap();
+        assert_eq!(first[0].status, ConflictStatus::Unresolved);
+
+        let mut edited_b = b.clone();
+        edited_b.content = "run tests occasionally".to_string();
+        skills.0.lock().unwrap()[1] = edited_b;
+
+        let second = service.detect().await.unwrap();
+        assert!(second.is_empty());
+        assert_eq!(conflicts.0.lock().unwrap()[0].status, ConflictStatus::Stale);
+    }
+
+    #[tokio::test]
+    async fn custom_contradiction_pair_detects_a_conflict_the_defaults_would_miss() {
+        let a = skill(1, "a", "prefer tabs for indentation", true);
+        let b = skill(2, "b", "prefer spaces for indentation", true);
+        let skills = Arc::new(FakeSkills(Mutex::new(vec![a, b])));
+        let conflicts = Arc::new(FakeConflicts::new(Vec::new()));
+        let service = ConflictServiceImpl::new(skills, conflicts)
+            .with_contradiction_pairs(&["tabs|spaces".to_string()]);
+
+        let found = service.detect().await.unwrap();
+
+        assert_eq!(found.len(), 1);
+        assert!(found[0].description.contains("tabs"));
+        assert!(found[0].description.contains("spaces"));
+    }
+
+    #[tokio::test]
+    async fn a_shared_bulleted_list_inside_a_code_block_is_not_reported_as_a_conflict() {
+        let example = "```\n- always run tests\n- never skip lint\n```\n";
+        let a = skill(1, "a", example, true);
+        let b = skill(2, "b", example, true);
+        let skills = Arc::new(FakeSkills(Mutex::new(vec![a, b])));
+        let conflicts = Arc::new(FakeConflicts::new(Vec::new()));
+        let service = ConflictServiceImpl::new(skills, conflicts);
+
+        let found = service.detect().await.unwrap();
+
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn strip_code_blocks_removes_fenced_content_but_keeps_surrounding_lines() {
+        let content = "intro\n```\nalways do the opposite of what you'd expect\n```\noutro";
+        let stripped = strip_code_blocks(content);
+
+        assert!(stripped.contains("intro"));
+        assert!(stripped.contains("outro"));
+        assert!(!stripped.contains("always do the opposite"));
+    }
+
+    #[test]
+    fn strip_inline_code_drops_only_the_backtick_span() {
+        let stripped = strip_inline_code("run `always-on` mode, never manual");
+
+        assert!(stripped.contains("never manual"));
+        assert!(!stripped.contains("always-on"));
+    }
+}
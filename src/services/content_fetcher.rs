@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::error::Result;
+use crate::models::SkillSource;
+
+/// Resolves a `SkillSource` into raw markdown content, regardless of origin.
+#[async_trait]
+pub trait ContentFetcher: Send + Sync {
+    async fn fetch_content(&self, source: &SkillSource) -> Result<String>;
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use crate::models::Skill;
+
+/// Groups skill names that currently share identical content, so `update`
+/// can fetch shared upstream content once and apply it to every skill in
+/// the group instead of re-fetching per skill.
+pub fn group_identical_content(skills: &[Skill]) -> Vec<Vec<String>> {
+    let mut by_hash: HashMap<&str, Vec<String>> = HashMap::new();
+    for skill in skills {
+        by_hash
+            .entry(skill.content_hash.as_str())
+            .or_default()
+            .push(skill.name.clone());
+    }
+
+    by_hash
+        .into_values()
+        .filter(|names| names.len() > 1)
+        .collect()
+}
+
+/// Groups skill names that track the same upstream source (via
+/// `SkillSource::same_target`, ignoring volatile fields like
+/// `Git::commit_sha`), so `update` can check it once per group instead of
+/// once per skill. `O(n^2)` in the number of skills, fine at `csm`'s scale.
+pub fn group_same_target_sources(skills: &[Skill]) -> Vec<Vec<String>> {
+    let mut groups: Vec<Vec<&Skill>> = Vec::new();
+
+    'skills: for skill in skills {
+        for group in &mut groups {
+            if group[0].source.same_target(&skill.source) {
+                group.push(skill);
+                continue 'skills;
+            }
+        }
+        groups.push(vec![skill]);
+    }
+
+    groups
+        .into_iter()
+        .filter(|group| group.len() > 1)
+        .map(|group| group.into_iter().map(|s| s.name.clone()).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SkillScope, SkillSource, UpdateMode, UpdateTrigger};
+
+    fn skill(name: &str, hash: &str) -> Skill {
+        skill_with_source(name, hash, SkillSource::Inline)
+    }
+
+    fn skill_with_source(name: &str, hash: &str, source: SkillSource) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 0,
+            name: name.to_string(),
+            source,
+            scope: SkillScope::Global,
+            content: String::new(),
+            content_hash: hash.to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::OnCommit,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn groups_only_hashes_shared_by_more_than_one_skill() {
+        let skills = vec![
+            skill("a", "hash1"),
+            skill("b", "hash1"),
+            skill("c", "hash2"),
+        ];
+
+        let groups = group_identical_content(&skills);
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].len(), 2);
+    }
+
+    fn git_source(commit_sha: Option<&str>) -> SkillSource {
+        SkillSource::Git {
+            url: "git@github.com:acme/skills.git".to_string(),
+            path: "SKILL.md".to_string(),
+            ref_spec: "main".to_string(),
+            commit_sha: commit_sha.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn groups_skills_tracking_the_same_git_target_despite_different_commit_shas() {
+        let skills = vec![
+            skill_with_source("a", "hash1", git_source(Some("deadbeef"))),
+            skill_with_source("b", "hash2", git_source(Some("f00dcafe"))),
+            skill_with_source("c", "hash3", SkillSource::Inline),
+        ];
+
+        let groups = group_same_target_sources(&skills);
+
+        assert_eq!(groups, vec![vec!["a".to_string(), "b".to_string()]]);
+    }
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use crate::error::{CsmError, Result};
+use crate::models::SkillSource;
+
+use super::content_fetcher::ContentFetcher;
+
+/// Default `ContentFetcher`, dispatching on the kind of `SkillSource`.
+pub struct DefaultContentFetcher;
+
+#[async_trait]
+impl ContentFetcher for DefaultContentFetcher {
+    async fn fetch_content(&self, source: &SkillSource) -> Result<String> {
+        match source {
+            SkillSource::Url(url) => {
+                let response = reqwest::get(url).await?;
+                Ok(response.text().await?)
+            }
+            SkillSource::Local(path) => Ok(std::fs::read_to_string(path)?),
+            SkillSource::Inline => Err(CsmError::InvalidSource(
+                "inline sources require add_with_content".to_string(),
+            )),
+            SkillSource::GitHub { .. } => Err(CsmError::Other(
+                "GitHub sources must be fetched via GitHubClient".to_string(),
+            )),
+            SkillSource::GitLab { .. } => Err(CsmError::Other(
+                "GitLab sources must be fetched via GitLabClient".to_string(),
+            )),
+            SkillSource::Git { .. } => Err(CsmError::Other(
+                "Git sources must be fetched via GitClient".to_string(),
+            )),
+        }
+    }
+}
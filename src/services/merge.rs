@@ -0,0 +1,572 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use crate::error::Result;
+use crate::models::Skill;
+
+use super::merge_service::{MergeService, RebuildSummary};
+
+/// Marker written in place of an enabled skill's body when its content is
+/// missing (empty), so the gap is visible in the merged file rather than
+/// silently shrinking it. `{name}` is substituted at render time.
+const MISSING_CONTENT_PLACEHOLDER: &str =
+    "<!-- csm:error skill=\"{name}\" content missing, run csm doctor -->";
+
+fn missing_content_placeholder(name: &str) -> String {
+    MISSING_CONTENT_PLACEHOLDER.replace("{name}", name)
+}
+
+/// `[merge] skill_header` used when the config doesn't set one.
+const DEFAULT_SKILL_HEADER: &str = "## {name}";
+
+/// Substitutes `{name}`, `{source}`, and `{priority}` in a `skill_header`
+/// template for `skill`.
+fn render_skill_header(template: &str, skill: &Skill) -> String {
+    template
+        .replace("{name}", &skill.name)
+        .replace("{source}", &skill.source.to_string())
+        .replace("{priority}", &skill.priority.to_string())
+}
+
+/// Appends a skill's content into the project's `CLAUDE.md`.
+pub struct ClaudeMdMergeService {
+    claude_md_path: PathBuf,
+    dedupe_sections: bool,
+    dedupe_lines: bool,
+    toc: bool,
+    header_text: Option<String>,
+    skill_header: Option<String>,
+}
+
+impl ClaudeMdMergeService {
+    pub fn new(claude_md_path: PathBuf) -> Self {
+        Self {
+            claude_md_path,
+            dedupe_sections: false,
+            dedupe_lines: false,
+            toc: false,
+            header_text: None,
+            skill_header: None,
+        }
+    }
+
+    /// Enables `[merge] dedupe_sections`: a `##`+ section that's
+    /// byte-identical (after trimming) to one already emitted by an earlier
+    /// skill is replaced with a pointer back to that skill instead of being
+    /// repeated in full.
+    pub fn with_dedupe_sections(mut self, dedupe_sections: bool) -> Self {
+        self.dedupe_sections = dedupe_sections;
+        self
+    }
+
+    /// Enables `[merge] dedupe_lines`: a non-heading, non-blank line
+    /// (e.g. a shared "Be concise" bullet) that's already appeared under a
+    /// higher-priority skill is dropped instead of repeated.
+    pub fn with_dedupe_lines(mut self, dedupe_lines: bool) -> Self {
+        self.dedupe_lines = dedupe_lines;
+        self
+    }
+
+    /// Enables `[merge] toc`: a table of contents linking to each enabled
+    /// skill's section heading is prepended after `header_text`.
+    pub fn with_toc(mut self, toc: bool) -> Self {
+        self.toc = toc;
+        self
+    }
+
+    /// Sets `[merge] header_text`, written at the top of the merged
+    /// `CLAUDE.md`, before any skill sections.
+    pub fn with_header_text(mut self, header_text: Option<String>) -> Self {
+        self.header_text = header_text;
+        self
+    }
+
+    /// Sets `[merge] skill_header`, the per-skill heading template rendered
+    /// by `render_merged_with_options`. Falls back to `DEFAULT_SKILL_HEADER`
+    /// when unset.
+    pub fn with_skill_header(mut self, skill_header: Option<String>) -> Self {
+        self.skill_header = skill_header;
+        self
+    }
+
+    /// Sibling of `claude_md_path` holding the pre-`rebuild` contents, e.g.
+    /// `CLAUDE.md.bak` for `CLAUDE.md`.
+    fn backup_path(&self) -> PathBuf {
+        let mut backup = self.claude_md_path.clone().into_os_string();
+        backup.push(".bak");
+        PathBuf::from(backup)
+    }
+}
+
+/// Renders what `rebuild` would write for `skills` (enabled ones only) with
+/// every `[merge]` option off and no `header_text`, without touching disk.
+/// Used by `doctor --dry-run`, which diffs this against the on-disk file to
+/// report drift without fixing it.
+pub fn render_merged(skills: &[Skill]) -> String {
+    render_merged_with_options(skills, false, None, false, false)
+}
+
+/// Same as `render_merged`, but with `dedupe_sections` deduplicating
+/// identical `##`+ sections across skills instead of repeating them,
+/// `skill_header` overriding the per-skill heading template (falls back to
+/// `DEFAULT_SKILL_HEADER` when `None`), `dedupe_lines` dropping any
+/// non-heading, non-blank line already emitted by a higher-priority skill,
+/// and `toc` prepending a table of contents linking to each skill's
+/// heading. Skills are emitted highest priority first, ties broken by
+/// their original order.
+pub fn render_merged_with_options(
+    skills: &[Skill],
+    dedupe_sections: bool,
+    skill_header: Option<&str>,
+    dedupe_lines: bool,
+    toc: bool,
+) -> String {
+    use std::fmt::Write;
+    let header_template = skill_header.unwrap_or(DEFAULT_SKILL_HEADER);
+    let mut rendered = String::new();
+    let mut toc_entries = Vec::new();
+    let mut seen_sections: HashMap<String, String> = HashMap::new();
+    let mut seen_lines: HashSet<String> = HashSet::new();
+
+    let mut enabled: Vec<&Skill> = skills.iter().filter(|s| s.enabled).collect();
+    enabled.sort_by(|a, b| b.priority.cmp(&a.priority));
+
+    for skill in enabled {
+        let header = render_skill_header(header_template, skill);
+        let title = header.trim_start_matches('#').trim();
+        toc_entries.push(format!("- [{title}](#{})", crate::utils::headings::github_slug(title)));
+
+        let _ = writeln!(rendered, "\n{header}\n");
+        if skill.content.trim().is_empty() {
+            let _ = writeln!(rendered, "{}", missing_content_placeholder(&skill.name));
+            continue;
+        }
+        let mut body = if dedupe_sections {
+            render_content_deduped(&skill.name, &skill.content, &mut seen_sections)
+        } else {
+            format!("{}\n", skill.content)
+        };
+        if dedupe_lines {
+            body = dedupe_content_lines(&body, &mut seen_lines);
+        }
+        rendered.push_str(&body);
+    }
+
+    if toc && !toc_entries.is_empty() {
+        let mut with_toc = String::from("## Table of Contents\n\n");
+        with_toc.push_str(&toc_entries.join("\n"));
+        with_toc.push('\n');
+        with_toc.push_str(&rendered);
+        return with_toc;
+    }
+
+    rendered
+}
+
+/// Splits `content` into blocks starting at each `##`-or-deeper heading (the
+/// text before the first such heading, if any, is its own leading block).
+fn split_sections(content: &str) -> Vec<String> {
+    let mut sections = Vec::new();
+    let mut current = String::new();
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        let is_section_heading = (2..=6).contains(&level) && !trimmed[level..].trim().is_empty();
+
+        if is_section_heading && !current.is_empty() {
+            sections.push(std::mem::take(&mut current));
+        }
+        current.push_str(line);
+        current.push('\n');
+    }
+    if !current.is_empty() {
+        sections.push(current);
+    }
+
+    sections
+}
+
+fn normalize_section(section: &str) -> String {
+    section.lines().map(str::trim).collect::<Vec<_>>().join("\n").trim().to_string()
+}
+
+/// Renders one skill's content, replacing any `##`+ section whose
+/// (normalized) text already appeared under an earlier skill with a note
+/// pointing back at that skill instead of repeating it.
+fn render_content_deduped(owner: &str, content: &str, seen: &mut HashMap<String, String>) -> String {
+    let mut rendered = String::new();
+
+    for section in split_sections(content) {
+        let is_heading_section = section.trim_start().starts_with('#');
+        if !is_heading_section {
+            rendered.push_str(&section);
+            continue;
+        }
+
+        let normalized = normalize_section(&section);
+        match seen.get(&normalized) {
+            Some(original_owner) => {
+                let title_line = section.lines().next().unwrap_or_default();
+                rendered.push_str(title_line);
+                rendered.push('\n');
+                rendered.push_str(&format!("\n_(same as in skill '{original_owner}')_\n\n"));
+            }
+            None => {
+                seen.insert(normalized, owner.to_string());
+                rendered.push_str(&section);
+            }
+        }
+    }
+
+    rendered
+}
+
+/// Drops any non-blank, non-heading line whose trimmed text is already in
+/// `seen` (recorded as it goes), so skills sharing boilerplate (e.g. a "Be
+/// concise" bullet) don't repeat it verbatim. Blank lines and `#` headings
+/// are always kept, since they carry structure rather than content.
+fn dedupe_content_lines(content: &str, seen: &mut HashSet<String>) -> String {
+    let mut kept = String::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            kept.push_str(line);
+            kept.push('\n');
+            continue;
+        }
+        if seen.insert(trimmed.to_string()) {
+            kept.push_str(line);
+            kept.push('\n');
+        }
+    }
+    kept
+}
+
+#[async_trait]
+impl MergeService for ClaudeMdMergeService {
+    async fn merge(&self, skill: &Skill) -> Result<()> {
+        if !skill.enabled {
+            return Ok(());
+        }
+        use std::io::Write;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.claude_md_path)?;
+        let body = if skill.content.trim().is_empty() {
+            missing_content_placeholder(&skill.name)
+        } else {
+            skill.content.clone()
+        };
+        let header = render_skill_header(
+            self.skill_header.as_deref().unwrap_or(DEFAULT_SKILL_HEADER),
+            skill,
+        );
+        writeln!(file, "\n{header}\n\n{body}")?;
+        Ok(())
+    }
+
+    async fn rebuild(&self, skills: &[Skill]) -> Result<RebuildSummary> {
+        let mut rendered = String::new();
+        if let Some(header_text) = &self.header_text {
+            rendered.push_str(header_text);
+            rendered.push('\n');
+        }
+        rendered.push_str(&render_merged_with_options(
+            skills,
+            self.dedupe_sections,
+            self.skill_header.as_deref(),
+            self.dedupe_lines,
+            self.toc,
+        ));
+
+        let summary = RebuildSummary {
+            skill_count: skills.iter().filter(|s| s.enabled).count(),
+            bytes: rendered.len(),
+        };
+
+        let existing = std::fs::read_to_string(&self.claude_md_path).ok();
+        if existing.as_deref() == Some(rendered.as_str()) {
+            // Nothing changed: skip the write (and the backup it'd trigger)
+            // so mtime-watching editors and file-watchers don't churn on a
+            // no-op merge.
+            return Ok(summary);
+        }
+        if let Some(existing) = existing {
+            std::fs::write(self.backup_path(), existing)?;
+        }
+
+        std::fs::write(&self.claude_md_path, rendered)?;
+        Ok(summary)
+    }
+
+    async fn restore_backup(&self) -> Result<()> {
+        let backup = std::fs::read_to_string(self.backup_path()).map_err(|_| {
+            crate::error::CsmError::NotFound(format!(
+                "no backup found at {}",
+                self.backup_path().display()
+            ))
+        })?;
+        std::fs::write(&self.claude_md_path, backup)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SkillScope, SkillSource, UpdateMode, UpdateTrigger};
+
+    fn skill(name: &str, content: &str) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: name.to_string(),
+            source: SkillSource::Inline,
+            scope: SkillScope::Global,
+            content: content.to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: UpdateMode::Auto,
+            update_trigger: UpdateTrigger::Manual,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn dedupe_sections_emits_an_identical_shared_section_only_once() {
+        let skills = vec![
+            skill("alpha", "## Safety\n\nAlways ask before deleting files.\n"),
+            skill("beta", "## Safety\n\nAlways ask before deleting files.\n\n## Usage\n\nbeta-specific usage.\n"),
+        ];
+
+        let rendered = render_merged_with_options(&skills, true, None, false, false);
+
+        assert_eq!(
+            rendered.matches("Always ask before deleting files.").count(),
+            1
+        );
+        assert!(rendered.contains("_(same as in skill 'alpha')_"));
+        assert!(rendered.contains("beta-specific usage."));
+    }
+
+    #[test]
+    fn dedupe_sections_off_repeats_identical_sections_as_before() {
+        let skills = vec![
+            skill("alpha", "## Safety\n\nAlways ask before deleting files.\n"),
+            skill("beta", "## Safety\n\nAlways ask before deleting files.\n"),
+        ];
+
+        let rendered = render_merged_with_options(&skills, false, None, false, false);
+
+        assert_eq!(
+            rendered.matches("Always ask before deleting files.").count(),
+            2
+        );
+    }
+
+    #[test]
+    fn dedupe_lines_drops_a_bullet_already_emitted_by_a_higher_priority_skill() {
+        let mut high = skill("alpha", "- Be concise\n- alpha-only bullet\n");
+        high.priority = 10;
+        let mut low = skill("beta", "- Be concise\n- beta-only bullet\n");
+        low.priority = 1;
+
+        let rendered = render_merged_with_options(&[high, low], false, None, true, false);
+
+        assert_eq!(rendered.matches("- Be concise").count(), 1, "the second occurrence must be dropped");
+        assert!(rendered.contains("- alpha-only bullet"));
+        assert!(rendered.contains("- beta-only bullet"));
+    }
+
+    #[test]
+    fn dedupe_lines_preserves_headings_and_blank_lines() {
+        let skills = vec![
+            skill("alpha", "## Safety\n\n- shared bullet\n"),
+            skill("beta", "## Safety\n\n- shared bullet\n"),
+        ];
+
+        let rendered = render_merged_with_options(&skills, false, None, true, false);
+
+        assert_eq!(rendered.matches("## Safety").count(), 2, "headings are always kept");
+        assert_eq!(rendered.matches("- shared bullet").count(), 1);
+    }
+
+    #[test]
+    fn dedupe_lines_off_repeats_shared_bullets_as_before() {
+        assert_eq!(
+            render_merged_with_options(
+                &[skill("alpha", "- shared bullet\n"), skill("beta", "- shared bullet\n")],
+                false,
+                None,
+                false,
+                false,
+            )
+            .matches("- shared bullet")
+            .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn each_skill_is_preceded_by_its_rendered_header_in_priority_order() {
+        let mut low = skill("alpha", "alpha content");
+        low.priority = 1;
+        let mut high = skill("beta", "beta content");
+        high.priority = 10;
+
+        let rendered = render_merged_with_options(
+            &[low, high],
+            false,
+            Some("### {name} (priority {priority}, from {source})"),
+            false,
+            false,
+        );
+
+        let beta_header = rendered.find("### beta (priority 10, from inline)").unwrap();
+        let beta_content = rendered.find("beta content").unwrap();
+        let alpha_header = rendered.find("### alpha (priority 1, from inline)").unwrap();
+        let alpha_content = rendered.find("alpha content").unwrap();
+
+        assert!(beta_header < beta_content);
+        assert!(beta_content < alpha_header);
+        assert!(alpha_header < alpha_content);
+    }
+
+    #[test]
+    fn missing_skill_header_config_falls_back_to_the_default_template() {
+        let rendered = render_merged_with_options(&[skill("alpha", "content")], false, None, false, false);
+
+        assert!(rendered.contains("## alpha"));
+    }
+
+    #[test]
+    fn an_enabled_skill_with_missing_content_gets_a_placeholder_and_merge_still_succeeds() {
+        let skills = vec![
+            skill("alpha", ""),
+            skill("beta", "beta content"),
+        ];
+
+        let rendered = render_merged_with_options(&skills, false, None, false, false);
+
+        assert!(rendered.contains(r#"<!-- csm:error skill="alpha" content missing, run csm doctor -->"#));
+        assert!(rendered.contains("beta content"));
+    }
+
+    #[test]
+    fn toc_lists_each_enabled_skills_header_in_priority_order_with_a_github_style_anchor() {
+        let mut low = skill("alpha", "alpha content");
+        low.priority = 1;
+        let mut high = skill("beta", "beta content");
+        high.priority = 10;
+        let mut disabled = skill("gamma", "gamma content");
+        disabled.enabled = false;
+
+        let rendered = render_merged_with_options(&[low, high, disabled], false, None, false, true);
+
+        let toc_end = rendered.find("## beta").unwrap();
+        let toc = &rendered[..toc_end];
+        assert!(toc.contains("- [beta](#beta)"));
+        assert!(toc.contains("- [alpha](#alpha)"));
+        assert!(!toc.contains("gamma"), "disabled skills are left out of the toc");
+        assert!(toc.find("beta").unwrap() < toc.find("alpha").unwrap(), "toc follows priority order");
+    }
+
+    #[test]
+    fn toc_off_renders_no_table_of_contents() {
+        let rendered = render_merged_with_options(&[skill("alpha", "alpha content")], false, None, false, false);
+
+        assert!(!rendered.contains("Table of Contents"));
+    }
+
+    #[tokio::test]
+    async fn rebuild_backs_up_the_prior_content_before_overwriting() {
+        let path = std::env::temp_dir().join("csm_test_merge_backup_claude_md.md");
+        let backup_path = std::env::temp_dir().join("csm_test_merge_backup_claude_md.md.bak");
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&backup_path);
+
+        let service = ClaudeMdMergeService::new(path.clone());
+        service.rebuild(&[skill("alpha", "first version")]).await.unwrap();
+        service.rebuild(&[skill("alpha", "second version")]).await.unwrap();
+
+        let current = std::fs::read_to_string(&path).unwrap();
+        assert!(current.contains("second version"));
+
+        let backed_up = std::fs::read_to_string(&backup_path).unwrap();
+        assert!(backed_up.contains("first version"));
+
+        service.restore_backup().await.unwrap();
+        let restored = std::fs::read_to_string(&path).unwrap();
+        assert!(restored.contains("first version"));
+
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&backup_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn rebuilding_with_unchanged_content_does_not_rewrite_the_file() {
+        let path = std::env::temp_dir().join("csm_test_merge_noop_claude_md.md");
+        let backup_path = std::env::temp_dir().join("csm_test_merge_noop_claude_md.md.bak");
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&backup_path);
+
+        let service = ClaudeMdMergeService::new(path.clone());
+        service.rebuild(&[skill("alpha", "unchanged content")]).await.unwrap();
+        let mtime_after_first = std::fs::metadata(&path).unwrap().modified().unwrap();
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        service.rebuild(&[skill("alpha", "unchanged content")]).await.unwrap();
+        let mtime_after_second = std::fs::metadata(&path).unwrap().modified().unwrap();
+
+        assert_eq!(mtime_after_first, mtime_after_second, "no-op rebuild must not rewrite the file");
+        assert!(!backup_path.exists(), "no-op rebuild must not take a backup either");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn rebuild_summary_reflects_the_enabled_skill_count_and_bytes() {
+        let path = std::env::temp_dir().join("csm_test_merge_summary_claude_md.md");
+        let backup_path = std::env::temp_dir().join("csm_test_merge_summary_claude_md.md.bak");
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&backup_path);
+
+        let mut disabled = skill("gamma", "disabled content");
+        disabled.enabled = false;
+        let skills = vec![skill("alpha", "alpha content"), skill("beta", "beta content"), disabled];
+
+        let service = ClaudeMdMergeService::new(path.clone());
+        let summary = service.rebuild(&skills).await.unwrap();
+
+        assert_eq!(summary.skill_count, 2, "only enabled skills count towards the summary");
+        assert_eq!(summary.bytes, std::fs::read(&path).unwrap().len());
+
+        std::fs::remove_file(&path).unwrap();
+        let _ = std::fs::remove_file(&backup_path);
+    }
+
+    #[tokio::test]
+    async fn restore_backup_fails_when_no_backup_has_ever_been_taken() {
+        let path = std::env::temp_dir().join("csm_test_merge_no_backup_claude_md.md");
+        let backup_path = std::env::temp_dir().join("csm_test_merge_no_backup_claude_md.md.bak");
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&backup_path);
+
+        let service = ClaudeMdMergeService::new(path);
+        assert!(service.restore_backup().await.is_err());
+    }
+}
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+
+use crate::error::Result;
+use crate::models::Skill;
+
+/// What a `rebuild` produced, for `csm doctor --fix` to report back to the
+/// user instead of rebuilding silently.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RebuildSummary {
+    /// Enabled skills folded into the rebuilt output.
+    pub skill_count: usize,
+    /// Size of the rebuilt output, in bytes.
+    pub bytes: usize,
+}
+
+/// Folds enabled skills into the project's `CLAUDE.md`.
+#[async_trait]
+pub trait MergeService: Send + Sync {
+    async fn merge(&self, skill: &Skill) -> Result<()>;
+
+    /// Discards whatever is currently merged and rewrites it from `skills`,
+    /// used by `csm doctor --fix` to repair drift rather than append to it.
+    /// Implementors that clobber a file in place should back up its prior
+    /// contents first (see `restore_backup`) so a hand-edit isn't lost.
+    /// Returns a `RebuildSummary` of what was folded in, whether or not the
+    /// write was actually skipped as a no-op.
+    async fn rebuild(&self, skills: &[Skill]) -> Result<RebuildSummary>;
+
+    /// Overwrites the current merged output with the backup taken by the
+    /// most recent `rebuild`, for undoing an unwanted `--fix`.
+    ///
+    /// Defaults to reporting there's nothing to restore: only implementors
+    /// that actually clobber a file (like `ClaudeMdMergeService`) take a
+    /// backup worth restoring.
+    async fn restore_backup(&self) -> Result<()> {
+        Err(crate::error::CsmError::NotFound("no backup available".to_string()))
+    }
+}
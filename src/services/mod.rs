@@ -0,0 +1,17 @@
+mod conflict_service;
+mod content_fetcher;
+pub mod dedup;
+pub mod fetcher;
+pub mod merge;
+mod merge_service;
+pub mod naming;
+mod skill_service;
+mod update_service;
+mod update_summary;
+
+pub use conflict_service::{ConflictService, ConflictServiceImpl};
+pub use content_fetcher::ContentFetcher;
+pub use merge_service::{MergeService, RebuildSummary};
+pub use skill_service::{SkillService, SkillServiceImpl};
+pub use update_service::{SkillUpdateStatus, UpdateAllSummary, UpdateService, UpdateServiceImpl};
+pub use update_summary::{summarize_update, UpdateSummary};
@@ -0,0 +1,74 @@
+/// Whether `name` is safe to use as a skill name: non-empty and restricted
+/// to ASCII letters, digits, `-`, `_`, and `.`.
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// Slugifies `raw` into a valid name: lowercased, non-alphanumeric runs
+/// collapsed to a single `-`, with leading/trailing `-` trimmed.
+pub fn slugify(raw: &str) -> String {
+    let mut slug = String::with_capacity(raw.len());
+    let mut last_was_dash = false;
+    for c in raw.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+            last_was_dash = false;
+        } else if !last_was_dash {
+            slug.push('-');
+            last_was_dash = true;
+        }
+    }
+    slug.trim_matches('-').to_string()
+}
+
+/// Returns `base` if it isn't taken, otherwise `base-2`, `base-3`, ... until
+/// one is free. Used by `add --auto-suffix` to resolve name collisions
+/// without failing the whole operation.
+pub fn next_available_name(existing: &[String], base: &str) -> String {
+    if !existing.iter().any(|n| n == base) {
+        return base.to_string();
+    }
+
+    let mut suffix = 2;
+    loop {
+        let candidate = format!("{base}-{suffix}");
+        if !existing.iter().any(|n| n == &candidate) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appends_incrementing_suffix_until_free() {
+        let existing = vec!["skill".to_string(), "skill-2".to_string()];
+        assert_eq!(next_available_name(&existing, "skill"), "skill-3");
+    }
+
+    #[test]
+    fn returns_base_unchanged_when_free() {
+        let existing = vec!["other".to_string()];
+        assert_eq!(next_available_name(&existing, "skill"), "skill");
+    }
+
+    #[test]
+    fn slugify_collapses_spacing_and_punctuation() {
+        assert_eq!(slugify("My Skill!"), "my-skill");
+        assert_eq!(slugify("  --Weird__Name.md--  "), "weird-name-md");
+    }
+
+    #[test]
+    fn is_valid_name_rejects_spaces_and_punctuation() {
+        assert!(is_valid_name("my-skill"));
+        assert!(is_valid_name("deploy.md"));
+        assert!(!is_valid_name("My Skill!"));
+        assert!(!is_valid_name(""));
+    }
+}
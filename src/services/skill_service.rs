@@ -0,0 +1,1154 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::error::{CsmError, Result};
+use crate::models::{MergePreviewStats, SameNameStrategy, Skill, SkillScope, SkillSource, UpdateMode};
+use crate::repository::SkillRepository;
+use crate::utils::frontmatter;
+use crate::utils::hash::hash_content;
+
+use super::content_fetcher::ContentFetcher;
+use super::merge_service::MergeService;
+
+/// Adds, updates, and removes skills, keeping storage and the merged
+/// `CLAUDE.md` output in sync.
+#[async_trait]
+pub trait SkillService: Send + Sync {
+    async fn add(&self, name: &str, source: SkillSource, scope: SkillScope) -> Result<Skill>;
+
+    /// Like `add`, but when `name`/`scope` already exists, overwrites its
+    /// content in place via `update_content` instead of failing, preserving
+    /// the existing skill's id. Used by `csm add --force` for scripted
+    /// re-scaffolding where the caller doesn't care whether the name is new.
+    async fn add_or_overwrite(&self, name: &str, source: SkillSource, scope: SkillScope) -> Result<Skill>;
+
+    async fn add_with_content(
+        &self,
+        name: &str,
+        source: SkillSource,
+        scope: SkillScope,
+        content: String,
+    ) -> Result<Skill>;
+
+    async fn list(&self) -> Result<Vec<Skill>>;
+
+    /// Overwrites a skill's content in place, recomputing its hash.
+    async fn update_content(
+        &self,
+        name: &str,
+        scope: SkillScope,
+        content: String,
+    ) -> Result<Skill>;
+
+    /// Aggregate stats for what merging would produce, optionally restricted to one scope.
+    async fn merge_preview(&self, scope: Option<SkillScope>) -> Result<MergePreviewStats>;
+
+    /// `merge_preview` broken out per `SkillScope`, for `csm sync`'s
+    /// per-scope summary. Defaults to one `merge_preview` call per scope;
+    /// implementors with a cheaper way to get every scope at once (e.g. a
+    /// single query) can override it.
+    async fn merge_preview_all(&self) -> Result<Vec<(SkillScope, MergePreviewStats)>> {
+        let mut stats = Vec::new();
+        for scope in [SkillScope::Global, SkillScope::Project] {
+            stats.push((scope, self.merge_preview(Some(scope)).await?));
+        }
+        Ok(stats)
+    }
+
+    /// Skills as they should actually be merged: every non-archived global
+    /// and project skill, with a project skill that shares a global skill's
+    /// name reconciled per `[merge] same_name_strategy` (by default the
+    /// project skill replaces the global one).
+    async fn effective_list(&self) -> Result<Vec<Skill>>;
+
+    /// Sets or clears a skill's local note. `note: None` clears it.
+    async fn set_note(&self, name: &str, scope: SkillScope, note: Option<String>) -> Result<Skill>;
+
+    /// Soft-deletes a skill: it drops out of `effective_list` (and so out of
+    /// the next merge) and out of `csm list`'s default listing, but keeps its
+    /// content, ready for `restore`. `list` still returns it, flagged `archived`.
+    async fn archive(&self, name: &str, scope: SkillScope) -> Result<Skill>;
+
+    /// Reverses `archive`, putting the skill back into normal listings.
+    async fn restore(&self, name: &str, scope: SkillScope) -> Result<Skill>;
+
+    /// Renames a skill in place, keeping its id, timestamps other than
+    /// `updated_at`, and history intact, unlike remove-then-re-add. Fails if
+    /// `new_name` is empty or already taken by a different skill in `scope`.
+    async fn rename(&self, name: &str, scope: SkillScope, new_name: &str) -> Result<Skill>;
+
+    /// Replaces a skill's tag set. Tags aren't merged into `CLAUDE.md`, so
+    /// unlike `set_priority` this never triggers a rebuild.
+    async fn set_tags(&self, name: &str, scope: SkillScope, tags: Vec<String>) -> Result<Skill>;
+
+    /// Sets a skill's merge priority and rebuilds the merge output, since
+    /// priority affects ordering there.
+    async fn set_priority(&self, name: &str, scope: SkillScope, priority: i32) -> Result<Skill>;
+
+    /// Enables or disables a skill in place, rebuilding the merge output
+    /// since a disabled skill drops out of it. Used by `add --disabled` to
+    /// flip a just-added skill off pending review, without archiving it.
+    async fn set_enabled(&self, name: &str, scope: SkillScope, enabled: bool) -> Result<Skill>;
+
+    /// Records the outcome of an update attempt against a skill's source:
+    /// resets `failure_count`/`last_failure_at` on success, or bumps them on
+    /// failure. Used by `update_all` to back off from a persistently-failing
+    /// source. Never rebuilds the merge, since neither field affects it.
+    async fn record_update_result(&self, name: &str, scope: SkillScope, failed: bool) -> Result<Skill>;
+
+    /// Restores the content `update_content` most recently overwrote,
+    /// rebuilding the merge output since content affects it. Returns `false`
+    /// if there's no `previous_content` to roll back to, e.g. the skill was
+    /// never updated, or an earlier rollback already consumed it.
+    async fn rollback_content(&self, name: &str, scope: SkillScope) -> Result<bool>;
+
+    /// Permanently deletes a skill, archived or not. Unlike `archive`, this
+    /// cannot be undone. Already a single atomic `delete` call with no
+    /// paired side-effect step (unlike `add`'s create-then-merge), so it
+    /// needs no transactional wrapper of its own.
+    async fn purge(&self, name: &str, scope: SkillScope) -> Result<()>;
+
+    /// Raw persistence-layer corruption `csm doctor` should surface instead
+    /// of masking, delegated to the repository. Defaults to "nothing to
+    /// report", since most implementors (including every in-memory fake used
+    /// in tests) have no raw representation to drift from the parsed model.
+    async fn integrity_issues(&self) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// Skills whose content contains `query`, for `csm search --content`.
+    /// Defaults to a plain filter over `list()`, matching
+    /// `SkillRepository::search_content_only`'s own default; `SkillServiceImpl`
+    /// overrides it to delegate straight to the repository instead of
+    /// filtering every already-loaded skill here, so the FTS5-backed
+    /// `SqliteSkillRepository` override actually gets used.
+    async fn search_content_only(&self, query: &str) -> Result<Vec<Skill>> {
+        let needle = query.to_lowercase();
+        Ok(self
+            .list()
+            .await?
+            .into_iter()
+            .filter(|s| s.content.to_lowercase().contains(&needle))
+            .collect())
+    }
+}
+
+pub struct SkillServiceImpl {
+    repository: Arc<dyn SkillRepository>,
+    fetcher: Arc<dyn ContentFetcher>,
+    merger: Arc<dyn MergeService>,
+    read_only: bool,
+    same_name_strategy: SameNameStrategy,
+    enable_on_add: bool,
+    inherit_global: bool,
+}
+
+impl SkillServiceImpl {
+    pub fn new(
+        repository: Arc<dyn SkillRepository>,
+        fetcher: Arc<dyn ContentFetcher>,
+        merger: Arc<dyn MergeService>,
+    ) -> Self {
+        Self {
+            repository,
+            fetcher,
+            merger,
+            read_only: false,
+            same_name_strategy: SameNameStrategy::default(),
+            enable_on_add: true,
+            inherit_global: true,
+        }
+    }
+
+    /// Puts the service in read-only mode, rejecting `add*`/`update_content` calls.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets `[merge] same_name_strategy`, controlling how `effective_list`
+    /// reconciles a project skill with a global skill of the same name.
+    pub fn with_same_name_strategy(mut self, strategy: SameNameStrategy) -> Self {
+        self.same_name_strategy = strategy;
+        self
+    }
+
+    /// Sets `[merge] inherit_global`, controlling whether `effective_list`
+    /// folds enabled global skills into the project's effective set at all.
+    /// When false, `effective_list` returns each scope's own skills without
+    /// reconciling same-named pairs across scopes.
+    pub fn with_inherit_global(mut self, inherit_global: bool) -> Self {
+        self.inherit_global = inherit_global;
+        self
+    }
+
+    /// Sets `[general] enable_on_add`, controlling whether `add`/`add_with_content`
+    /// mark a newly created skill enabled immediately or leave it disabled
+    /// (and so out of the merge) pending review.
+    pub fn with_enable_on_add(mut self, enable_on_add: bool) -> Self {
+        self.enable_on_add = enable_on_add;
+        self
+    }
+
+    fn ensure_writable(&self) -> Result<()> {
+        if self.read_only {
+            return Err(CsmError::Other(
+                "csm is in read-only mode; skill storage cannot be modified".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_content(&self, name: &str, content: &str) -> Result<()> {
+        if content.trim().is_empty() {
+            return Err(CsmError::Validation(format!(
+                "skill '{name}' has empty content"
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SkillService for SkillServiceImpl {
+    async fn add(&self, name: &str, source: SkillSource, scope: SkillScope) -> Result<Skill> {
+        let content = self.fetcher.fetch_content(&source).await?;
+        self.add_with_content(name, source, scope, content).await
+    }
+
+    async fn add_or_overwrite(&self, name: &str, source: SkillSource, scope: SkillScope) -> Result<Skill> {
+        let content = self.fetcher.fetch_content(&source).await?;
+        match self.repository.find_by_name(name, scope).await? {
+            Some(_) => self.update_content(name, scope, content).await,
+            None => self.add_with_content(name, source, scope, content).await,
+        }
+    }
+
+    async fn add_with_content(
+        &self,
+        name: &str,
+        source: SkillSource,
+        scope: SkillScope,
+        content: String,
+    ) -> Result<Skill> {
+        self.ensure_writable()?;
+        if self.repository.find_by_name(name, scope).await?.is_some() {
+            return Err(CsmError::AlreadyExists(name.to_string()));
+        }
+
+        let (meta, content) = frontmatter::parse(&content);
+        self.validate_content(name, &content)?;
+
+        let now = Utc::now();
+        let skill = Skill {
+            id: 0,
+            name: name.to_string(),
+            source,
+            scope,
+            content_hash: hash_content(&content),
+            previous_content: None,
+            content,
+            enabled: self.enable_on_add,
+            priority: meta.as_ref().and_then(|m| m.priority).unwrap_or(0),
+            update_mode: UpdateMode::default(),
+            update_trigger: crate::models::UpdateTrigger::default(),
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: meta.map(|m| m.tags).unwrap_or_default(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        // `create_and_merge` deletes the just-created row if `merge` fails,
+        // so a failed merge never leaves a skill stored whose content isn't
+        // reflected in `CLAUDE.md`.
+        self.repository.create_and_merge(skill, self.merger.as_ref()).await
+    }
+
+    async fn list(&self) -> Result<Vec<Skill>> {
+        self.repository.list().await
+    }
+
+    async fn update_content(
+        &self,
+        name: &str,
+        scope: SkillScope,
+        content: String,
+    ) -> Result<Skill> {
+        self.ensure_writable()?;
+        let mut skill = self
+            .repository
+            .find_by_name(name, scope)
+            .await?
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))?;
+
+        self.validate_content(name, &content)?;
+        skill.previous_content = Some(skill.content.clone());
+        skill.content_hash = hash_content(&content);
+        skill.content = content;
+        skill.updated_at = Utc::now();
+
+        self.repository.update(skill).await
+    }
+
+    async fn merge_preview(&self, scope: Option<SkillScope>) -> Result<MergePreviewStats> {
+        let skills = self.repository.list().await?;
+        let mut stats = MergePreviewStats::default();
+        for skill in skills
+            .iter()
+            .filter(|s| !s.archived)
+            .filter(|s| scope.map_or(true, |sc| sc == s.scope))
+        {
+            stats.skill_count += 1;
+            if skill.enabled {
+                stats.enabled_count += 1;
+                stats.total_bytes += skill.content.len();
+            }
+        }
+        Ok(stats)
+    }
+
+    async fn effective_list(&self) -> Result<Vec<Skill>> {
+        let mut globals: Vec<Skill> = self
+            .repository
+            .list_by_scope(SkillScope::Global)
+            .await?
+            .into_iter()
+            .filter(|s| !s.archived)
+            .collect();
+        let projects: Vec<Skill> = self
+            .repository
+            .list_by_scope(SkillScope::Project)
+            .await?
+            .into_iter()
+            .filter(|s| !s.archived)
+            .collect();
+
+        if !self.inherit_global {
+            globals.extend(projects);
+            return Ok(globals);
+        }
+
+        for project_skill in projects {
+            match globals.iter_mut().find(|g| g.name == project_skill.name) {
+                Some(global_skill) => match self.same_name_strategy {
+                    SameNameStrategy::Override => *global_skill = project_skill,
+                    SameNameStrategy::Append => {
+                        global_skill.content =
+                            format!("{}\n\n{}", global_skill.content, project_skill.content);
+                    }
+                    SameNameStrategy::PreferGlobal => {}
+                },
+                None => globals.push(project_skill),
+            }
+        }
+
+        Ok(globals)
+    }
+
+    async fn set_note(&self, name: &str, scope: SkillScope, note: Option<String>) -> Result<Skill> {
+        self.ensure_writable()?;
+        let mut skill = self
+            .repository
+            .find_by_name(name, scope)
+            .await?
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))?;
+
+        skill.notes = note;
+        skill.updated_at = Utc::now();
+
+        self.repository.update(skill).await
+    }
+
+    async fn archive(&self, name: &str, scope: SkillScope) -> Result<Skill> {
+        self.ensure_writable()?;
+        let mut skill = self
+            .repository
+            .find_by_name(name, scope)
+            .await?
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))?;
+
+        skill.archived = true;
+        skill.archived_at = Some(Utc::now());
+        skill.updated_at = Utc::now();
+
+        self.repository.update(skill).await
+    }
+
+    async fn restore(&self, name: &str, scope: SkillScope) -> Result<Skill> {
+        self.ensure_writable()?;
+        let mut skill = self
+            .repository
+            .find_by_name(name, scope)
+            .await?
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))?;
+
+        skill.archived = false;
+        skill.archived_at = None;
+        skill.updated_at = Utc::now();
+
+        self.repository.update(skill).await
+    }
+
+    async fn purge(&self, name: &str, scope: SkillScope) -> Result<()> {
+        self.ensure_writable()?;
+        let skill = self
+            .repository
+            .find_by_name(name, scope)
+            .await?
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))?;
+
+        self.repository.delete(skill.id).await
+    }
+
+    async fn integrity_issues(&self) -> Result<Vec<String>> {
+        self.repository.find_integrity_issues().await
+    }
+
+    async fn search_content_only(&self, query: &str) -> Result<Vec<Skill>> {
+        self.repository.search_content_only(query).await
+    }
+
+    async fn rename(&self, name: &str, scope: SkillScope, new_name: &str) -> Result<Skill> {
+        self.ensure_writable()?;
+        if new_name.trim().is_empty() {
+            return Err(CsmError::InvalidName(new_name.to_string()));
+        }
+        if new_name != name && self.repository.find_by_name(new_name, scope).await?.is_some() {
+            return Err(CsmError::AlreadyExists(new_name.to_string()));
+        }
+
+        let mut skill = self
+            .repository
+            .find_by_name(name, scope)
+            .await?
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))?;
+
+        skill.name = new_name.to_string();
+        skill.updated_at = Utc::now();
+        let renamed = self.repository.update(skill).await?;
+
+        let effective = self.effective_list().await?;
+        self.merger.rebuild(&effective).await?;
+
+        Ok(renamed)
+    }
+
+    async fn set_tags(&self, name: &str, scope: SkillScope, tags: Vec<String>) -> Result<Skill> {
+        self.ensure_writable()?;
+        let mut skill = self
+            .repository
+            .find_by_name(name, scope)
+            .await?
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))?;
+
+        skill.tags = tags;
+        skill.updated_at = Utc::now();
+
+        self.repository.update(skill).await
+    }
+
+    async fn set_priority(&self, name: &str, scope: SkillScope, priority: i32) -> Result<Skill> {
+        self.ensure_writable()?;
+        let mut skill = self
+            .repository
+            .find_by_name(name, scope)
+            .await?
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))?;
+
+        skill.priority = priority;
+        skill.updated_at = Utc::now();
+        let updated = self.repository.update(skill).await?;
+
+        let effective = self.effective_list().await?;
+        self.merger.rebuild(&effective).await?;
+
+        Ok(updated)
+    }
+
+    async fn set_enabled(&self, name: &str, scope: SkillScope, enabled: bool) -> Result<Skill> {
+        self.ensure_writable()?;
+        let mut skill = self
+            .repository
+            .find_by_name(name, scope)
+            .await?
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))?;
+
+        skill.enabled = enabled;
+        skill.updated_at = Utc::now();
+        let updated = self.repository.update(skill).await?;
+
+        let effective = self.effective_list().await?;
+        self.merger.rebuild(&effective).await?;
+
+        Ok(updated)
+    }
+
+    async fn record_update_result(&self, name: &str, scope: SkillScope, failed: bool) -> Result<Skill> {
+        self.ensure_writable()?;
+        let mut skill = self
+            .repository
+            .find_by_name(name, scope)
+            .await?
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))?;
+
+        if failed {
+            skill.failure_count += 1;
+            skill.last_failure_at = Some(Utc::now());
+        } else {
+            skill.failure_count = 0;
+            skill.last_failure_at = None;
+        }
+        skill.updated_at = Utc::now();
+
+        self.repository.update(skill).await
+    }
+
+    async fn rollback_content(&self, name: &str, scope: SkillScope) -> Result<bool> {
+        self.ensure_writable()?;
+        let mut skill = self
+            .repository
+            .find_by_name(name, scope)
+            .await?
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))?;
+
+        let Some(previous) = skill.previous_content.take() else {
+            return Ok(false);
+        };
+
+        skill.content_hash = hash_content(&previous);
+        skill.content = previous;
+        skill.updated_at = Utc::now();
+        self.repository.update(skill).await?;
+
+        let effective = self.effective_list().await?;
+        self.merger.rebuild(&effective).await?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct FakeRepository {
+        skills: Mutex<Vec<Skill>>,
+    }
+
+    #[async_trait]
+    impl SkillRepository for FakeRepository {
+        async fn create(&self, mut skill: Skill) -> Result<Skill> {
+            let mut skills = self.skills.lock().unwrap();
+            skill.id = skills.len() as i64 + 1;
+            skills.push(skill.clone());
+            Ok(skill)
+        }
+
+        async fn find_by_name(&self, name: &str, scope: SkillScope) -> Result<Option<Skill>> {
+            Ok(self
+                .skills
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|s| s.name == name && s.scope == scope)
+                .cloned())
+        }
+
+        async fn list(&self) -> Result<Vec<Skill>> {
+            Ok(self.skills.lock().unwrap().clone())
+        }
+
+        async fn update(&self, skill: Skill) -> Result<Skill> {
+            let mut skills = self.skills.lock().unwrap();
+            if let Some(existing) = skills.iter_mut().find(|s| s.id == skill.id) {
+                *existing = skill.clone();
+            }
+            Ok(skill)
+        }
+
+        async fn delete(&self, id: i64) -> Result<()> {
+            self.skills.lock().unwrap().retain(|s| s.id != id);
+            Ok(())
+        }
+    }
+
+    struct FakeFetcher;
+
+    #[async_trait]
+    impl ContentFetcher for FakeFetcher {
+        async fn fetch_content(&self, _source: &SkillSource) -> Result<String> {
+            Ok("# fetched content".to_string())
+        }
+    }
+
+    struct FakeMerger {
+        merged: Mutex<Vec<String>>,
+        fails: bool,
+    }
+
+    #[async_trait]
+    impl MergeService for FakeMerger {
+        async fn merge(&self, skill: &Skill) -> Result<()> {
+            if self.fails {
+                return Err(CsmError::Other("merge failed".to_string()));
+            }
+            self.merged.lock().unwrap().push(skill.name.clone());
+            Ok(())
+        }
+
+        async fn rebuild(&self, skills: &[Skill]) -> Result<crate::services::RebuildSummary> {
+            // Like `ClaudeMdMergeService`, only enabled skills are folded in.
+            let enabled: Vec<String> =
+                skills.iter().filter(|s| s.enabled).map(|s| s.name.clone()).collect();
+            let skill_count = enabled.len();
+            *self.merged.lock().unwrap() = enabled;
+            Ok(crate::services::RebuildSummary {
+                skill_count,
+                bytes: 0,
+            })
+        }
+    }
+
+    fn service() -> (SkillServiceImpl, Arc<FakeRepository>) {
+        let repository = Arc::new(FakeRepository {
+            skills: Mutex::new(Vec::new()),
+        });
+        let fetcher = Arc::new(FakeFetcher);
+        let merger = Arc::new(FakeMerger {
+            merged: Mutex::new(Vec::new()),
+            fails: false,
+        });
+        (
+            SkillServiceImpl::new(repository.clone(), fetcher, merger),
+            repository,
+        )
+    }
+
+    #[tokio::test]
+    async fn add_with_content_stores_the_given_content_without_fetching() {
+        let (service, repository) = service();
+
+        let skill = service
+            .add_with_content(
+                "my-skill",
+                SkillSource::Inline,
+                SkillScope::Project,
+                "# pasted content".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(skill.content, "# pasted content");
+        let stored = repository
+            .find_by_name("my-skill", SkillScope::Project)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.content, "# pasted content");
+    }
+
+    #[tokio::test]
+    async fn add_with_content_rejects_duplicate_name_in_scope() {
+        let (service, _repository) = service();
+        service
+            .add_with_content(
+                "dup",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "one".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let err = service
+            .add_with_content(
+                "dup",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "two".to_string(),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, CsmError::AlreadyExists(_)));
+    }
+
+    #[tokio::test]
+    async fn a_failed_merge_rolls_back_the_just_created_skill() {
+        let repository = Arc::new(FakeRepository {
+            skills: Mutex::new(Vec::new()),
+        });
+        let fetcher = Arc::new(FakeFetcher);
+        let merger = Arc::new(FakeMerger {
+            merged: Mutex::new(Vec::new()),
+            fails: true,
+        });
+        let service = SkillServiceImpl::new(repository.clone(), fetcher, merger);
+
+        let err = service
+            .add_with_content(
+                "doomed",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "content".to_string(),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, CsmError::Other(_)));
+        assert!(repository
+            .find_by_name("doomed", SkillScope::Global)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn read_only_service_rejects_writes() {
+        let (service, _repository) = service();
+        let service = service.with_read_only(true);
+
+        let err = service
+            .add_with_content(
+                "blocked",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "content".to_string(),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, CsmError::Other(_)));
+    }
+
+    #[tokio::test]
+    async fn merge_preview_counts_only_enabled_skills_bytes_but_all_skills() {
+        let (service, _repository) = service();
+        service
+            .add_with_content(
+                "on",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "12345".to_string(),
+            )
+            .await
+            .unwrap();
+        let disabled = service
+            .add_with_content(
+                "off",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "1234567890".to_string(),
+            )
+            .await
+            .unwrap();
+        _repository
+            .update(Skill {
+                enabled: false,
+                ..disabled
+            })
+            .await
+            .unwrap();
+
+        let stats = service.merge_preview(Some(SkillScope::Global)).await.unwrap();
+
+        assert_eq!(stats.skill_count, 2);
+        assert_eq!(stats.enabled_count, 1);
+        assert_eq!(stats.total_bytes, 5);
+    }
+
+    #[tokio::test]
+    async fn merge_preview_all_breaks_stats_down_by_scope() {
+        let (service, _repository) = service();
+        service
+            .add_with_content("global-skill", SkillSource::Inline, SkillScope::Global, "12345".to_string())
+            .await
+            .unwrap();
+        service
+            .add_with_content("project-skill", SkillSource::Inline, SkillScope::Project, "1234567".to_string())
+            .await
+            .unwrap();
+
+        let stats = service.merge_preview_all().await.unwrap();
+
+        let global = stats.iter().find(|(scope, _)| *scope == SkillScope::Global).unwrap();
+        let project = stats.iter().find(|(scope, _)| *scope == SkillScope::Project).unwrap();
+        assert_eq!(global.1.enabled_count, 1);
+        assert_eq!(global.1.total_bytes, 5);
+        assert_eq!(project.1.enabled_count, 1);
+        assert_eq!(project.1.total_bytes, 7);
+    }
+
+    #[tokio::test]
+    async fn effective_list_appends_project_content_onto_matching_global_skill() {
+        let (service, _repository) = service();
+        let service = service.with_same_name_strategy(SameNameStrategy::Append);
+        service
+            .add_with_content(
+                "shared",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "global rules".to_string(),
+            )
+            .await
+            .unwrap();
+        service
+            .add_with_content(
+                "shared",
+                SkillSource::Inline,
+                SkillScope::Project,
+                "project extras".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let effective = service.effective_list().await.unwrap();
+
+        assert_eq!(effective.len(), 1);
+        assert_eq!(effective[0].content, "global rules\n\nproject extras");
+    }
+
+    async fn shared_name_service() -> (SkillServiceImpl, Arc<FakeRepository>) {
+        let (service, repository) = service();
+        service
+            .add_with_content(
+                "shared",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "global rules".to_string(),
+            )
+            .await
+            .unwrap();
+        service
+            .add_with_content(
+                "shared",
+                SkillSource::Inline,
+                SkillScope::Project,
+                "project extras".to_string(),
+            )
+            .await
+            .unwrap();
+        (service, repository)
+    }
+
+    #[tokio::test]
+    async fn same_name_strategy_override_defaults_to_the_project_skill_replacing_the_global_one() {
+        let (service, _repository) = shared_name_service().await;
+
+        let effective = service.effective_list().await.unwrap();
+
+        assert_eq!(effective.len(), 1);
+        assert_eq!(effective[0].content, "project extras");
+        assert_eq!(effective[0].scope, SkillScope::Project);
+    }
+
+    #[tokio::test]
+    async fn same_name_strategy_prefer_global_keeps_the_global_skill_untouched() {
+        let (service, _repository) = shared_name_service().await;
+        let service = service.with_same_name_strategy(SameNameStrategy::PreferGlobal);
+
+        let effective = service.effective_list().await.unwrap();
+
+        assert_eq!(effective.len(), 1);
+        assert_eq!(effective[0].content, "global rules");
+        assert_eq!(effective[0].scope, SkillScope::Global);
+    }
+
+    #[tokio::test]
+    async fn inherit_global_false_keeps_both_scopes_own_same_named_skill_instead_of_reconciling() {
+        let (service, _repository) = shared_name_service().await;
+        let service = service.with_inherit_global(false);
+
+        let effective = service.effective_list().await.unwrap();
+
+        assert_eq!(effective.len(), 2, "both scopes' skills pass through unreconciled");
+        assert!(effective.iter().any(|s| s.scope == SkillScope::Global && s.content == "global rules"));
+        assert!(effective.iter().any(|s| s.scope == SkillScope::Project && s.content == "project extras"));
+    }
+
+    #[tokio::test]
+    async fn set_note_persists_and_can_be_read_back() {
+        let (service, _repository) = service();
+        service
+            .add_with_content(
+                "pinned",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "content".to_string(),
+            )
+            .await
+            .unwrap();
+
+        service
+            .set_note(
+                "pinned",
+                SkillScope::Global,
+                Some("pinned to v1 until the v2 rewrite lands".to_string()),
+            )
+            .await
+            .unwrap();
+
+        let stored = _repository
+            .find_by_name("pinned", SkillScope::Global)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            stored.notes.as_deref(),
+            Some("pinned to v1 until the v2 rewrite lands")
+        );
+    }
+
+    #[tokio::test]
+    async fn archive_removes_a_skill_from_effective_list_and_restore_brings_it_back() {
+        let (service, _repository) = service();
+        service
+            .add_with_content(
+                "old-skill",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "content".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let archived = service.archive("old-skill", SkillScope::Global).await.unwrap();
+        assert!(archived.archived);
+        assert!(archived.archived_at.is_some());
+        assert!(service.effective_list().await.unwrap().is_empty());
+
+        let restored = service.restore("old-skill", SkillScope::Global).await.unwrap();
+        assert!(!restored.archived);
+        assert!(restored.archived_at.is_none());
+        assert_eq!(service.effective_list().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn purge_permanently_deletes_a_skill() {
+        let (service, repository) = service();
+        service
+            .add_with_content(
+                "throwaway",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "content".to_string(),
+            )
+            .await
+            .unwrap();
+
+        service.purge("throwaway", SkillScope::Global).await.unwrap();
+
+        assert!(repository
+            .find_by_name("throwaway", SkillScope::Global)
+            .await
+            .unwrap()
+            .is_none());
+        let err = service.purge("throwaway", SkillScope::Global).await.unwrap_err();
+        assert!(matches!(err, CsmError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn set_tags_replaces_the_tag_set_without_rebuilding_the_merge() {
+        let (service, repository) = service();
+        service
+            .add_with_content(
+                "tagged",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "content".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let skill = service
+            .set_tags(
+                "tagged",
+                SkillScope::Global,
+                vec!["ops".to_string(), "deploy".to_string()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(skill.tags, vec!["ops".to_string(), "deploy".to_string()]);
+        let stored = repository
+            .find_by_name("tagged", SkillScope::Global)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.tags, vec!["ops".to_string(), "deploy".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn set_priority_updates_the_row_and_rebuilds_the_merge() {
+        let repository = Arc::new(FakeRepository {
+            skills: Mutex::new(Vec::new()),
+        });
+        let fetcher = Arc::new(FakeFetcher);
+        let merger = Arc::new(FakeMerger {
+            merged: Mutex::new(Vec::new()),
+            fails: false,
+        });
+        let service = SkillServiceImpl::new(repository.clone(), fetcher, merger.clone());
+        service
+            .add_with_content(
+                "prioritized",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "content".to_string(),
+            )
+            .await
+            .unwrap();
+        merger.merged.lock().unwrap().clear();
+
+        let skill = service
+            .set_priority("prioritized", SkillScope::Global, 5)
+            .await
+            .unwrap();
+
+        assert_eq!(skill.priority, 5);
+        let stored = repository
+            .find_by_name("prioritized", SkillScope::Global)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.priority, 5);
+        assert_eq!(
+            merger.merged.lock().unwrap().as_slice(),
+            &["prioritized".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn disabling_enable_on_add_leaves_new_skills_disabled_and_out_of_the_merge() {
+        let (service, repository) = service();
+        let service = service.with_enable_on_add(false);
+
+        let skill = service
+            .add_with_content(
+                "pending-review",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "content".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert!(!skill.enabled);
+        let stored = repository
+            .find_by_name("pending-review", SkillScope::Global)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!stored.enabled);
+        assert!(service.merge_preview(None).await.unwrap().enabled_count == 0);
+    }
+
+    #[tokio::test]
+    async fn add_with_content_applies_leading_frontmatter_and_strips_it_from_the_body() {
+        let (service, _repository) = service();
+
+        let skill = service
+            .add_with_content(
+                "deploy",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "---\ndescription: deploy helper\ntags: [ops, deploy]\npriority: 70\n---\n# Deploy\nsteps here\n".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(skill.tags, vec!["ops".to_string(), "deploy".to_string()]);
+        assert_eq!(skill.priority, 70);
+        assert_eq!(skill.content, "# Deploy\nsteps here\n");
+    }
+
+    #[tokio::test]
+    async fn set_enabled_toggles_a_skill_and_rebuilds_the_merge() {
+        let repository = Arc::new(FakeRepository {
+            skills: Mutex::new(Vec::new()),
+        });
+        let fetcher = Arc::new(FakeFetcher);
+        let merger = Arc::new(FakeMerger {
+            merged: Mutex::new(Vec::new()),
+            fails: false,
+        });
+        let service = SkillServiceImpl::new(repository.clone(), fetcher, merger.clone());
+        service
+            .add_with_content(
+                "toggled",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "content".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let skill = service
+            .set_enabled("toggled", SkillScope::Global, false)
+            .await
+            .unwrap();
+
+        assert!(!skill.enabled);
+        assert!(merger.merged.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rollback_content_restores_the_content_and_hash_from_before_the_last_update() {
+        let (service, repository) = service();
+        let original = service
+            .add_with_content(
+                "flaky",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "original content".to_string(),
+            )
+            .await
+            .unwrap();
+
+        service
+            .update_content("flaky", SkillScope::Global, "broken content".to_string())
+            .await
+            .unwrap();
+
+        let rolled_back = service.rollback_content("flaky", SkillScope::Global).await.unwrap();
+        assert!(rolled_back);
+
+        let stored = repository
+            .find_by_name("flaky", SkillScope::Global)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.content, original.content);
+        assert_eq!(stored.content_hash, original.content_hash);
+        assert!(stored.previous_content.is_none());
+    }
+
+    #[tokio::test]
+    async fn rollback_content_is_a_no_op_when_there_is_nothing_to_restore() {
+        let (service, _repository) = service();
+        service
+            .add_with_content(
+                "never-updated",
+                SkillSource::Inline,
+                SkillScope::Global,
+                "content".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let rolled_back = service
+            .rollback_content("never-updated", SkillScope::Global)
+            .await
+            .unwrap();
+
+        assert!(!rolled_back);
+    }
+}
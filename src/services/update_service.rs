@@ -0,0 +1,743 @@
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use serde::Serialize;
+
+use crate::error::{CsmError, Result};
+use crate::github::GitHubClient;
+use crate::models::{Skill, SkillSource, UpdateMode, UpdateTrigger};
+use crate::services::SkillService;
+use crate::utils::cancellation::CancellationToken;
+
+/// Outcome of checking a single skill against its upstream source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkillUpdateStatus {
+    UpToDate,
+    UpdateAvailable { new_ref: String },
+}
+
+/// Aggregate result of `update_all`, for the CLI's summary line and `--json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct UpdateAllSummary {
+    pub checked: usize,
+    /// Skills with `update_mode: Auto` whose content was fetched and applied.
+    pub changed: Vec<String>,
+    /// Skills with `update_mode: Notify` that have an update available but
+    /// were left untouched, pending manual review.
+    pub notified: Vec<String>,
+    /// Skills skipped without being checked because they're still inside
+    /// their failure backoff window. Empty when `--force` is passed.
+    pub skipped_backoff: Vec<String>,
+    /// Skills whose check or fetch errored (network failure, bad
+    /// credentials, upstream gone, ...). Also recorded against the skill via
+    /// `record_update_result(failed: true)` for the next run's backoff.
+    pub failed: Vec<String>,
+    pub bytes_changed: usize,
+    /// Set when a `CancellationToken` stopped the loop early, so the CLI can
+    /// report "interrupted after N items" instead of implying full coverage.
+    pub interrupted: bool,
+}
+
+impl fmt::Display for UpdateAllSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Checked: {}, Updated: {}",
+            self.checked,
+            self.changed.len()
+        )?;
+        if !self.changed.is_empty() {
+            write!(f, " ({})", self.changed.join(", "))?;
+        }
+        if !self.notified.is_empty() {
+            write!(
+                f,
+                "\nupdates available (notify mode): {}",
+                self.notified.join(", ")
+            )?;
+        }
+        if !self.skipped_backoff.is_empty() {
+            write!(
+                f,
+                "\nskipped (backoff after repeated failures): {}",
+                self.skipped_backoff.join(", ")
+            )?;
+        }
+        if !self.failed.is_empty() {
+            write!(f, "\nfailed: {}", self.failed.join(", "))?;
+        }
+        if self.interrupted {
+            write!(f, "\ninterrupted after {} item(s)", self.checked)?;
+        }
+        Ok(())
+    }
+}
+
+/// Base delay for the backoff computed from a skill's `failure_count`,
+/// doubled per consecutive failure (1m, 2m, 4m, ...) and capped at
+/// `MAX_BACKOFF` so a long-failing skill isn't skipped forever.
+const BASE_BACKOFF: Duration = Duration::minutes(1);
+const MAX_BACKOFF: Duration = Duration::hours(24);
+
+/// How long a skill with `failure_count` consecutive failed update attempts,
+/// the most recent at `last_failure_at`, should be skipped for.
+fn backoff_remaining(failure_count: i32, last_failure_at: Option<chrono::DateTime<Utc>>) -> Option<Duration> {
+    let last_failure_at = last_failure_at?;
+    // A single failure is treated as a blip and retried next run regardless;
+    // backoff only kicks in once a source has failed back-to-back, so it
+    // doesn't slow down runs over a one-off transient error.
+    if failure_count < 2 {
+        return None;
+    }
+    let doublings = (failure_count - 2).clamp(0, 20) as u32;
+    let backoff = BASE_BACKOFF
+        .checked_mul(1 << doublings)
+        .unwrap_or(MAX_BACKOFF)
+        .min(MAX_BACKOFF);
+
+    let remaining = backoff - (Utc::now() - last_failure_at);
+    if remaining > Duration::zero() {
+        Some(remaining)
+    } else {
+        None
+    }
+}
+
+#[async_trait]
+pub trait UpdateService: Send + Sync {
+    async fn check_skill_update(&self, skill: &Skill) -> Result<SkillUpdateStatus>;
+
+    /// Checks every skill and, for any `Auto`-mode skill with an available
+    /// update, fetches the new content and persists it via the wired
+    /// `SkillService`; `Notify` skills are only reported, `Manual` ones left.
+    /// Checked for cancellation between skills (not mid-update) so a
+    /// Ctrl-C finishes the current skill cleanly rather than aborting it
+    /// partway through.
+    ///
+    /// A skill whose source has recently failed is skipped without being
+    /// checked at all, until its exponential backoff window elapses; pass
+    /// `force` to override this and check every skill regardless.
+    ///
+    /// `strict` controls what happens when fetched content looks like an
+    /// HTML error/login page instead of a skill body (e.g. a source behind
+    /// an auth wall returning 200 anyway): by default it's still applied
+    /// with a warning printed to stderr; with `strict` it's rejected and
+    /// counted in `failed` instead, same as a fetch error.
+    async fn update_all(
+        &self,
+        skills: &[Skill],
+        cancel: &CancellationToken,
+        force: bool,
+        strict: bool,
+    ) -> Result<UpdateAllSummary>;
+}
+
+pub struct UpdateServiceImpl {
+    github: Arc<dyn GitHubClient>,
+    skills: Arc<dyn SkillService>,
+}
+
+impl UpdateServiceImpl {
+    pub fn new(github: Arc<dyn GitHubClient>, skills: Arc<dyn SkillService>) -> Self {
+        Self { github, skills }
+    }
+}
+
+#[async_trait]
+impl UpdateService for UpdateServiceImpl {
+    async fn check_skill_update(&self, skill: &Skill) -> Result<SkillUpdateStatus> {
+        let SkillSource::GitHub {
+            owner, repo, ref_spec, ..
+        } = &skill.source
+        else {
+            return Err(CsmError::InvalidSource(
+                "update checks are only supported for GitHub sources".to_string(),
+            ));
+        };
+
+        let latest = match skill.update_trigger {
+            UpdateTrigger::OnCommit => self.github.latest_commit_sha(owner, repo, ref_spec).await?,
+            UpdateTrigger::OnTag | UpdateTrigger::OnRelease => {
+                match self.github.latest_tag(owner, repo).await? {
+                    Some(tag) => tag,
+                    None => return Ok(SkillUpdateStatus::UpToDate),
+                }
+            }
+        };
+
+        match &skill.last_known_ref {
+            Some(known) if known == &latest => Ok(SkillUpdateStatus::UpToDate),
+            _ => Ok(SkillUpdateStatus::UpdateAvailable { new_ref: latest }),
+        }
+    }
+
+    async fn update_all(
+        &self,
+        skills: &[Skill],
+        cancel: &CancellationToken,
+        force: bool,
+        strict: bool,
+    ) -> Result<UpdateAllSummary> {
+        let mut summary = UpdateAllSummary::default();
+
+        for skill in skills {
+            if cancel.is_cancelled() {
+                summary.interrupted = true;
+                break;
+            }
+
+            if !force && backoff_remaining(skill.failure_count, skill.last_failure_at).is_some() {
+                summary.skipped_backoff.push(skill.name.clone());
+                continue;
+            }
+
+            summary.checked += 1;
+
+            let status = match self.check_skill_update(skill).await {
+                Ok(status) => status,
+                Err(_) => {
+                    summary.failed.push(skill.name.clone());
+                    self.skills
+                        .record_update_result(&skill.name, skill.scope, true)
+                        .await?;
+                    continue;
+                }
+            };
+            let SkillUpdateStatus::UpdateAvailable { .. } = status else {
+                continue;
+            };
+
+            if skill.update_mode == UpdateMode::Manual {
+                continue;
+            }
+            if skill.update_mode == UpdateMode::Notify {
+                summary.notified.push(skill.name.clone());
+                continue;
+            }
+
+            let SkillSource::GitHub { owner, repo, path, ref_spec } = &skill.source else {
+                continue;
+            };
+
+            match self.github.fetch_file(owner, repo, path, ref_spec).await {
+                Ok(content) if strict && crate::utils::content_sanity::looks_like_html_error_page(&content) => {
+                    eprintln!(
+                        "warning: '{}' fetched what looks like an HTML error/login page, not a skill body; rejecting under --strict",
+                        skill.name
+                    );
+                    summary.failed.push(skill.name.clone());
+                    self.skills
+                        .record_update_result(&skill.name, skill.scope, true)
+                        .await?;
+                }
+                Ok(content) => {
+                    if crate::utils::content_sanity::looks_like_html_error_page(&content) {
+                        eprintln!(
+                            "warning: '{}' fetched what looks like an HTML error/login page, not a skill body",
+                            skill.name
+                        );
+                    }
+                    summary.bytes_changed += content.len();
+                    summary.changed.push(skill.name.clone());
+                    self.skills
+                        .update_content(&skill.name, skill.scope, content)
+                        .await?;
+                    if skill.failure_count > 0 {
+                        self.skills
+                            .record_update_result(&skill.name, skill.scope, false)
+                            .await?;
+                    }
+                }
+                Err(_) => {
+                    summary.failed.push(skill.name.clone());
+                    self.skills
+                        .record_update_result(&skill.name, skill.scope, true)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct FakeGitHub {
+        commit: &'static str,
+        tag: Option<&'static str>,
+        file_content: &'static str,
+        /// When set, every call that would hit the network fails, simulating
+        /// a persistently-erroring source (e.g. repeated 500s).
+        fails: bool,
+    }
+
+    #[async_trait]
+    impl GitHubClient for FakeGitHub {
+        async fn fetch_file(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _path: &str,
+            _r#ref: &str,
+        ) -> Result<String> {
+            if self.fails {
+                return Err(CsmError::Other("upstream error".to_string()));
+            }
+            Ok(self.file_content.to_string())
+        }
+
+        async fn latest_commit_sha(&self, _owner: &str, _repo: &str, _r#ref: &str) -> Result<String> {
+            if self.fails {
+                return Err(CsmError::Other("upstream error".to_string()));
+            }
+            Ok(self.commit.to_string())
+        }
+
+        async fn latest_tag(&self, _owner: &str, _repo: &str) -> Result<Option<String>> {
+            if self.fails {
+                return Err(CsmError::Other("upstream error".to_string()));
+            }
+            Ok(self.tag.map(str::to_string))
+        }
+
+        async fn commits_between(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _from: &str,
+            _to: &str,
+        ) -> Result<Vec<String>> {
+            Ok(Vec::new())
+        }
+
+        async fn list_refs(&self, _owner: &str, _repo: &str) -> Result<Vec<String>> {
+            Ok(Vec::new())
+        }
+
+        async fn fetch_directory(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _path: &str,
+            _ref_spec: &str,
+        ) -> Result<Vec<(String, String)>> {
+            unimplemented!()
+        }
+    }
+
+    fn skill_with(trigger: UpdateTrigger, last_known_ref: Option<&str>) -> Skill {
+        let now = chrono::Utc::now();
+        Skill {
+            id: 1,
+            name: "tracked".to_string(),
+            source: SkillSource::GitHub {
+                owner: "acme".to_string(),
+                repo: "skills".to_string(),
+                path: "SKILL.md".to_string(),
+                ref_spec: "main".to_string(),
+            },
+            scope: crate::models::SkillScope::Global,
+            content: "content".to_string(),
+            content_hash: "hash".to_string(),
+            previous_content: None,
+            enabled: true,
+            priority: 0,
+            update_mode: crate::models::UpdateMode::Auto,
+            update_trigger: trigger,
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: last_known_ref.map(str::to_string),
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    struct FakeSkillService {
+        updated: Mutex<Vec<(String, String)>>,
+        /// Simulates the persisted failure-tracking columns, keyed by name,
+        /// so a test can call `update_all` more than once and see the prior
+        /// call's `record_update_result` writes reflected in the next one.
+        failure_state: Mutex<std::collections::HashMap<String, (i32, Option<chrono::DateTime<Utc>>)>>,
+    }
+
+    impl FakeSkillService {
+        fn new() -> Self {
+            Self {
+                updated: Mutex::new(Vec::new()),
+                failure_state: Mutex::new(std::collections::HashMap::new()),
+            }
+        }
+
+        fn failure_state_of(&self, name: &str) -> (i32, Option<chrono::DateTime<Utc>>) {
+            self.failure_state
+                .lock()
+                .unwrap()
+                .get(name)
+                .cloned()
+                .unwrap_or((0, None))
+        }
+    }
+
+    #[async_trait]
+    impl SkillService for FakeSkillService {
+        async fn add(&self, _n: &str, _s: SkillSource, _sc: crate::models::SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_or_overwrite(&self, _n: &str, _s: SkillSource, _sc: crate::models::SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn add_with_content(
+            &self,
+            _n: &str,
+            _s: SkillSource,
+            _sc: crate::models::SkillScope,
+            _c: String,
+        ) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn list(&self) -> Result<Vec<Skill>> {
+            unimplemented!()
+        }
+        async fn update_content(
+            &self,
+            name: &str,
+            _scope: crate::models::SkillScope,
+            content: String,
+        ) -> Result<Skill> {
+            self.updated.lock().unwrap().push((name.to_string(), content));
+            Ok(skill_with(UpdateTrigger::OnCommit, None))
+        }
+        async fn merge_preview(
+            &self,
+            _scope: Option<crate::models::SkillScope>,
+        ) -> Result<crate::models::MergePreviewStats> {
+            unimplemented!()
+        }
+        async fn effective_list(&self) -> Result<Vec<Skill>> {
+            unimplemented!()
+        }
+        async fn set_note(
+            &self,
+            _n: &str,
+            _sc: crate::models::SkillScope,
+            _note: Option<String>,
+        ) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn archive(&self, _n: &str, _sc: crate::models::SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn restore(&self, _n: &str, _sc: crate::models::SkillScope) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn purge(&self, _n: &str, _sc: crate::models::SkillScope) -> Result<()> {
+            unimplemented!()
+        }
+        async fn rename(&self, _n: &str, _sc: crate::models::SkillScope, _new: &str) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_tags(&self, _n: &str, _sc: crate::models::SkillScope, _tags: Vec<String>) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_priority(&self, _n: &str, _sc: crate::models::SkillScope, _p: i32) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn set_enabled(&self, _n: &str, _sc: crate::models::SkillScope, _e: bool) -> Result<Skill> {
+            unimplemented!()
+        }
+        async fn record_update_result(&self, name: &str, _sc: crate::models::SkillScope, failed: bool) -> Result<Skill> {
+            let mut state = self.failure_state.lock().unwrap();
+            let entry = state.entry(name.to_string()).or_insert((0, None));
+            if failed {
+                entry.0 += 1;
+                entry.1 = Some(Utc::now());
+            } else {
+                *entry = (0, None);
+            }
+            Ok(skill_with(UpdateTrigger::OnCommit, None))
+        }
+        async fn rollback_content(&self, _n: &str, _sc: crate::models::SkillScope) -> Result<bool> {
+            unimplemented!()
+        }
+
+    }
+
+    #[tokio::test]
+    async fn on_tag_skill_ignores_a_new_commit_with_no_new_tag() {
+        let service = UpdateServiceImpl::new(
+            Arc::new(FakeGitHub {
+                commit: "deadbeef",
+                tag: Some("v1.0.0"),
+                file_content: "",
+                fails: false,
+            }),
+            Arc::new(FakeSkillService::new()),
+        );
+        let skill = skill_with(UpdateTrigger::OnTag, Some("v1.0.0"));
+
+        let status = service.check_skill_update(&skill).await.unwrap();
+
+        assert_eq!(status, SkillUpdateStatus::UpToDate);
+    }
+
+    #[tokio::test]
+    async fn on_tag_skill_reports_a_new_tag() {
+        let service = UpdateServiceImpl::new(
+            Arc::new(FakeGitHub {
+                commit: "deadbeef",
+                tag: Some("v1.1.0"),
+                file_content: "",
+                fails: false,
+            }),
+            Arc::new(FakeSkillService::new()),
+        );
+        let skill = skill_with(UpdateTrigger::OnTag, Some("v1.0.0"));
+
+        let status = service.check_skill_update(&skill).await.unwrap();
+
+        assert_eq!(
+            status,
+            SkillUpdateStatus::UpdateAvailable {
+                new_ref: "v1.1.0".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn update_all_aggregates_checked_changed_and_bytes_changed() {
+        let github = Arc::new(FakeGitHub {
+            commit: "deadbeef",
+            tag: None,
+            file_content: "fresh content",
+            fails: false,
+        });
+        let skills_service = Arc::new(FakeSkillService::new());
+        let service = UpdateServiceImpl::new(github, skills_service.clone());
+
+        let up_to_date = {
+            let mut s = skill_with(UpdateTrigger::OnCommit, Some("deadbeef"));
+            s.name = "up-to-date".to_string();
+            s
+        };
+        let stale = {
+            let mut s = skill_with(UpdateTrigger::OnCommit, Some("old-sha"));
+            s.name = "stale".to_string();
+            s
+        };
+
+        let summary = service
+            .update_all(&[up_to_date, stale], &CancellationToken::default(), false, false)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.checked, 2);
+        assert_eq!(summary.changed, vec!["stale".to_string()]);
+        assert_eq!(summary.bytes_changed, "fresh content".len());
+        assert!(!summary.interrupted);
+        assert_eq!(skills_service.updated.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn a_notify_mode_skill_is_reported_but_not_applied_while_auto_still_updates() {
+        let github = Arc::new(FakeGitHub {
+            commit: "deadbeef",
+            tag: None,
+            file_content: "fresh content",
+            fails: false,
+        });
+        let skills_service = Arc::new(FakeSkillService::new());
+        let service = UpdateServiceImpl::new(github, skills_service.clone());
+
+        let auto = {
+            let mut s = skill_with(UpdateTrigger::OnCommit, Some("old-sha"));
+            s.name = "auto-skill".to_string();
+            s.update_mode = UpdateMode::Auto;
+            s
+        };
+        let notify = {
+            let mut s = skill_with(UpdateTrigger::OnCommit, Some("old-sha"));
+            s.name = "notify-skill".to_string();
+            s.update_mode = UpdateMode::Notify;
+            s
+        };
+
+        let summary = service
+            .update_all(&[auto, notify], &CancellationToken::default(), false, false)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.changed, vec!["auto-skill".to_string()]);
+        assert_eq!(summary.notified, vec!["notify-skill".to_string()]);
+        assert_eq!(skills_service.updated.lock().unwrap().len(), 1);
+        assert_eq!(skills_service.updated.lock().unwrap()[0].0, "auto-skill");
+    }
+
+    #[tokio::test]
+    async fn cancelling_before_a_skill_stops_the_loop_and_marks_it_interrupted() {
+        let github = Arc::new(FakeGitHub {
+            commit: "deadbeef",
+            tag: None,
+            file_content: "fresh content",
+            fails: false,
+        });
+        let skills_service = Arc::new(FakeSkillService::new());
+        let service = UpdateServiceImpl::new(github, skills_service.clone());
+
+        let first = {
+            let mut s = skill_with(UpdateTrigger::OnCommit, Some("old-sha"));
+            s.name = "first".to_string();
+            s
+        };
+        let second = {
+            let mut s = skill_with(UpdateTrigger::OnCommit, Some("old-sha"));
+            s.name = "second".to_string();
+            s
+        };
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        let summary = service.update_all(&[first, second], &cancel, false, false).await.unwrap();
+
+        assert!(summary.interrupted);
+        assert_eq!(summary.checked, 0);
+        assert!(skills_service.updated.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_skill_whose_check_errors_is_reported_in_failed() {
+        let github = Arc::new(FakeGitHub {
+            commit: "deadbeef",
+            tag: None,
+            file_content: "fresh content",
+            fails: true,
+        });
+        let skills_service = Arc::new(FakeSkillService::new());
+        let service = UpdateServiceImpl::new(github, skills_service.clone());
+
+        let mut failing = skill_with(UpdateTrigger::OnCommit, Some("old-sha"));
+        failing.name = "flaky".to_string();
+
+        let summary = service
+            .update_all(&[failing], &CancellationToken::default(), false, false)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.failed, vec!["flaky".to_string()]);
+        assert!(summary.changed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_skill_failing_twice_is_skipped_on_the_next_immediate_run() {
+        let github = Arc::new(FakeGitHub {
+            commit: "deadbeef",
+            tag: None,
+            file_content: "fresh content",
+            fails: true,
+        });
+        let skills_service = Arc::new(FakeSkillService::new());
+        let service = UpdateServiceImpl::new(github, skills_service.clone());
+
+        let mut failing = skill_with(UpdateTrigger::OnCommit, Some("old-sha"));
+        failing.name = "flaky".to_string();
+
+        // First failure: the source is still checked (a single failure is a
+        // blip, not yet a backoff trigger).
+        let first = service
+            .update_all(&[failing.clone()], &CancellationToken::default(), false, false)
+            .await
+            .unwrap();
+        assert_eq!(first.checked, 1);
+        assert!(first.skipped_backoff.is_empty());
+        let (count, last_failure_at) = skills_service.failure_state_of("flaky");
+        failing.failure_count = count;
+        failing.last_failure_at = last_failure_at;
+        assert_eq!(failing.failure_count, 1);
+
+        // Second consecutive failure trips the backoff.
+        let second = service
+            .update_all(&[failing.clone()], &CancellationToken::default(), false, false)
+            .await
+            .unwrap();
+        assert_eq!(second.checked, 1);
+        assert!(second.skipped_backoff.is_empty());
+        let (count, last_failure_at) = skills_service.failure_state_of("flaky");
+        failing.failure_count = count;
+        failing.last_failure_at = last_failure_at;
+        assert_eq!(failing.failure_count, 2);
+
+        // A third, immediate run is skipped without hitting the source at all.
+        let third = service
+            .update_all(&[failing.clone()], &CancellationToken::default(), false, false)
+            .await
+            .unwrap();
+        assert_eq!(third.checked, 0);
+        assert_eq!(third.skipped_backoff, vec!["flaky".to_string()]);
+
+        // `--force` overrides the backoff.
+        let forced = service
+            .update_all(&[failing], &CancellationToken::default(), true, false)
+            .await
+            .unwrap();
+        assert_eq!(forced.checked, 1);
+        assert!(forced.skipped_backoff.is_empty());
+    }
+
+    #[tokio::test]
+    async fn an_html_error_page_is_applied_with_a_warning_by_default() {
+        let github = Arc::new(FakeGitHub {
+            commit: "deadbeef",
+            tag: None,
+            file_content: "<!DOCTYPE html><html><body>Please sign in</body></html>",
+            fails: false,
+        });
+        let skills_service = Arc::new(FakeSkillService::new());
+        let service = UpdateServiceImpl::new(github, skills_service.clone());
+
+        let mut stale = skill_with(UpdateTrigger::OnCommit, Some("old-sha"));
+        stale.name = "wall".to_string();
+
+        let summary = service
+            .update_all(&[stale], &CancellationToken::default(), false, false)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.changed, vec!["wall".to_string()]);
+        assert!(summary.failed.is_empty());
+        assert_eq!(skills_service.updated.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn strict_mode_rejects_an_html_error_page_instead_of_applying_it() {
+        let github = Arc::new(FakeGitHub {
+            commit: "deadbeef",
+            tag: None,
+            file_content: "<!DOCTYPE html><html><body>Please sign in</body></html>",
+            fails: false,
+        });
+        let skills_service = Arc::new(FakeSkillService::new());
+        let service = UpdateServiceImpl::new(github, skills_service.clone());
+
+        let mut stale = skill_with(UpdateTrigger::OnCommit, Some("old-sha"));
+        stale.name = "wall".to_string();
+
+        let summary = service
+            .update_all(&[stale], &CancellationToken::default(), false, true)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.failed, vec!["wall".to_string()]);
+        assert!(summary.changed.is_empty());
+        assert!(skills_service.updated.lock().unwrap().is_empty());
+    }
+}
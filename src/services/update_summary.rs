@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::github::GitHubClient;
+use crate::models::{Skill, SkillSource};
+
+/// Commit messages between the stored and latest ref, grouped by their
+/// Conventional Commits type (`feat`, `fix`, `chore`, ...); anything that
+/// doesn't match a known type is grouped under `"other"`.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateSummary {
+    pub by_type: BTreeMap<String, Vec<String>>,
+}
+
+const KNOWN_TYPES: &[&str] = &["feat", "fix", "chore", "docs", "refactor", "test", "perf"];
+
+fn commit_type(message: &str) -> String {
+    if let Some((prefix, _)) = message.split_once(':') {
+        let candidate = prefix.split('(').next().unwrap_or(prefix).trim();
+        if KNOWN_TYPES.contains(&candidate) {
+            return candidate.to_string();
+        }
+    }
+    "other".to_string()
+}
+
+pub async fn summarize_update(
+    github: &Arc<dyn GitHubClient>,
+    skill: &Skill,
+    new_ref: &str,
+) -> Result<UpdateSummary> {
+    let SkillSource::GitHub { owner, repo, .. } = &skill.source else {
+        return Ok(UpdateSummary::default());
+    };
+    let from = skill.last_known_ref.as_deref().unwrap_or(new_ref);
+
+    let messages = github.commits_between(owner, repo, from, new_ref).await?;
+    let mut summary = UpdateSummary::default();
+    for message in messages {
+        summary
+            .by_type
+            .entry(commit_type(&message))
+            .or_default()
+            .push(message);
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_conventional_and_unconventional_messages() {
+        let mut summary = UpdateSummary::default();
+        for message in ["feat: add x", "fix: bug", "tidy up whitespace"] {
+            summary
+                .by_type
+                .entry(commit_type(message))
+                .or_default()
+                .push(message.to_string());
+        }
+
+        assert_eq!(summary.by_type["feat"], vec!["feat: add x"]);
+        assert_eq!(summary.by_type["fix"], vec!["fix: bug"]);
+        assert_eq!(summary.by_type["other"], vec!["tidy up whitespace"]);
+    }
+}
@@ -0,0 +1,229 @@
+//! Shared `SkillService` test double, used by `cli::*`'s own test modules
+//! in place of hand-rolled `Fake*` stubs that each panicked on every method
+//! but the one or two their test needed. Real CRUD over an in-memory
+//! `Vec<Skill>`, matching `SkillServiceImpl`'s semantics minus the
+//! content-fetching and merge side effects (no fetcher/merger to call).
+//!
+//! Not every `cli::*` fake was migrated here: `add`, `update`, `import`,
+//! `doctor`, and `sync` hard-code bespoke fixture behavior for their own
+//! tests (fixed "original content" on `add`, injectable corrupt rows,
+//! a `MergeService` double, etc.) that doesn't fit this shared double
+//! without either losing that behavior or growing the double well past
+//! what a plain in-memory stub should carry; those keep their local
+//! `Fake*` types.
+
+#![cfg(test)]
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::error::{CsmError, Result};
+use crate::models::{MergePreviewStats, Skill, SkillScope, SkillSource};
+use crate::services::SkillService;
+
+pub(crate) struct StubSkillService {
+    skills: Mutex<Vec<Skill>>,
+}
+
+impl StubSkillService {
+    pub(crate) fn new(skills: Vec<Skill>) -> Self {
+        Self {
+            skills: Mutex::new(skills),
+        }
+    }
+
+    /// Current stored skills, for assertions after a mutating call.
+    pub(crate) fn skills(&self) -> Vec<Skill> {
+        self.skills.lock().unwrap().clone()
+    }
+
+    fn find(&self, name: &str, scope: SkillScope) -> Result<Skill> {
+        self.skills
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.name == name && s.scope == scope)
+            .cloned()
+            .ok_or_else(|| CsmError::NotFound(name.to_string()))
+    }
+
+    fn replace(&self, skill: Skill) -> Skill {
+        let mut skills = self.skills.lock().unwrap();
+        if let Some(existing) = skills.iter_mut().find(|s| s.id == skill.id) {
+            *existing = skill.clone();
+        }
+        skill
+    }
+}
+
+#[async_trait]
+impl SkillService for StubSkillService {
+    async fn add(&self, _name: &str, _source: SkillSource, _scope: SkillScope) -> Result<Skill> {
+        unimplemented!("StubSkillService has no fetcher; use add_with_content in tests")
+    }
+
+    async fn add_or_overwrite(&self, _name: &str, _source: SkillSource, _scope: SkillScope) -> Result<Skill> {
+        unimplemented!("StubSkillService has no fetcher; use add_with_content in tests")
+    }
+
+    async fn add_with_content(
+        &self,
+        name: &str,
+        source: SkillSource,
+        scope: SkillScope,
+        content: String,
+    ) -> Result<Skill> {
+        if self.skills.lock().unwrap().iter().any(|s| s.name == name && s.scope == scope) {
+            return Err(CsmError::AlreadyExists(name.to_string()));
+        }
+        let now = chrono::Utc::now();
+        let mut skills = self.skills.lock().unwrap();
+        let skill = Skill {
+            id: skills.len() as i64 + 1,
+            name: name.to_string(),
+            source,
+            scope,
+            content_hash: crate::utils::hash::hash_content(&content),
+            previous_content: None,
+            content,
+            enabled: true,
+            priority: 0,
+            update_mode: crate::models::UpdateMode::default(),
+            update_trigger: crate::models::UpdateTrigger::default(),
+            failure_count: 0,
+            last_failure_at: None,
+            archived: false,
+            archived_at: None,
+            last_known_ref: None,
+            notes: None,
+            tags: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        };
+        skills.push(skill.clone());
+        Ok(skill)
+    }
+
+    async fn list(&self) -> Result<Vec<Skill>> {
+        Ok(self.skills())
+    }
+
+    async fn update_content(&self, name: &str, scope: SkillScope, content: String) -> Result<Skill> {
+        let mut skill = self.find(name, scope)?;
+        skill.previous_content = Some(skill.content.clone());
+        skill.content_hash = crate::utils::hash::hash_content(&content);
+        skill.content = content;
+        skill.updated_at = chrono::Utc::now();
+        Ok(self.replace(skill))
+    }
+
+    async fn merge_preview(&self, scope: Option<SkillScope>) -> Result<MergePreviewStats> {
+        let mut stats = MergePreviewStats::default();
+        for skill in self
+            .skills()
+            .into_iter()
+            .filter(|s| !s.archived)
+            .filter(|s| scope.map_or(true, |sc| sc == s.scope))
+        {
+            stats.skill_count += 1;
+            if skill.enabled {
+                stats.enabled_count += 1;
+                stats.total_bytes += skill.content.len();
+            }
+        }
+        Ok(stats)
+    }
+
+    async fn effective_list(&self) -> Result<Vec<Skill>> {
+        Ok(self.skills().into_iter().filter(|s| !s.archived).collect())
+    }
+
+    async fn set_note(&self, name: &str, scope: SkillScope, note: Option<String>) -> Result<Skill> {
+        let mut skill = self.find(name, scope)?;
+        skill.notes = note;
+        skill.updated_at = chrono::Utc::now();
+        Ok(self.replace(skill))
+    }
+
+    async fn archive(&self, name: &str, scope: SkillScope) -> Result<Skill> {
+        let mut skill = self.find(name, scope)?;
+        skill.archived = true;
+        skill.archived_at = Some(chrono::Utc::now());
+        skill.updated_at = chrono::Utc::now();
+        Ok(self.replace(skill))
+    }
+
+    async fn restore(&self, name: &str, scope: SkillScope) -> Result<Skill> {
+        let mut skill = self.find(name, scope)?;
+        skill.archived = false;
+        skill.archived_at = None;
+        skill.updated_at = chrono::Utc::now();
+        Ok(self.replace(skill))
+    }
+
+    async fn purge(&self, name: &str, scope: SkillScope) -> Result<()> {
+        let skill = self.find(name, scope)?;
+        self.skills.lock().unwrap().retain(|s| s.id != skill.id);
+        Ok(())
+    }
+
+    async fn rename(&self, name: &str, scope: SkillScope, new_name: &str) -> Result<Skill> {
+        if new_name.trim().is_empty() {
+            return Err(CsmError::InvalidName(new_name.to_string()));
+        }
+        if new_name != name && self.skills.lock().unwrap().iter().any(|s| s.name == new_name && s.scope == scope) {
+            return Err(CsmError::AlreadyExists(new_name.to_string()));
+        }
+        let mut skill = self.find(name, scope)?;
+        skill.name = new_name.to_string();
+        skill.updated_at = chrono::Utc::now();
+        Ok(self.replace(skill))
+    }
+
+    async fn set_tags(&self, name: &str, scope: SkillScope, tags: Vec<String>) -> Result<Skill> {
+        let mut skill = self.find(name, scope)?;
+        skill.tags = tags;
+        skill.updated_at = chrono::Utc::now();
+        Ok(self.replace(skill))
+    }
+
+    async fn set_priority(&self, name: &str, scope: SkillScope, priority: i32) -> Result<Skill> {
+        let mut skill = self.find(name, scope)?;
+        skill.priority = priority;
+        skill.updated_at = chrono::Utc::now();
+        Ok(self.replace(skill))
+    }
+
+    async fn set_enabled(&self, name: &str, scope: SkillScope, enabled: bool) -> Result<Skill> {
+        let mut skill = self.find(name, scope)?;
+        skill.enabled = enabled;
+        skill.updated_at = chrono::Utc::now();
+        Ok(self.replace(skill))
+    }
+
+    async fn record_update_result(&self, name: &str, scope: SkillScope, failed: bool) -> Result<Skill> {
+        let mut skill = self.find(name, scope)?;
+        if failed {
+            skill.failure_count += 1;
+            skill.last_failure_at = Some(chrono::Utc::now());
+        } else {
+            skill.failure_count = 0;
+            skill.last_failure_at = None;
+        }
+        skill.updated_at = chrono::Utc::now();
+        Ok(self.replace(skill))
+    }
+
+    async fn rollback_content(&self, name: &str, scope: SkillScope) -> Result<bool> {
+        let mut skill = self.find(name, scope)?;
+        let Some(previous) = skill.previous_content.take() else {
+            return Ok(false);
+        };
+        skill.content_hash = crate::utils::hash::hash_content(&previous);
+        skill.content = previous;
+        skill.updated_at = chrono::Utc::now();
+        self.replace(skill);
+        Ok(true)
+    }
+}
@@ -0,0 +1,101 @@
+use std::io::Read;
+
+use crate::error::{CsmError, Result};
+
+/// A single skill file extracted from an archive, keyed by its file stem.
+pub struct ArchiveEntry {
+    pub name: String,
+    pub content: String,
+}
+
+/// Extracts every markdown file from a zip or gzipped tarball of skills.
+pub fn extract_skills(bytes: &[u8], filename: &str) -> Result<Vec<ArchiveEntry>> {
+    if filename.ends_with(".zip") {
+        extract_from_zip(bytes)
+    } else if filename.ends_with(".tar.gz") || filename.ends_with(".tgz") {
+        extract_from_tarball(bytes)
+    } else {
+        Err(CsmError::InvalidSource(format!(
+            "unsupported archive format: {filename}"
+        )))
+    }
+}
+
+fn extract_from_zip(bytes: &[u8]) -> Result<Vec<ArchiveEntry>> {
+    let reader = std::io::Cursor::new(bytes);
+    let mut archive =
+        zip::ZipArchive::new(reader).map_err(|e| CsmError::InvalidSource(e.to_string()))?;
+
+    let mut entries = Vec::new();
+    for i in 0..archive.len() {
+        let mut file = archive
+            .by_index(i)
+            .map_err(|e| CsmError::InvalidSource(e.to_string()))?;
+        if !file.name().ends_with(".md") {
+            continue;
+        }
+        let name = skill_name_from_path(file.name());
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+        entries.push(ArchiveEntry { name, content });
+    }
+    Ok(entries)
+}
+
+fn extract_from_tarball(bytes: &[u8]) -> Result<Vec<ArchiveEntry>> {
+    let decompressed = flate2::read::GzDecoder::new(bytes);
+    let mut archive = tar::Archive::new(decompressed);
+
+    let mut entries = Vec::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.to_string_lossy().to_string();
+        if !path.ends_with(".md") {
+            continue;
+        }
+        let name = skill_name_from_path(&path);
+        let mut content = String::new();
+        entry.read_to_string(&mut content)?;
+        entries.push(ArchiveEntry { name, content });
+    }
+    Ok(entries)
+}
+
+fn skill_name_from_path(path: &str) -> String {
+    std::path::Path::new(path)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn extracts_only_markdown_entries_from_a_zip() {
+        let mut buffer = Vec::new();
+        {
+            let mut writer = zip::ZipWriter::new(std::io::Cursor::new(&mut buffer));
+            let options: zip::write::FileOptions<()> = zip::write::FileOptions::default();
+            writer.start_file("skill-a.md", options).unwrap();
+            writer.write_all(b"# skill a").unwrap();
+            writer.start_file("README.txt", options).unwrap();
+            writer.write_all(b"not a skill").unwrap();
+            writer.finish().unwrap();
+        }
+
+        let entries = extract_skills(&buffer, "bundle.zip").unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "skill-a");
+        assert_eq!(entries[0].content, "# skill a");
+    }
+
+    #[test]
+    fn rejects_unsupported_extensions() {
+        let err = extract_skills(&[], "bundle.rar").unwrap_err();
+        assert!(matches!(err, CsmError::InvalidSource(_)));
+    }
+}
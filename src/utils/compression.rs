@@ -0,0 +1,85 @@
+use std::io::{Read, Write};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use crate::error::{CsmError, Result};
+
+/// gzip's two-byte magic number, used to tell compressed content apart from
+/// plain UTF-8 written before `compress_content` was ever enabled.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Compresses `content` with gzip when `enabled` and it's at least
+/// `threshold_bytes`, so small skills aren't paid the framing overhead.
+/// Returns the content's own UTF-8 bytes unchanged otherwise.
+pub fn maybe_compress(content: &str, enabled: bool, threshold_bytes: usize) -> Result<Vec<u8>> {
+    if !enabled || content.len() < threshold_bytes {
+        return Ok(content.as_bytes().to_vec());
+    }
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(content.as_bytes())
+        .map_err(|e| CsmError::Other(format!("failed to compress content: {e}")))?;
+    encoder
+        .finish()
+        .map_err(|e| CsmError::Other(format!("failed to compress content: {e}")))
+}
+
+/// Reverses `maybe_compress`. Bytes that don't start with the gzip magic
+/// number are assumed to already be plain UTF-8 (either compression was
+/// never enabled, or the content fell under the threshold), so reading
+/// content written before compression was turned on still works.
+pub fn maybe_decompress(bytes: &[u8]) -> Result<String> {
+    if !bytes.starts_with(&GZIP_MAGIC) {
+        return String::from_utf8(bytes.to_vec())
+            .map_err(|e| CsmError::Other(format!("stored content is not valid UTF-8: {e}")));
+    }
+
+    let mut decoder = GzDecoder::new(bytes);
+    let mut out = String::new();
+    decoder
+        .read_to_string(&mut out)
+        .map_err(|e| CsmError::Other(format!("failed to decompress content: {e}")))?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::hash::hash_content;
+
+    #[test]
+    fn round_trips_compressed_content_and_preserves_the_uncompressed_hash() {
+        let content = "line of skill content\n".repeat(500);
+        let original_hash = hash_content(&content);
+
+        let compressed = maybe_compress(&content, true, 4096).unwrap();
+        assert!(compressed.len() < content.len());
+
+        let decompressed = maybe_decompress(&compressed).unwrap();
+
+        assert_eq!(decompressed, content);
+        assert_eq!(hash_content(&decompressed), original_hash);
+    }
+
+    #[test]
+    fn content_under_the_threshold_is_left_uncompressed() {
+        let content = "short";
+
+        let stored = maybe_compress(content, true, 4096).unwrap();
+
+        assert_eq!(stored, content.as_bytes());
+        assert_eq!(maybe_decompress(&stored).unwrap(), content);
+    }
+
+    #[test]
+    fn compression_disabled_leaves_content_uncompressed_even_above_the_threshold() {
+        let content = "x".repeat(10_000);
+
+        let stored = maybe_compress(&content, false, 4096).unwrap();
+
+        assert_eq!(stored, content.as_bytes());
+    }
+}
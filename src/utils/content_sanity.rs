@@ -0,0 +1,33 @@
+/// Crude signal that fetched content is an HTML page (e.g. a login wall or
+/// a proxy error page returned with a 200 status) rather than a markdown
+/// skill body: it carries the usual HTML document markers and has no
+/// markdown heading of its own to suggest otherwise.
+pub fn looks_like_html_error_page(content: &str) -> bool {
+    let lower = content.to_lowercase();
+    let has_html_markers =
+        lower.contains("<!doctype html") || lower.contains("<html") || lower.contains("<body");
+    has_html_markers && !content.contains('#')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_html_login_wall_is_flagged() {
+        let body = "<!DOCTYPE html><html><body><h1>Please sign in</h1></body></html>";
+        assert!(looks_like_html_error_page(body));
+    }
+
+    #[test]
+    fn plain_markdown_is_not_flagged() {
+        let body = "# My Skill\n\nDo the thing carefully.\n";
+        assert!(!looks_like_html_error_page(body));
+    }
+
+    #[test]
+    fn markdown_that_happens_to_embed_a_snippet_of_html_is_not_flagged() {
+        let body = "# My Skill\n\nExample: `<body>` is the root element.\n";
+        assert!(!looks_like_html_error_page(body));
+    }
+}
@@ -0,0 +1,229 @@
+use serde::Serialize;
+
+/// Whether a diffed line was removed from `a`, added in `b`, or unchanged
+/// context shared by both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffLineKind {
+    Context,
+    Removed,
+    Added,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub text: String,
+}
+
+/// Line-by-line diff of `a` against `b`, using a longest-common-subsequence
+/// to find the minimal set of removed/added lines around shared context.
+pub fn diff_lines(a: &str, b: &str) -> Vec<DiffLine> {
+    let a_lines: Vec<&str> = a.lines().collect();
+    let b_lines: Vec<&str> = b.lines().collect();
+
+    let lcs = longest_common_subsequence(&a_lines, &b_lines);
+
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    for (li, lj) in lcs {
+        while i < li {
+            result.push(DiffLine { kind: DiffLineKind::Removed, text: a_lines[i].to_string() });
+            i += 1;
+        }
+        while j < lj {
+            result.push(DiffLine { kind: DiffLineKind::Added, text: b_lines[j].to_string() });
+            j += 1;
+        }
+        result.push(DiffLine { kind: DiffLineKind::Context, text: a_lines[li].to_string() });
+        i = li + 1;
+        j = lj + 1;
+    }
+    while i < a_lines.len() {
+        result.push(DiffLine { kind: DiffLineKind::Removed, text: a_lines[i].to_string() });
+        i += 1;
+    }
+    while j < b_lines.len() {
+        result.push(DiffLine { kind: DiffLineKind::Added, text: b_lines[j].to_string() });
+        j += 1;
+    }
+    result
+}
+
+/// Trims `lines` down to at most `context` lines of unchanged context
+/// immediately surrounding each run of removed/added lines, dropping any
+/// context further from a change than that. `context: 0` keeps only the
+/// changed lines themselves. A run of dropped context collapses to a single
+/// context line noting how many were skipped, so a reader can tell a gap
+/// from a diff with no further changes.
+pub fn trim_context(lines: Vec<DiffLine>, context: usize) -> Vec<DiffLine> {
+    let keep: Vec<bool> = (0..lines.len())
+        .map(|i| {
+            if lines[i].kind != DiffLineKind::Context {
+                return true;
+            }
+            (i.saturating_sub(context)..=i + context).any(|j| {
+                lines.get(j).is_some_and(|l| l.kind != DiffLineKind::Context)
+            })
+        })
+        .collect();
+
+    let mut result = Vec::new();
+    let mut omitted = 0usize;
+    for (line, &keep) in lines.into_iter().zip(keep.iter()) {
+        if keep {
+            if omitted > 0 {
+                result.push(DiffLine {
+                    kind: DiffLineKind::Context,
+                    text: format!("... {omitted} line(s) omitted ..."),
+                });
+                omitted = 0;
+            }
+            result.push(line);
+        } else {
+            omitted += 1;
+        }
+    }
+    if omitted > 0 {
+        result.push(DiffLine {
+            kind: DiffLineKind::Context,
+            text: format!("... {omitted} line(s) omitted ..."),
+        });
+    }
+    result
+}
+
+/// Renders `diff_lines(a, b)` as unified-diff-style text: ` ` for context,
+/// `-`/`+` for removed/added lines. No hunk headers or surrounding-context
+/// trimming, since callers here always want the full picture rather than a
+/// patch to apply. When `a` is empty (e.g. no file exists yet), every line
+/// of `b` shows up as an addition.
+pub fn unified_diff(a: &str, b: &str) -> String {
+    diff_lines(a, b)
+        .into_iter()
+        .map(|line| match line.kind {
+            DiffLineKind::Context => format!(" {}\n", line.text),
+            DiffLineKind::Removed => format!("-{}\n", line.text),
+            DiffLineKind::Added => format!("+{}\n", line.text),
+        })
+        .collect()
+}
+
+/// Returns the indices `(i, j)` of each line in the longest common
+/// subsequence between `a` and `b`, in order.
+fn longest_common_subsequence(a: &[&str], b: &[&str]) -> Vec<(usize, usize)> {
+    let (n, m) = (a.len(), b.len());
+    let mut table = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[i][j] = if a[i] == b[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_content_is_all_context() {
+        let lines = diff_lines("a\nb\nc", "a\nb\nc");
+        assert!(lines.iter().all(|l| l.kind == DiffLineKind::Context));
+    }
+
+    #[test]
+    fn a_changed_line_shows_up_as_a_removal_and_an_addition() {
+        let lines = diff_lines("a\nb\nc", "a\nx\nc");
+
+        assert_eq!(
+            lines,
+            vec![
+                DiffLine { kind: DiffLineKind::Context, text: "a".to_string() },
+                DiffLine { kind: DiffLineKind::Removed, text: "b".to_string() },
+                DiffLine { kind: DiffLineKind::Added, text: "x".to_string() },
+                DiffLine { kind: DiffLineKind::Context, text: "c".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn trailing_added_lines_are_reported() {
+        let lines = diff_lines("a", "a\nb\nc");
+
+        assert_eq!(
+            lines,
+            vec![
+                DiffLine { kind: DiffLineKind::Context, text: "a".to_string() },
+                DiffLine { kind: DiffLineKind::Added, text: "b".to_string() },
+                DiffLine { kind: DiffLineKind::Added, text: "c".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn trim_context_keeps_only_the_requested_number_of_surrounding_lines() {
+        let lines = diff_lines("a\nb\nc\nd\ne\nf\ng", "a\nb\nc\nX\ne\nf\ng");
+        let trimmed = trim_context(lines, 1);
+
+        assert_eq!(
+            trimmed,
+            vec![
+                DiffLine { kind: DiffLineKind::Context, text: "... 2 line(s) omitted ...".to_string() },
+                DiffLine { kind: DiffLineKind::Context, text: "c".to_string() },
+                DiffLine { kind: DiffLineKind::Removed, text: "d".to_string() },
+                DiffLine { kind: DiffLineKind::Added, text: "X".to_string() },
+                DiffLine { kind: DiffLineKind::Context, text: "e".to_string() },
+                DiffLine { kind: DiffLineKind::Context, text: "... 2 line(s) omitted ...".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn trim_context_zero_keeps_only_changed_lines() {
+        let lines = diff_lines("a\nb\nc", "a\nx\nc");
+        let trimmed = trim_context(lines, 0);
+
+        assert_eq!(
+            trimmed,
+            vec![
+                DiffLine { kind: DiffLineKind::Context, text: "... 1 line(s) omitted ...".to_string() },
+                DiffLine { kind: DiffLineKind::Removed, text: "b".to_string() },
+                DiffLine { kind: DiffLineKind::Added, text: "x".to_string() },
+                DiffLine { kind: DiffLineKind::Context, text: "... 1 line(s) omitted ...".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn unified_diff_prefixes_removed_and_added_lines() {
+        let text = unified_diff("a\nb\nc", "a\nx\nc");
+
+        assert_eq!(text, " a\n-b\n+x\n c\n");
+    }
+
+    #[test]
+    fn unified_diff_against_empty_content_shows_everything_as_additions() {
+        let text = unified_diff("", "a\nb");
+
+        assert_eq!(text, "+a\n+b\n");
+    }
+}
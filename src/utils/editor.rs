@@ -0,0 +1,16 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::error::{CsmError, Result};
+
+/// Opens `path` in `$EDITOR` (falling back to `vi`), waiting for it to exit.
+pub fn launch_editor(path: &Path) -> Result<()> {
+    let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+    let status = Command::new(&editor).arg(path).status()?;
+    if !status.success() {
+        return Err(CsmError::Other(format!(
+            "editor '{editor}' exited with {status}"
+        )));
+    }
+    Ok(())
+}
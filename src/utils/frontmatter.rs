@@ -0,0 +1,111 @@
+/// Metadata parsed from a skill's leading YAML frontmatter block.
+///
+/// `description` is parsed for completeness but currently has nowhere to
+/// go: `Skill` has no `description` field, so callers only apply `tags`
+/// and `priority`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Meta {
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub priority: Option<i32>,
+}
+
+/// Parses a leading `---\n...\n---\n` frontmatter block off `content` and
+/// returns it alongside the remaining body with the block stripped.
+///
+/// Recognizes `description: <text>`, `tags: [a, b, c]`, and `priority: <n>`
+/// keys; any other key is ignored. If `content` doesn't open with a
+/// frontmatter block, or the block has no closing `---`, this returns
+/// `(None, content)` unchanged.
+pub fn parse(content: &str) -> (Option<Meta>, String) {
+    let Some(rest) = content.strip_prefix("---\n") else {
+        return (None, content.to_string());
+    };
+
+    let Some(end) = rest.find("\n---") else {
+        return (None, content.to_string());
+    };
+
+    let block = &rest[..end];
+    let after_marker = &rest[end + "\n---".len()..];
+    let body = after_marker.strip_prefix('\n').unwrap_or(after_marker);
+
+    let mut meta = Meta::default();
+    for line in block.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+        match key {
+            "description" => meta.description = Some(value.trim_matches('"').to_string()),
+            "priority" => meta.priority = value.parse().ok(),
+            "tags" => meta.tags = parse_inline_list(value),
+            _ => {}
+        }
+    }
+
+    (Some(meta), body.to_string())
+}
+
+/// Parses a YAML flow sequence like `[a, b, "c"]` into its elements.
+fn parse_inline_list(value: &str) -> Vec<String> {
+    let Some(inner) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) else {
+        return Vec::new();
+    };
+    inner
+        .split(',')
+        .map(|item| item.trim().trim_matches('"').to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn well_formed_frontmatter_is_parsed_and_stripped_from_the_body() {
+        let content = "---\ndescription: deploy helper\ntags: [ops, deploy]\npriority: 70\n---\n# Deploy\nsteps here\n";
+
+        let (meta, body) = parse(content);
+
+        let meta = meta.unwrap();
+        assert_eq!(meta.description.as_deref(), Some("deploy helper"));
+        assert_eq!(meta.tags, vec!["ops".to_string(), "deploy".to_string()]);
+        assert_eq!(meta.priority, Some(70));
+        assert_eq!(body, "# Deploy\nsteps here\n");
+    }
+
+    #[test]
+    fn content_with_no_frontmatter_is_returned_unchanged() {
+        let content = "# Deploy\nno frontmatter here\n";
+
+        let (meta, body) = parse(content);
+
+        assert!(meta.is_none());
+        assert_eq!(body, content);
+    }
+
+    #[test]
+    fn an_unclosed_frontmatter_block_is_treated_as_absent() {
+        let content = "---\ndescription: deploy helper\n# Deploy\nsteps here\n";
+
+        let (meta, body) = parse(content);
+
+        assert!(meta.is_none());
+        assert_eq!(body, content);
+    }
+
+    #[test]
+    fn unrecognized_keys_and_a_non_numeric_priority_are_ignored() {
+        let content = "---\nauthor: nobody\npriority: not-a-number\n---\nbody\n";
+
+        let (meta, body) = parse(content);
+
+        let meta = meta.unwrap();
+        assert_eq!(meta.priority, None);
+        assert!(meta.description.is_none());
+        assert_eq!(body, "body\n");
+    }
+}
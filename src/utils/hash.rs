@@ -0,0 +1,51 @@
+use std::io::Read;
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+use crate::error::Result;
+
+const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Content-addressable hash used to detect drift between stored and upstream skill content.
+pub fn hash_content(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Same digest as `hash_content`, but reads `path` in fixed-size chunks so
+/// large files never need to be loaded into memory whole.
+pub fn hash_file_streaming(path: &Path) -> Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; STREAM_CHUNK_SIZE];
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn streaming_hash_matches_in_memory_hash() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("csm_hash_streaming_test.txt");
+        let content = "x".repeat(200_000);
+        std::fs::write(&path, &content).unwrap();
+
+        let streamed = hash_file_streaming(&path).unwrap();
+
+        assert_eq!(streamed, hash_content(&content));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
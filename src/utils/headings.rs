@@ -0,0 +1,131 @@
+use serde::Serialize;
+
+/// A single Markdown ATX heading (`# Title`, `## Title`, ...).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Heading {
+    pub level: u8,
+    pub title: String,
+}
+
+/// Extracts the heading outline of Markdown content, in document order.
+pub fn extract_headings(content: &str) -> Vec<Heading> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let trimmed = line.trim_start();
+            let level = trimmed.chars().take_while(|&c| c == '#').count();
+            if level == 0 || level > 6 {
+                return None;
+            }
+            let title = trimmed[level..].trim();
+            if title.is_empty() {
+                return None;
+            }
+            Some(Heading {
+                level: level as u8,
+                title: title.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Extracts the body of the section under the heading titled `title`,
+/// stopping at the next heading of the same or shallower level.
+pub fn extract_section(content: &str, title: &str) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let start = lines.iter().position(|line| {
+        let trimmed = line.trim_start();
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        level > 0 && level <= 6 && trimmed[level..].trim() == title
+    })?;
+    let start_level = lines[start]
+        .trim_start()
+        .chars()
+        .take_while(|&c| c == '#')
+        .count();
+
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| {
+            let trimmed = line.trim_start();
+            let level = trimmed.chars().take_while(|&c| c == '#').count();
+            level > 0 && level <= start_level
+        })
+        .map(|offset| start + 1 + offset)
+        .unwrap_or(lines.len());
+
+    Some(lines[start + 1..end].join("\n").trim().to_string())
+}
+
+/// GitHub-style heading anchor slug: lowercased, spaces turned into
+/// hyphens, and anything that isn't alphanumeric, `-`, or `_` dropped —
+/// matching how GitHub renders `## Section Title` as anchor `#section-title`.
+pub fn github_slug(title: &str) -> String {
+    title
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                Some(c)
+            } else if c.is_whitespace() {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "\
+# Title
+intro
+
+## Setup
+setup steps
+
+## Usage
+usage details
+
+### Advanced
+advanced details
+";
+
+    #[test]
+    fn extracts_the_full_heading_outline() {
+        let headings = extract_headings(CONTENT);
+        assert_eq!(
+            headings,
+            vec![
+                Heading { level: 1, title: "Title".to_string() },
+                Heading { level: 2, title: "Setup".to_string() },
+                Heading { level: 2, title: "Usage".to_string() },
+                Heading { level: 3, title: "Advanced".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_a_section_stopping_at_the_next_heading_of_equal_or_shallower_level() {
+        let section = extract_section(CONTENT, "Usage").unwrap();
+        assert_eq!(section, "usage details\n\n### Advanced\nadvanced details");
+    }
+
+    #[test]
+    fn unknown_section_title_returns_none() {
+        assert!(extract_section(CONTENT, "Missing").is_none());
+    }
+
+    #[test]
+    fn slugifies_spaces_and_strips_punctuation() {
+        assert_eq!(github_slug("Setup & Usage"), "setup--usage");
+    }
+
+    #[test]
+    fn slug_preserves_hyphens_and_underscores() {
+        assert_eq!(github_slug("Already-Hyphenated_Title"), "already-hyphenated_title");
+    }
+}
@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+/// A simple on-disk ETag cache for conditional HTTP GETs, keyed by an
+/// arbitrary caller-supplied string (e.g. `owner/repo/path@ref`). Used by
+/// [`crate::github::GitHubClientImpl`] to avoid re-downloading file content
+/// that hasn't changed upstream.
+pub struct HttpCache {
+    dir: PathBuf,
+}
+
+impl HttpCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// The ETag stored for `key`, if a response has ever been cached for it.
+    pub fn etag(&self, key: &str) -> Option<String> {
+        std::fs::read_to_string(self.etag_path(key)).ok()
+    }
+
+    /// The cached response body for `key`, if any.
+    pub fn body(&self, key: &str) -> Option<String> {
+        std::fs::read_to_string(self.body_path(key)).ok()
+    }
+
+    /// Persists a response body and its optional ETag under `key`.
+    pub fn store(&self, key: &str, etag: Option<&str>, body: &str) {
+        let _ = std::fs::create_dir_all(&self.dir);
+        let _ = std::fs::write(self.body_path(key), body);
+        if let Some(etag) = etag {
+            let _ = std::fs::write(self.etag_path(key), etag);
+        }
+    }
+
+    fn slug(key: &str) -> String {
+        key.chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '.' { c } else { '_' })
+            .collect()
+    }
+
+    fn body_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.body", Self::slug(key)))
+    }
+
+    fn etag_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.etag", Self::slug(key)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stores_and_reads_back_a_body_and_etag() {
+        let dir = std::env::temp_dir().join("csm_test_http_cache_roundtrip");
+        let _ = std::fs::remove_dir_all(&dir);
+        let cache = HttpCache::new(dir.clone());
+
+        cache.store("acme/skills/SKILL.md@main", Some("\"abc123\""), "file content");
+
+        assert_eq!(cache.body("acme/skills/SKILL.md@main").as_deref(), Some("file content"));
+        assert_eq!(cache.etag("acme/skills/SKILL.md@main").as_deref(), Some("\"abc123\""));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn a_key_that_was_never_stored_misses() {
+        let dir = std::env::temp_dir().join("csm_test_http_cache_miss");
+        let cache = HttpCache::new(dir);
+
+        assert_eq!(cache.body("never/stored"), None);
+        assert_eq!(cache.etag("never/stored"), None);
+    }
+}
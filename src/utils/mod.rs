@@ -0,0 +1,13 @@
+pub mod archive;
+pub mod cancellation;
+pub mod compression;
+pub mod content_sanity;
+pub mod diff;
+pub mod editor;
+pub mod frontmatter;
+pub mod hash;
+pub mod headings;
+pub mod http_cache;
+pub mod prompt;
+pub mod relative_time;
+pub mod retry;
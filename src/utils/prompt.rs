@@ -0,0 +1,7 @@
+use std::io::IsTerminal;
+
+/// Whether stdin is an interactive terminal, i.e. safe to block on a prompt
+/// instead of erroring immediately like a script or CI run would need.
+pub fn is_interactive() -> bool {
+    std::io::stdin().is_terminal()
+}
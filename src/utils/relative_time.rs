@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+
+/// Renders `when` relative to `now` as a short phrase like `"3 days ago"`,
+/// for `csm show`'s human-readable timestamp display. Takes `now` explicitly
+/// rather than calling `Utc::now()` internally so callers can test against a
+/// fixed instant.
+pub fn humanize(when: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = (now - when).num_seconds();
+    if seconds < 0 {
+        return "in the future".to_string();
+    }
+
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    const MONTH: i64 = 30 * DAY;
+    const YEAR: i64 = 365 * DAY;
+
+    let (amount, unit) = if seconds < MINUTE {
+        return "just now".to_string();
+    } else if seconds < HOUR {
+        (seconds / MINUTE, "minute")
+    } else if seconds < DAY {
+        (seconds / HOUR, "hour")
+    } else if seconds < MONTH {
+        (seconds / DAY, "day")
+    } else if seconds < YEAR {
+        (seconds / MONTH, "month")
+    } else {
+        (seconds / YEAR, "year")
+    };
+
+    if amount == 1 {
+        format!("1 {unit} ago")
+    } else {
+        format!("{amount} {unit}s ago")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn three_days_ago_is_rendered_as_such() {
+        let now = Utc::now();
+        let when = now - Duration::days(3);
+
+        assert_eq!(humanize(when, now), "3 days ago");
+    }
+
+    #[test]
+    fn under_a_minute_is_just_now() {
+        let now = Utc::now();
+        let when = now - Duration::seconds(10);
+
+        assert_eq!(humanize(when, now), "just now");
+    }
+
+    #[test]
+    fn exactly_one_hour_uses_singular_unit() {
+        let now = Utc::now();
+        let when = now - Duration::hours(1);
+
+        assert_eq!(humanize(when, now), "1 hour ago");
+    }
+}
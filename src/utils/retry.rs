@@ -0,0 +1,131 @@
+use std::future::Future;
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::error::{CsmError, Result};
+
+/// Configures [`with_backoff`], usually sourced from `github.max_retries`.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    /// Upper bound on how long a `RateLimited` reset wait is allowed to sleep for.
+    pub max_rate_limit_wait: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_rate_limit_wait: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Retries `attempt` up to `policy.max_retries` times on a retryable
+/// `CsmError` (see [`CsmError::is_retryable`]). `RateLimited` sleeps until
+/// its reset time (capped at `max_rate_limit_wait`); everything else backs
+/// off exponentially from `base_delay` with jitter. Non-retryable errors
+/// propagate on the first attempt.
+pub async fn with_backoff<T, F, Fut>(policy: RetryPolicy, mut attempt: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut last_err = None;
+    for attempt_number in 0..=policy.max_retries {
+        match attempt().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt_number < policy.max_retries && err.is_retryable() => {
+                tokio::time::sleep(delay_for(&err, attempt_number, &policy)).await;
+                last_err = Some(err);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Err(last_err.expect("the loop above always attempts at least once"))
+}
+
+fn delay_for(err: &CsmError, attempt_number: u32, policy: &RetryPolicy) -> Duration {
+    if let CsmError::RateLimited { retry_after_secs } = err {
+        return Duration::from_secs(*retry_after_secs).min(policy.max_rate_limit_wait);
+    }
+    let exponential = policy.base_delay * 2u32.saturating_pow(attempt_number);
+    let jitter = rand::thread_rng().gen_range(0.5..1.5);
+    exponential.mul_f64(jitter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(1),
+            max_rate_limit_wait: Duration::from_millis(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_a_retryable_error_until_it_succeeds() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = attempts.clone();
+
+        let result = with_backoff(fast_policy(), || {
+            let counter = counter.clone();
+            async move {
+                if counter.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(CsmError::RateLimited { retry_after_secs: 0 })
+                } else {
+                    Ok(42)
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(result, 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_retries() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = attempts.clone();
+
+        let result: Result<()> = with_backoff(fast_policy(), || {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Err(CsmError::RateLimited { retry_after_secs: 0 })
+            }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn non_retryable_errors_propagate_on_the_first_attempt() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = attempts.clone();
+
+        let result: Result<()> = with_backoff(fast_policy(), || {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Err(CsmError::Validation("bad input".to_string()))
+            }
+        })
+        .await;
+
+        assert!(matches!(result, Err(CsmError::Validation(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+}
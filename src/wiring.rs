@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use sqlx::sqlite::SqlitePoolOptions;
+
+use crate::config::Config;
+use crate::error::Result;
+use crate::github::{GitHubClient, GitHubClientImpl};
+use crate::repository::{
+    InMemoryConflictRepository, SkillRepository, SqliteSkillRepository,
+};
+use crate::services::{
+    ConflictService, ConflictServiceImpl, ContentFetcher, MergeService, SkillService,
+    SkillServiceImpl, UpdateService, UpdateServiceImpl,
+};
+
+/// Builds the default `MergeService`, targeting `<csm_home>/CLAUDE.md`.
+pub fn build_merge_service(config: &Config) -> Arc<dyn MergeService> {
+    Arc::new(
+        crate::services::merge::ClaudeMdMergeService::new(config.csm_home.join("CLAUDE.md"))
+            .with_dedupe_sections(config.merge.dedupe_sections)
+            .with_header_text(config.merge.header_text.clone())
+            .with_skill_header(config.merge.skill_header.clone())
+            .with_dedupe_lines(config.merge.dedupe_lines)
+            .with_toc(config.merge.toc),
+    )
+}
+
+/// Assembles the default `SkillService` from a resolved config, wiring the
+/// SQLite repository, source fetcher, and `CLAUDE.md` merger together.
+pub async fn build_skill_service(config: &Config) -> Result<Arc<dyn SkillService>> {
+    let pool = SqlitePoolOptions::new()
+        .connect(&format!("sqlite://{}/csm.db", config.csm_home.display()))
+        .await?;
+
+    let sqlite_repository = SqliteSkillRepository::new(pool);
+    sqlite_repository
+        .apply_pragmas(&config.database.validated_pragmas()?)
+        .await?;
+    sqlite_repository.run_migrations().await?;
+    let repository: Arc<dyn SkillRepository> = Arc::new(sqlite_repository);
+    let fetcher: Arc<dyn ContentFetcher> = Arc::new(crate::services::fetcher::DefaultContentFetcher);
+    let merger = build_merge_service(config);
+
+    Ok(Arc::new(
+        SkillServiceImpl::new(repository, fetcher, merger)
+            .with_same_name_strategy(config.merge.same_name_strategy)
+            .with_enable_on_add(config.general.enable_on_add)
+            .with_inherit_global(config.merge.inherit_global),
+    ))
+}
+
+/// Assembles the default `ConflictService`, sharing the same skills table.
+pub async fn build_conflict_service(config: &Config) -> Result<Arc<dyn ConflictService>> {
+    let pool = SqlitePoolOptions::new()
+        .connect(&format!("sqlite://{}/csm.db", config.csm_home.display()))
+        .await?;
+
+    let sqlite_repository = SqliteSkillRepository::new(pool);
+    sqlite_repository
+        .apply_pragmas(&config.database.validated_pragmas()?)
+        .await?;
+    sqlite_repository.run_migrations().await?;
+    let skills: Arc<dyn SkillRepository> = Arc::new(sqlite_repository);
+    let conflicts = Arc::new(InMemoryConflictRepository::default());
+
+    Ok(Arc::new(
+        ConflictServiceImpl::new(skills, conflicts)
+            .with_contradiction_pairs(&config.conflicts.contradiction_pairs),
+    ))
+}
+
+/// Builds the default `GitHubClient`, targeting `github.api_url` when
+/// configured (GitHub Enterprise) or `api.github.com` otherwise.
+pub fn build_github_client(config: &Config) -> Arc<dyn GitHubClient> {
+    let client = match &config.github.api_url {
+        Some(api_url) => GitHubClientImpl::with_base_url(api_url.clone()),
+        None => GitHubClientImpl::new(),
+    };
+    let token = crate::github::resolve_token(config.github.token.as_deref());
+    Arc::new(
+        client
+            .with_token(token)
+            .with_cache_dir(config.csm_home.join("cache"))
+            .with_max_retries(config.github.max_retries),
+    )
+}
+
+/// Assembles the default `UpdateService`, targeting `github.api_url` when
+/// configured (GitHub Enterprise) or `api.github.com` otherwise.
+pub async fn build_update_service(config: &Config) -> Result<Arc<dyn UpdateService>> {
+    let github = build_github_client(config);
+    let skills = build_skill_service(config).await?;
+
+    Ok(Arc::new(UpdateServiceImpl::new(github, skills)))
+}